@@ -0,0 +1,170 @@
+//! Bump allocation for AST storage.
+//!
+//! Parsing allocates one node at a time; with `Box`-ed children every
+//! expression costs an allocator round-trip and nodes end up scattered
+//! across the heap. The arena instead hands out references into large
+//! chunks, so allocation is a pointer bump and a parse's nodes are laid
+//! out contiguously in allocation order — the order walkers visit them.
+//!
+//! The arena never runs destructors: everything allocated in it lives
+//! until the arena is dropped, and types with owned heap state (`Vec`,
+//! `String`) would leak that state. AST nodes hold interned identifiers
+//! and arena references precisely so they are drop-free; slices and
+//! strings go through [`Arena::alloc_slice`] / [`Arena::alloc_str`].
+
+use std::alloc::Layout;
+use std::cell::Cell;
+use std::cell::RefCell;
+
+/// The default chunk size; large allocations get a dedicated chunk.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// A bump allocator whose allocations live as long as the arena itself.
+#[derive(Default)]
+pub struct Arena {
+    chunks: RefCell<Vec<Vec<u8>>>,
+    allocated: Cell<usize>,
+}
+
+impl Arena {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move `value` into the arena and return a reference to it.
+    ///
+    /// The value's destructor will not run; see the module docs.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, value: T) -> &mut T {
+        let pointer = self.alloc_raw(Layout::new::<T>()) as *mut T;
+
+        // SAFETY: `alloc_raw` returned a properly aligned, exclusively
+        // owned region of at least `size_of::<T>()` bytes that lives as
+        // long as the arena.
+        unsafe {
+            pointer.write(value);
+            &mut *pointer
+        }
+    }
+
+    /// Copy a slice into the arena.
+    pub fn alloc_slice<T: Copy>(&self, values: &[T]) -> &[T] {
+        if values.is_empty() {
+            return &[];
+        }
+
+        let pointer = self.alloc_raw(Layout::array::<T>(values.len()).expect("slice layout")) as *mut T;
+
+        // SAFETY: the region is aligned for `T`, large enough for
+        // `values.len()` elements, and disjoint from `values` (it was just
+        // allocated).
+        unsafe {
+            pointer.copy_from_nonoverlapping(values.as_ptr(), values.len());
+            std::slice::from_raw_parts(pointer, values.len())
+        }
+    }
+
+    /// Copy a string into the arena.
+    pub fn alloc_str(&self, value: &str) -> &str {
+        let bytes = self.alloc_slice(value.as_bytes());
+
+        // SAFETY: `bytes` is a byte-for-byte copy of a valid `str`.
+        unsafe { std::str::from_utf8_unchecked(bytes) }
+    }
+
+    /// Total bytes handed out, excluding per-chunk slack.
+    pub fn allocated_bytes(&self) -> usize {
+        self.allocated.get()
+    }
+
+    fn alloc_raw(&self, layout: Layout) -> *mut u8 {
+        let size = layout.size().max(1);
+        let align = layout.align();
+        let mut chunks = self.chunks.borrow_mut();
+
+        if let Some(chunk) = chunks.last_mut() {
+            let base = chunk.as_ptr() as usize;
+            let start = base + chunk.len();
+            let aligned = (start + align - 1) & !(align - 1);
+            let new_length = aligned - base + size;
+
+            if new_length <= chunk.capacity() {
+                // SAFETY: `new_length <= capacity` and the bytes below it
+                // are either previously written or about to be; the buffer
+                // never reallocates because the length stays within the
+                // reserved capacity.
+                unsafe { chunk.set_len(new_length) };
+                self.allocated.set(self.allocated.get() + size);
+
+                return aligned as *mut u8;
+            }
+        }
+
+        // Start a fresh chunk; oversized requests get an exact-fit chunk so
+        // a single huge node cannot waste a whole default chunk.
+        let capacity = CHUNK_SIZE.max(size + align);
+        let mut chunk = Vec::with_capacity(capacity);
+
+        let base = chunk.as_ptr() as usize;
+        let aligned = (base + align - 1) & !(align - 1);
+
+        // SAFETY: as above; `aligned - base + size <= capacity` because the
+        // chunk reserves `align` slack beyond `size`.
+        unsafe { chunk.set_len(aligned - base + size) };
+        self.allocated.set(self.allocated.get() + size);
+        chunks.push(chunk);
+
+        aligned as *mut u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_values_survive_later_allocations() {
+        let arena = Arena::new();
+
+        let first = arena.alloc(41u64);
+        // Force multiple chunks so earlier references would dangle if
+        // chunk storage moved.
+        for index in 0..100_000u64 {
+            arena.alloc(index);
+        }
+
+        assert_eq!(*first, 41);
+    }
+
+    #[test]
+    fn test_alignment_is_respected() {
+        let arena = Arena::new();
+
+        arena.alloc(1u8);
+        let aligned = arena.alloc(7u64);
+
+        assert_eq!(aligned as *const u64 as usize % std::mem::align_of::<u64>(), 0);
+        assert_eq!(*aligned, 7);
+    }
+
+    #[test]
+    fn test_slices_and_strings_are_copied() {
+        let arena = Arena::new();
+
+        let slice = arena.alloc_slice(&[1u32, 2, 3]);
+        let text = arena.alloc_str("hello");
+
+        assert_eq!(slice, &[1, 2, 3]);
+        assert_eq!(text, "hello");
+        assert!(arena.allocated_bytes() >= 12 + 5);
+    }
+
+    #[test]
+    fn test_oversized_allocation_gets_its_own_chunk() {
+        let arena = Arena::new();
+
+        let big = arena.alloc_slice(&vec![0u8; CHUNK_SIZE * 2]);
+
+        assert_eq!(big.len(), CHUNK_SIZE * 2);
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::string_literals::decode_literal;
+
+/// A constant array key after PHP's key coercion.
+///
+/// PHP coerces keys before insertion: integral strings become integers
+/// (`'1'` and `1` collide), `true`/`false` become `1`/`0`, `null` becomes
+/// `''`, and floats are truncated. Two keys are duplicates exactly when
+/// their coerced forms are equal, which is why comparison happens on this
+/// normalized type rather than on source text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum CoercedKey {
+    Int(i64),
+    Str(String),
+}
+
+/// Find duplicate *constant* keys among the elements of one array literal,
+/// returning `(first_occurrence, duplicate)` span pairs.
+///
+/// Only keys whose value is statically known are considered — literals,
+/// `true`/`false`/`null`, and negated integer literals. Anything dynamic
+/// (variables, constants, calls) is skipped rather than guessed at.
+pub fn find_duplicate_array_keys(interner: &ThreadedInterner, elements: &[ArrayElement]) -> Vec<(Span, Span)> {
+    let mut first_seen: HashMap<CoercedKey, Span> = HashMap::new();
+    let mut duplicates = Vec::new();
+
+    for element in elements {
+        let ArrayElement::KeyValue(key_value) = element else {
+            continue;
+        };
+
+        let Some(key) = coerce_key(interner, &key_value.key) else {
+            continue;
+        };
+
+        match first_seen.get(&key) {
+            Some(first) => duplicates.push((*first, key_value.key.span())),
+            None => {
+                first_seen.insert(key, key_value.key.span());
+            }
+        }
+    }
+
+    duplicates
+}
+
+/// Evaluate a key expression to its coerced form, or `None` when it is not
+/// a compile-time constant.
+fn coerce_key(interner: &ThreadedInterner, key: &Expression) -> Option<CoercedKey> {
+    match key {
+        Expression::Parenthesized(inner) => coerce_key(interner, &inner.expression),
+        Expression::Literal(Literal::Integer(literal)) => {
+            Some(CoercedKey::Int(parse_integer_literal(interner.lookup(&literal.value))?))
+        }
+        Expression::Literal(Literal::Float(literal)) => {
+            // Float keys truncate toward zero (deprecated since 8.1, still
+            // coerced), so `1.5` collides with `1`.
+            let raw: String = interner.lookup(&literal.value).chars().filter(|c| *c != '_').collect();
+            Some(CoercedKey::Int(raw.parse::<f64>().ok()? as i64))
+        }
+        Expression::Literal(Literal::String(literal)) => {
+            Some(coerce_string_key(decode_literal(interner.lookup(&literal.value))))
+        }
+        Expression::Literal(Literal::True(_)) => Some(CoercedKey::Int(1)),
+        Expression::Literal(Literal::False(_)) => Some(CoercedKey::Int(0)),
+        Expression::Literal(Literal::Null(_)) => Some(CoercedKey::Str(String::new())),
+        Expression::UnaryPrefix(unary) if matches!(unary.operator, UnaryPrefixOperator::Minus(_)) => {
+            match coerce_key(interner, &unary.operand)? {
+                CoercedKey::Int(value) => Some(CoercedKey::Int(value.checked_neg()?)),
+                CoercedKey::Str(_) => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Apply PHP's integral-string key coercion: a string that is a canonical
+/// decimal integer (no leading zeros, no leading `+`, fits in `i64`)
+/// becomes that integer; everything else stays a string key.
+fn coerce_string_key(decoded: String) -> CoercedKey {
+    let is_canonical = match decoded.strip_prefix('-') {
+        Some(rest) => rest.len() > 1 && !rest.starts_with('0') && rest.bytes().all(|byte| byte.is_ascii_digit())
+            || rest.len() == 1 && rest != "0" && rest.bytes().all(|byte| byte.is_ascii_digit()),
+        None => {
+            decoded == "0"
+                || (!decoded.is_empty() && !decoded.starts_with('0') && decoded.bytes().all(|byte| byte.is_ascii_digit()))
+        }
+    };
+
+    if is_canonical {
+        if let Ok(value) = decoded.parse::<i64>() {
+            return CoercedKey::Int(value);
+        }
+    }
+
+    CoercedKey::Str(decoded)
+}
+
+/// Parse an integer literal in any of PHP's notations (decimal, hex,
+/// octal, binary, legacy leading-zero octal), ignoring digit separators.
+pub(crate) fn parse_integer_literal(raw: &str) -> Option<i64> {
+    let digits: String = raw.chars().filter(|character| *character != '_').collect();
+
+    let (radix, body) = if let Some(rest) = digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+        (16, rest)
+    } else if let Some(rest) = digits.strip_prefix("0b").or_else(|| digits.strip_prefix("0B")) {
+        (2, rest)
+    } else if let Some(rest) = digits.strip_prefix("0o").or_else(|| digits.strip_prefix("0O")) {
+        (8, rest)
+    } else if digits.len() > 1 && digits.starts_with('0') {
+        (8, &digits[1..])
+    } else {
+        (10, digits.as_str())
+    };
+
+    i64::from_str_radix(body, radix).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integral_string_coerces_to_int() {
+        assert_eq!(coerce_string_key("1".to_owned()), CoercedKey::Int(1));
+        assert_eq!(coerce_string_key("0".to_owned()), CoercedKey::Int(0));
+        assert_eq!(coerce_string_key("-7".to_owned()), CoercedKey::Int(-7));
+        // Non-canonical integer strings stay string keys, as in PHP.
+        assert_eq!(coerce_string_key("01".to_owned()), CoercedKey::Str("01".to_owned()));
+        assert_eq!(coerce_string_key("+1".to_owned()), CoercedKey::Str("+1".to_owned()));
+        assert_eq!(coerce_string_key("1.0".to_owned()), CoercedKey::Str("1.0".to_owned()));
+        assert_eq!(coerce_string_key("-0".to_owned()), CoercedKey::Str("-0".to_owned()));
+    }
+
+    #[test]
+    fn test_integer_literal_notations() {
+        assert_eq!(parse_integer_literal("42"), Some(42));
+        assert_eq!(parse_integer_literal("1_000"), Some(1000));
+        assert_eq!(parse_integer_literal("0x2A"), Some(42));
+        assert_eq!(parse_integer_literal("0b101"), Some(5));
+        assert_eq!(parse_integer_literal("0o17"), Some(15));
+        assert_eq!(parse_integer_literal("017"), Some(15));
+    }
+}
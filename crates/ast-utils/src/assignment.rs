@@ -0,0 +1,199 @@
+use mago_ast::*;
+use mago_span::HasSpan;
+
+/// Every assignment expression nested anywhere inside `expression`.
+///
+/// Nested function-likes are not descended into: an assignment in a
+/// closure body is deferred work, not part of evaluating the surrounding
+/// expression.
+pub fn get_assignments_from_expression(expression: &Expression) -> Vec<&Assignment> {
+    let mut assignments = Vec::new();
+    let mut stack = vec![Node::Expression(expression)];
+    while let Some(node) = stack.pop() {
+        match node {
+            Node::Expression(Expression::Closure(_))
+            | Node::Expression(Expression::ArrowFunction(_))
+            | Node::Expression(Expression::AnonymousClass(_)) => continue,
+            Node::Expression(Expression::Assignment(assignment)) => assignments.push(assignment),
+            _ => {}
+        }
+
+        stack.extend(node.children());
+    }
+
+    assignments
+}
+
+/// Find assignments inside the controlling conditions of the program's
+/// conditional constructs: `if` / `elseif`, `while`, `do-while`, the
+/// condition slots of `for`, ternary conditions, and `match` subjects.
+///
+/// `if ($a = foo())` almost always means `==`; assignments in *bodies*
+/// are fine and never reported. With `allow_compared`, the idiomatic
+/// `while (($line = fgets($f)) !== false)` shape — the assignment used as
+/// an operand of a comparison — is allowed, since the comparison makes
+/// the single `=` visibly deliberate.
+pub fn conditions_with_assignment(program: &Program, allow_compared: bool) -> Vec<&Assignment> {
+    let mut found = Vec::new();
+    let mut stack = vec![Node::Program(program)];
+    while let Some(node) = stack.pop() {
+        stack.extend(node.children());
+
+        match node {
+            Node::Statement(Statement::If(r#if)) => {
+                collect(&r#if.condition, allow_compared, &mut found);
+                for clause in r#if.body.else_if_clauses() {
+                    collect(&clause.condition, allow_compared, &mut found);
+                }
+            }
+            Node::Statement(Statement::While(r#while)) => {
+                collect(&r#while.condition, allow_compared, &mut found);
+            }
+            Node::Statement(Statement::DoWhile(do_while)) => {
+                collect(&do_while.condition, allow_compared, &mut found);
+            }
+            Node::Statement(Statement::For(r#for)) => {
+                // Only the condition slots control the loop; the
+                // initializations and increments exist to assign.
+                for condition in r#for.conditions.iter() {
+                    collect(condition, allow_compared, &mut found);
+                }
+            }
+            Node::Expression(Expression::Conditional(conditional)) => {
+                collect(&conditional.condition, allow_compared, &mut found);
+            }
+            Node::Expression(Expression::Match(r#match)) => {
+                collect(&r#match.expression, allow_compared, &mut found);
+            }
+            _ => {}
+        }
+    }
+
+    found
+}
+
+/// Collect assignments from one condition, tracking whether each sits
+/// under a comparison operator. Parentheses are transparent; anything
+/// else between the comparison and the assignment (a call, an array
+/// index) breaks the "visibly compared" exemption.
+fn collect<'a>(condition: &'a Expression, allow_compared: bool, found: &mut Vec<&'a Assignment>) {
+    for assignment in get_assignments_from_expression(condition) {
+        if allow_compared && is_compared_in(condition, assignment) {
+            continue;
+        }
+
+        found.push(assignment);
+    }
+}
+
+/// Whether `assignment` is a direct (parenthesized) operand of a
+/// comparison somewhere inside `condition`.
+fn is_compared_in(condition: &Expression, assignment: &Assignment) -> bool {
+    let mut stack = vec![(condition, false)];
+    while let Some((expression, compared)) = stack.pop() {
+        match expression {
+            Expression::Assignment(candidate) if std::ptr::eq(candidate, assignment) => {
+                return compared;
+            }
+            Expression::Parenthesized(parenthesized) => {
+                stack.push((&parenthesized.expression, compared));
+            }
+            Expression::Binary(binary) => {
+                let compared = is_comparison(&binary.operator);
+                stack.push((&binary.lhs, compared));
+                stack.push((&binary.rhs, compared));
+            }
+            Expression::Assignment(candidate) => {
+                // Descend for assignments nested in this one's operands,
+                // which are themselves not compared.
+                stack.push((&candidate.lhs, false));
+                stack.push((&candidate.rhs, false));
+            }
+            Expression::UnaryPrefix(unary) => stack.push((&unary.operand, false)),
+            _ => {
+                let mut children = vec![Node::Expression(expression)];
+                while let Some(node) = children.pop() {
+                    if let Node::Expression(Expression::Assignment(candidate)) = node {
+                        if std::ptr::eq(candidate, assignment) {
+                            return false;
+                        }
+                    }
+                    children.extend(node.children());
+                }
+            }
+        }
+    }
+
+    false
+}
+
+fn is_comparison(operator: &BinaryOperator) -> bool {
+    matches!(
+        operator,
+        BinaryOperator::Equal(_)
+            | BinaryOperator::NotEqual(_)
+            | BinaryOperator::Identical(_)
+            | BinaryOperator::NotIdentical(_)
+            | BinaryOperator::AngledNotEqual(_)
+            | BinaryOperator::LessThan(_)
+            | BinaryOperator::LessThanOrEqual(_)
+            | BinaryOperator::GreaterThan(_)
+            | BinaryOperator::GreaterThanOrEqual(_)
+            | BinaryOperator::Spaceship(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_interner::ThreadedInterner;
+
+    use super::*;
+
+    fn assignment_offsets(source: &str, allow_compared: bool) -> Vec<usize> {
+        let interner = ThreadedInterner::new();
+        let (program, error) = mago_parser::parse_source_text(&interner, source);
+        assert!(error.is_none(), "test source must parse");
+
+        conditions_with_assignment(&program, allow_compared)
+            .into_iter()
+            .map(|assignment| assignment.span().start.offset)
+            .collect()
+    }
+
+    #[test]
+    fn test_assignment_in_if_condition_is_found() {
+        assert_eq!(assignment_offsets("<?php if ($a = foo()) {}", false).len(), 1);
+    }
+
+    #[test]
+    fn test_assignment_in_body_is_not_flagged() {
+        assert!(assignment_offsets("<?php if ($a == 1) { $b = 2; }", false).is_empty());
+        assert!(assignment_offsets("<?php while (true) { $b = next(); }", false).is_empty());
+    }
+
+    #[test]
+    fn test_elseif_and_ternary_and_match_conditions_are_scanned() {
+        assert_eq!(assignment_offsets("<?php if ($a) {} elseif ($b = bar()) {}", false).len(), 1);
+        assert_eq!(assignment_offsets("<?php $x = ($a = f()) ? 1 : 2;", false).len(), 1);
+        assert_eq!(assignment_offsets("<?php echo match ($m = g()) { default => 0 };", false).len(), 1);
+    }
+
+    #[test]
+    fn test_for_initializations_and_increments_are_allowed() {
+        assert!(assignment_offsets("<?php for ($i = 0; $i < 10; $i = $i + 1) {}", false).is_empty());
+        assert_eq!(assignment_offsets("<?php for (; $i = next($it); ) {}", false).len(), 1);
+    }
+
+    #[test]
+    fn test_compared_assignment_is_exempt_only_with_the_flag() {
+        let source = "<?php while (($line = fgets($f)) !== false) {}";
+
+        assert_eq!(assignment_offsets(source, false).len(), 1);
+        assert!(assignment_offsets(source, true).is_empty());
+    }
+
+    #[test]
+    fn test_bare_assignment_is_flagged_even_with_the_flag() {
+        assert_eq!(assignment_offsets("<?php while ($line = fgets($f)) {}", true).len(), 1);
+    }
+}
@@ -0,0 +1,77 @@
+use mago_ast::*;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+/// Find an unparenthesized chain of a non-associative operator, such as
+/// `$a == $b == $c` or `$a < $b < $c`, and return the span of the
+/// offending (outer) operator.
+///
+/// PHP parses these left-to-right, so `$a == $b == $c` compares a boolean
+/// against `$c` — almost always a bug. Only *same-precedence* chains are
+/// flagged: mixed-precedence expressions like `$a == $b < $c` group
+/// unambiguously and are left alone, as is anything the author
+/// parenthesized explicitly.
+pub fn detect_non_associative_chain(expression: &Expression) -> Option<Span> {
+    let mut stack = vec![expression];
+    while let Some(current) = stack.pop() {
+        if let Expression::Binary(binary) = current {
+            if is_non_associative(&binary.operator) {
+                // A direct (unparenthesized) binary operand with the same
+                // precedence makes the chain ambiguous to humans.
+                if let Expression::Binary(inner) = binary.lhs.as_ref() {
+                    if is_non_associative(&inner.operator)
+                        && precedence_of(&inner.operator) == precedence_of(&binary.operator)
+                    {
+                        return Some(binary.operator.span());
+                    }
+                }
+
+                if let Expression::Binary(inner) = binary.rhs.as_ref() {
+                    if is_non_associative(&inner.operator)
+                        && precedence_of(&inner.operator) == precedence_of(&binary.operator)
+                    {
+                        return Some(binary.operator.span());
+                    }
+                }
+            }
+        }
+
+        // Parenthesized subtrees restart the analysis: the author has
+        // already disambiguated them.
+        match current {
+            Expression::Binary(binary) => {
+                stack.push(&binary.lhs);
+                stack.push(&binary.rhs);
+            }
+            Expression::UnaryPrefix(unary) => stack.push(&unary.operand),
+            Expression::UnaryPostfix(unary) => stack.push(&unary.operand),
+            Expression::Parenthesized(inner) => stack.push(&inner.expression),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Whether the operator is non-associative in PHP's grammar.
+fn is_non_associative(operator: &BinaryOperator) -> bool {
+    matches!(
+        operator,
+        BinaryOperator::Equal(_)
+            | BinaryOperator::NotEqual(_)
+            | BinaryOperator::Identical(_)
+            | BinaryOperator::NotIdentical(_)
+            | BinaryOperator::AngledNotEqual(_)
+            | BinaryOperator::LessThan(_)
+            | BinaryOperator::LessThanOrEqual(_)
+            | BinaryOperator::GreaterThan(_)
+            | BinaryOperator::GreaterThanOrEqual(_)
+            | BinaryOperator::Spaceship(_),
+    )
+}
+
+/// The token-level precedence of the operator, so chains are only flagged
+/// within one level.
+fn precedence_of(operator: &BinaryOperator) -> mago_token::Precedence {
+    mago_token::Precedence::infix(&operator.token_kind())
+}
@@ -0,0 +1,114 @@
+use mago_ast::*;
+
+/// The variables a closure's `use` clause captures, in declaration order.
+///
+/// Each entry is the clause variable itself, so callers can read both the
+/// name and whether the capture is by reference
+/// (`variable.ampersand.is_some()`) — an unused by-reference capture is
+/// often intentional (the closure writes through it for side effects),
+/// which an unused-capture lint must treat differently from an unused
+/// by-value copy.
+pub fn closure_used_variables(closure: &Closure) -> Vec<&ClosureUseClauseVariable> {
+    match &closure.use_clause {
+        Some(use_clause) => use_clause.variables.iter().collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Every direct variable appearing in the closure's body, in source
+/// order, without descending into nested closures or anonymous classes —
+/// their bodies are fresh scopes, and a `$x` there says nothing about
+/// this closure's captures. Nested closures' *own* `use` clauses do
+/// count: capturing `$x` for a nested closure reads it here. Arrow
+/// functions capture implicitly, so their bodies are traversed.
+///
+/// Parameters are not filtered out; callers diffing against the `use`
+/// clause should drop names shadowed by the closure's parameter list,
+/// since PHP resolves those to the parameter, not the capture.
+pub fn closure_body_variable_reads(closure: &Closure) -> Vec<&DirectVariable> {
+    let mut reads = Vec::new();
+    let mut stack = vec![Node::Block(&closure.body)];
+    while let Some(node) = stack.pop() {
+        match node {
+            Node::DirectVariable(variable) => reads.push(variable),
+            Node::Closure(nested) => {
+                if let Some(use_clause) = &nested.use_clause {
+                    for captured in use_clause.variables.iter() {
+                        reads.push(&captured.variable);
+                    }
+                }
+            }
+            Node::AnonymousClass(_) => {}
+            _ => stack.extend(node.children()),
+        }
+    }
+
+    reads.sort_by_key(|variable| variable.span.start.offset);
+    reads
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_interner::ThreadedInterner;
+
+    use super::*;
+
+    fn with_first_closure<R>(source: &str, f: impl FnOnce(&ThreadedInterner, &Closure) -> R) -> R {
+        let interner = ThreadedInterner::new();
+        let (program, error) = mago_parser::parse_source_text(&interner, source);
+        assert!(error.is_none(), "test source must parse");
+
+        let mut stack = vec![Node::Program(&program)];
+        while let Some(node) = stack.pop() {
+            if let Node::Closure(closure) = node {
+                return f(&interner, closure);
+            }
+            stack.extend(node.children());
+        }
+
+        panic!("no closure in test source");
+    }
+
+    #[test]
+    fn test_use_clause_variables_and_reference_flags() {
+        with_first_closure("<?php $f = function () use ($a, &$b) {};", |interner, closure| {
+            let captures = closure_used_variables(closure);
+            assert_eq!(captures.len(), 2);
+            assert_eq!(interner.lookup(&captures[0].variable.name), "$a");
+            assert!(captures[0].ampersand.is_none());
+            assert_eq!(interner.lookup(&captures[1].variable.name), "$b");
+            assert!(captures[1].ampersand.is_some());
+        });
+    }
+
+    #[test]
+    fn test_body_reads_exclude_nested_closure_bodies() {
+        let source = "<?php $f = function () use ($a) { $inner = function () use ($a) { return $a + $b; }; };";
+        with_first_closure(source, |interner, closure| {
+            let reads: Vec<&str> =
+                closure_body_variable_reads(closure).iter().map(|variable| interner.lookup(&variable.name)).collect();
+
+            // `$inner`, plus the nested closure's capture of `$a`; the
+            // nested body's `$a + $b` belongs to the nested scope.
+            assert_eq!(reads, vec!["$inner", "$a"]);
+        });
+    }
+
+    #[test]
+    fn test_arrow_function_bodies_count_as_reads() {
+        let source = "<?php $f = function () use ($a) { return fn () => $a; };";
+        with_first_closure(source, |interner, closure| {
+            let reads: Vec<&str> =
+                closure_body_variable_reads(closure).iter().map(|variable| interner.lookup(&variable.name)).collect();
+            assert_eq!(reads, vec!["$a"]);
+        });
+    }
+
+    #[test]
+    fn test_closure_without_use_clause_captures_nothing() {
+        with_first_closure("<?php $f = function () { return $x; };", |_, closure| {
+            assert!(closure_used_variables(closure).is_empty());
+            assert_eq!(closure_body_variable_reads(closure).len(), 1);
+        });
+    }
+}
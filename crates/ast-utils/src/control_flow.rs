@@ -0,0 +1,173 @@
+use mago_ast::*;
+
+/// Whether execution never continues past `statement` (it returns, throws,
+/// exits, or unconditionally transfers control).
+pub fn statement_terminates(statement: &Statement) -> bool {
+    match statement {
+        Statement::Return(_) | Statement::Break(_) | Statement::Continue(_) | Statement::Goto(_) => true,
+        Statement::Expression(expression) => expression_terminates(&expression.expression),
+        Statement::Block(block) => block.statements.last().is_some_and(statement_terminates),
+        Statement::If(r#if) => {
+            // An `if` only terminates when every branch exists and terminates.
+            let body = &r#if.body;
+            body.has_else()
+                && body.all_branch_tails().into_iter().all(statement_terminates)
+        }
+        _ => false,
+    }
+}
+
+fn expression_terminates(expression: &Expression) -> bool {
+    match expression {
+        Expression::Throw(_) => true,
+        Expression::Construct(construct) => matches!(construct.as_ref(), Construct::Exit(_) | Construct::Die(_)),
+        _ => false,
+    }
+}
+
+/// Whether the block returns (or throws) before its final statement —
+/// i.e. it already uses guard clauses / early returns somewhere.
+pub fn has_early_return(block: &Block) -> bool {
+    let statements = block.statements.as_slice();
+    let Some((_last, before)) = statements.split_last() else {
+        return false;
+    };
+
+    fn contains_return(statement: &Statement) -> bool {
+        let mut stack = vec![Node::Statement(statement)];
+        while let Some(node) = stack.pop() {
+            match node {
+                Node::Closure(_) | Node::ArrowFunction(_) | Node::AnonymousClass(_) => continue,
+                Node::Return(_) => return true,
+                Node::Throw(_) => return true,
+                _ => stack.extend(node.children()),
+            }
+        }
+
+        false
+    }
+
+    before.iter().any(contains_return)
+}
+
+/// Every statement that can never execute because an earlier statement in
+/// its block unconditionally terminates.
+///
+/// Within each block (recursing into nested blocks), everything after the
+/// first terminating statement is collected — except labels: a label is a
+/// `goto` target and can be reached out of order, and statements *after* a
+/// label are reachable through it, so a label ends the unreachable run.
+pub fn unreachable_statements_in_block(block: &Block) -> Vec<&Statement> {
+    let mut unreachable = Vec::new();
+    collect_unreachable(block, &mut unreachable);
+    unreachable
+}
+
+/// The first unreachable statement of each run, for lints preferring one
+/// diagnostic per run over one per statement.
+pub fn first_unreachable_in_block(block: &Block) -> Vec<&Statement> {
+    let mut firsts = Vec::new();
+    walk_unreachable(block, &mut |statement, first_of_run| {
+        if first_of_run {
+            firsts.push(statement);
+        }
+    });
+
+    firsts
+}
+
+fn collect_unreachable<'a>(block: &'a Block, unreachable: &mut Vec<&'a Statement>) {
+    walk_unreachable(block, &mut |statement, _| unreachable.push(statement));
+}
+
+fn walk_unreachable<'a>(block: &'a Block, report: &mut impl FnMut(&'a Statement, bool)) {
+    let mut terminated = false;
+    let mut first_of_run = false;
+    for statement in block.statements.iter() {
+        if terminated {
+            if matches!(statement, Statement::Label(_)) {
+                // A goto target: execution can resume here, ending the run.
+                terminated = false;
+            } else {
+                report(statement, first_of_run);
+                first_of_run = false;
+                continue;
+            }
+        }
+
+        if let Statement::Block(nested) = statement {
+            walk_unreachable(nested, report);
+        }
+
+        if statement_terminates(statement) {
+            terminated = true;
+            first_of_run = true;
+        }
+    }
+}
+
+/// Every `return` statement in the block, excluding those belonging to
+/// nested closures, arrow functions, and anonymous classes.
+pub fn find_returns_in_block(block: &Block) -> Vec<&Return> {
+    let mut returns = Vec::new();
+    let mut stack = vec![Node::Block(block)];
+    while let Some(node) = stack.pop() {
+        match node {
+            Node::Closure(_) | Node::ArrowFunction(_) | Node::AnonymousClass(_) => continue,
+            Node::Return(r#return) => returns.push(r#return),
+            _ => stack.extend(node.children()),
+        }
+    }
+
+    returns
+}
+
+/// Whether the block contains a `yield` (or `yield from`) of its own —
+/// yields inside nested function-likes belong to those functions and do
+/// not make the enclosing one a generator.
+pub fn block_has_yield(block: &Block) -> bool {
+    let mut stack = vec![Node::Block(block)];
+    while let Some(node) = stack.pop() {
+        match node {
+            Node::Closure(_) | Node::ArrowFunction(_) | Node::AnonymousClass(_) => continue,
+            Node::Expression(Expression::Yield(_) | Expression::YieldFrom(_)) => return true,
+            _ => stack.extend(node.children()),
+        }
+    }
+
+    false
+}
+
+/// The trailing `if` of a block that wraps the remainder of the function —
+/// the shape a "prefer early return" lint wants to invert into a guard
+/// clause.
+///
+/// Returns the final statement's `if` when:
+///
+/// - it is the last statement of the block (nothing follows it to be
+///   shadowed by the inversion),
+/// - it has no `else` / `elseif` (inverting would otherwise change paths),
+/// - its body holds more statements than the rest of the block, so it is
+///   genuinely "the body wrapped in a conditional" rather than an
+///   incidental trailing check.
+pub fn block_ends_with_conditional_wrapping(block: &Block) -> Option<&If> {
+    let statements = block.statements.as_slice();
+    let (last, before) = statements.split_last()?;
+
+    let Statement::If(r#if) = last else {
+        return None;
+    };
+
+    if r#if.body.has_else() || r#if.body.has_else_if() {
+        return None;
+    }
+
+    // If an earlier statement already terminates, the trailing `if` is
+    // unreachable decoration, not the function body.
+    if before.iter().any(statement_terminates) {
+        return None;
+    }
+
+    let wrapped = r#if.body.statement_count();
+    if wrapped > before.len() { Some(r#if) } else { None }
+}
@@ -0,0 +1,91 @@
+use mago_span::Span;
+use mago_token::Token;
+use mago_token::TokenKind;
+
+/// The docblock attached to the node starting at `node_span`, if any.
+///
+/// Scans backward from the node's start through the token stream: only
+/// whitespace may sit between the docblock and the node. An intervening
+/// regular comment, attribute, or any code token breaks the attachment —
+/// a docblock followed by an unrelated comment documents nothing, which
+/// is exactly how PHP reflection's `getDocComment()` behaves.
+///
+/// `tokens` is the file's full token stream (trivia included) in source
+/// order, as produced by the lexer before parsing.
+pub fn leading_docblock<'a>(node_span: Span, tokens: &'a [Token]) -> Option<&'a Token> {
+    // Index of the first token at or past the node; everything before it
+    // precedes the node.
+    let node_start = node_span.start.offset;
+    let first_at_node = tokens.partition_point(|token| token.span.start.offset < node_start);
+
+    for token in tokens[..first_at_node].iter().rev() {
+        match token.kind {
+            TokenKind::Whitespace => continue,
+            TokenKind::DocBlockComment => return Some(token),
+            _ => return None,
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_interner::ThreadedInterner;
+    use mago_lexer::input::Input;
+    use mago_lexer::Lexer;
+    use mago_span::FileId;
+    use mago_span::Position;
+
+    use super::*;
+
+    fn tokens_of(interner: &ThreadedInterner, source: &str) -> Vec<Token> {
+        let mut lexer = Lexer::new(interner, Input::new(FileId(0), source.as_bytes()));
+        std::iter::from_fn(|| lexer.advance()).map(|token| token.expect("test source must lex")).collect()
+    }
+
+    fn span_at(offset: usize) -> Span {
+        Span::new(Position::new(FileId(0), offset, 1), Position::new(FileId(0), offset, 1))
+    }
+
+    #[test]
+    fn test_docblock_directly_above_attaches() {
+        let interner = ThreadedInterner::new();
+        let source = "<?php\n/** Doc. */\nfunction a() {}\n";
+        let tokens = tokens_of(&interner, source);
+
+        let function_offset = source.find("function").expect("source has a function");
+        let docblock = leading_docblock(span_at(function_offset), &tokens).expect("docblock attaches");
+        assert_eq!(docblock.kind, TokenKind::DocBlockComment);
+    }
+
+    #[test]
+    fn test_intervening_comment_breaks_attachment() {
+        let interner = ThreadedInterner::new();
+        let source = "<?php\n/** Doc. */\n// unrelated\nfunction a() {}\n";
+        let tokens = tokens_of(&interner, source);
+
+        let function_offset = source.find("function").expect("source has a function");
+        assert!(leading_docblock(span_at(function_offset), &tokens).is_none());
+    }
+
+    #[test]
+    fn test_intervening_code_breaks_attachment() {
+        let interner = ThreadedInterner::new();
+        let source = "<?php\n/** Doc. */\n$a = 1;\nfunction b() {}\n";
+        let tokens = tokens_of(&interner, source);
+
+        let function_offset = source.find("function").expect("source has a function");
+        assert!(leading_docblock(span_at(function_offset), &tokens).is_none());
+    }
+
+    #[test]
+    fn test_no_docblock_yields_none() {
+        let interner = ThreadedInterner::new();
+        let source = "<?php\nfunction a() {}\n";
+        let tokens = tokens_of(&interner, source);
+
+        let function_offset = source.find("function").expect("source has a function");
+        assert!(leading_docblock(span_at(function_offset), &tokens).is_none());
+    }
+}
@@ -0,0 +1,116 @@
+use mago_ast::*;
+
+/// A reference to any of the four function-like node types, so callers can
+/// ask for parameters, return type hint, or body without matching four
+/// variants every time.
+#[derive(Debug, Clone, Copy)]
+pub enum FunctionLikeRef<'a> {
+    Function(&'a Function),
+    Method(&'a Method),
+    Closure(&'a Closure),
+    ArrowFunction(&'a ArrowFunction),
+}
+
+impl<'a> FunctionLikeRef<'a> {
+    pub fn parameter_list(&self) -> &'a FunctionLikeParameterList {
+        match self {
+            Self::Function(function) => &function.parameter_list,
+            Self::Method(method) => &method.parameter_list,
+            Self::Closure(closure) => &closure.parameter_list,
+            Self::ArrowFunction(arrow_function) => &arrow_function.parameter_list,
+        }
+    }
+
+    pub fn return_type_hint(&self) -> Option<&'a FunctionLikeReturnTypeHint> {
+        match self {
+            Self::Function(function) => function.return_type_hint.as_ref(),
+            Self::Method(method) => method.return_type_hint.as_ref(),
+            Self::Closure(closure) => closure.return_type_hint.as_ref(),
+            Self::ArrowFunction(arrow_function) => arrow_function.return_type_hint.as_ref(),
+        }
+    }
+
+    /// The statement body, if this function-like has one. Arrow functions
+    /// (whose body is a single expression) and abstract or interface methods
+    /// return `None`.
+    pub fn body(&self) -> Option<&'a Block> {
+        match self {
+            Self::Function(function) => Some(&function.body),
+            Self::Method(method) => match &method.body {
+                MethodBody::Concrete(block) => Some(block),
+                MethodBody::Abstract(_) => None,
+            },
+            Self::Closure(closure) => Some(&closure.body),
+            Self::ArrowFunction(_) => None,
+        }
+    }
+
+    pub fn is_static(&self) -> bool {
+        match self {
+            Self::Function(_) => false,
+            Self::Method(method) => method.modifiers.contains_static(),
+            Self::Closure(closure) => closure.r#static.is_some(),
+            Self::ArrowFunction(arrow_function) => arrow_function.r#static.is_some(),
+        }
+    }
+}
+
+/// A reference to any class-like node.
+#[derive(Debug, Clone, Copy)]
+pub enum ClassLikeRef<'a> {
+    Class(&'a Class),
+    AnonymousClass(&'a AnonymousClass),
+    Interface(&'a Interface),
+    Trait(&'a Trait),
+    Enum(&'a Enum),
+}
+
+impl<'a> ClassLikeRef<'a> {
+    /// The declared name of the class-like; `None` for anonymous classes.
+    pub fn name(&self) -> Option<&'a LocalIdentifier> {
+        match self {
+            Self::Class(class) => Some(&class.name),
+            Self::AnonymousClass(_) => None,
+            Self::Interface(interface) => Some(&interface.name),
+            Self::Trait(r#trait) => Some(&r#trait.name),
+            Self::Enum(r#enum) => Some(&r#enum.name),
+        }
+    }
+
+    /// The members of the class-like, unifying the five node kinds so rules
+    /// iterating members (e.g. "every public method needs a docblock") don't
+    /// each write a five-arm match.
+    pub fn members(&self) -> &'a [ClassLikeMember] {
+        match self {
+            Self::Class(class) => class.members.as_slice(),
+            Self::AnonymousClass(class) => class.members.as_slice(),
+            Self::Interface(interface) => interface.members.as_slice(),
+            Self::Trait(r#trait) => r#trait.members.as_slice(),
+            Self::Enum(r#enum) => r#enum.members.as_slice(),
+        }
+    }
+}
+
+/// The nearest function-like ancestor, given the ancestor chain tracked by
+/// the walker (ordered from the program node down to the immediate parent).
+pub fn enclosing_function_like<'a>(ancestors: &[Node<'a>]) -> Option<FunctionLikeRef<'a>> {
+    ancestors.iter().rev().find_map(|ancestor| match ancestor {
+        Node::Function(function) => Some(FunctionLikeRef::Function(function)),
+        Node::Method(method) => Some(FunctionLikeRef::Method(method)),
+        Node::Closure(closure) => Some(FunctionLikeRef::Closure(closure)),
+        Node::ArrowFunction(arrow_function) => Some(FunctionLikeRef::ArrowFunction(arrow_function)),
+        _ => None,
+    })
+}
+
+/// The nearest class-like ancestor, given the walker's ancestor chain.
+pub fn enclosing_class_like<'a>(ancestors: &[Node<'a>]) -> Option<ClassLikeRef<'a>> {
+    ancestors.iter().rev().find_map(|ancestor| match ancestor {
+        Node::Class(class) => Some(ClassLikeRef::Class(class)),
+        Node::AnonymousClass(class) => Some(ClassLikeRef::AnonymousClass(class)),
+        Node::Interface(interface) => Some(ClassLikeRef::Interface(interface)),
+        Node::Trait(r#trait) => Some(ClassLikeRef::Trait(r#trait)),
+        Node::Enum(r#enum) => Some(ClassLikeRef::Enum(r#enum)),
+        _ => None,
+    })
+}
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::array_keys::parse_integer_literal;
+use crate::string_literals::decode_literal;
+
+/// A backed enum case value after constant folding.
+///
+/// Unlike array keys there is no cross-type coercion: `1` and `'1'`
+/// cannot appear in the same enum (the backing type is declared once), so
+/// int and string values only ever compare within their own kind.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum BackedValue {
+    Int(i64),
+    Str(String),
+}
+
+/// Find cases of a backed enum whose folded backing values collide,
+/// returning `(first_occurrence, duplicate)` span pairs over the case
+/// names. PHP fatals on duplicate values when the enum is loaded.
+///
+/// Values that cannot be folded — references to constants, arbitrary
+/// expressions — are skipped rather than guessed at; folding covers
+/// integer and string literals, parentheses, and unary minus.
+pub fn find_duplicate_enum_case_values(interner: &ThreadedInterner, r#enum: &Enum) -> Vec<(Span, Span)> {
+    let mut first_seen: HashMap<BackedValue, Span> = HashMap::new();
+    let mut duplicates = Vec::new();
+
+    for member in r#enum.members.iter() {
+        let ClassLikeMember::EnumCase(case) = member else {
+            continue;
+        };
+
+        let EnumCaseItem::Backed(item) = &case.item else {
+            continue;
+        };
+
+        let Some(value) = fold(interner, &item.value) else {
+            continue;
+        };
+
+        match first_seen.get(&value) {
+            Some(first) => duplicates.push((*first, item.name.span())),
+            None => {
+                first_seen.insert(value, item.name.span());
+            }
+        }
+    }
+
+    duplicates
+}
+
+fn fold(interner: &ThreadedInterner, value: &Expression) -> Option<BackedValue> {
+    match value {
+        Expression::Parenthesized(inner) => fold(interner, &inner.expression),
+        Expression::Literal(Literal::Integer(literal)) => {
+            Some(BackedValue::Int(parse_integer_literal(interner.lookup(&literal.value))?))
+        }
+        Expression::Literal(Literal::String(literal)) => {
+            Some(BackedValue::Str(decode_literal(interner.lookup(&literal.value))))
+        }
+        Expression::UnaryPrefix(unary) if matches!(unary.operator, UnaryPrefixOperator::Minus(_)) => {
+            match fold(interner, &unary.operand)? {
+                BackedValue::Int(folded) => Some(BackedValue::Int(folded.checked_neg()?)),
+                BackedValue::Str(_) => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_interner::ThreadedInterner;
+
+    use super::*;
+
+    fn duplicates_in(source: &str) -> usize {
+        let interner = ThreadedInterner::new();
+        let (program, error) = mago_parser::parse_source_text(&interner, source);
+        assert!(error.is_none(), "test source must parse");
+
+        let mut stack = vec![Node::Program(&program)];
+        while let Some(node) = stack.pop() {
+            if let Node::Statement(Statement::Enum(r#enum)) = node {
+                return find_duplicate_enum_case_values(&interner, r#enum).len();
+            }
+            stack.extend(node.children());
+        }
+
+        panic!("no enum in test source");
+    }
+
+    #[test]
+    fn test_duplicate_int_values_collide_across_notations() {
+        assert_eq!(duplicates_in("<?php enum Status: int { case A = 1; case B = 0x1; }"), 1);
+        assert_eq!(duplicates_in("<?php enum Status: int { case A = 1; case B = 2; }"), 0);
+    }
+
+    #[test]
+    fn test_duplicate_string_values_compare_decoded() {
+        assert_eq!(duplicates_in("<?php enum Kind: string { case A = 'x'; case B = \"x\"; }"), 1);
+    }
+
+    #[test]
+    fn test_unit_enums_have_nothing_to_compare() {
+        assert_eq!(duplicates_in("<?php enum Direction { case Up; case Down; }"), 0);
+    }
+
+    #[test]
+    fn test_unfoldable_values_are_skipped() {
+        assert_eq!(duplicates_in("<?php enum Kind: int { case A = self::BASE; case B = self::BASE; }"), 0);
+    }
+}
@@ -0,0 +1,52 @@
+use mago_ast::*;
+use mago_span::HasSpan;
+
+/// Whether `target` is always evaluated when `root` is.
+///
+/// Extract-variable refactors must not hoist a subexpression across a
+/// short-circuit boundary — the right-hand side of `&&`, `||`, `and`, `or`,
+/// `??`, `??=`, the branches of `?:` and `match`, or a function-like body —
+/// because doing so changes when (and whether) the expression runs. This
+/// returns `true` only for positions outside every such boundary, so a
+/// hoist right before `root` is evaluation-order safe.
+///
+/// `target` is identified by span, so pass a node taken from `root` itself.
+pub fn subexpression_is_unconditionally_evaluated(root: &Expression, target: &Expression) -> bool {
+    let target_span = target.span();
+    if root.span() == target_span {
+        return true;
+    }
+
+    // Descend only through children that are unconditionally evaluated
+    // whenever the current node is; conditional children are skipped
+    // entirely, so a target inside them is never reached.
+    fn search(node: Node<'_>, target: mago_span::Span) -> bool {
+        if node.span() == target && matches!(node, Node::Expression(_)) {
+            return true;
+        }
+
+        let children: Vec<Node<'_>> = match node {
+            // Function-like bodies run later (or never); nothing inside is
+            // evaluated as part of the surrounding expression.
+            Node::Closure(_) | Node::ArrowFunction(_) | Node::AnonymousClass(_) => return false,
+            Node::Expression(expression) => match expression {
+                Expression::Binary(binary) if binary.operator.is_short_circuiting() => {
+                    vec![Node::Expression(&binary.lhs)]
+                }
+                Expression::Assignment(assignment)
+                    if matches!(assignment.operator, AssignmentOperator::Coalesce(_)) =>
+                {
+                    vec![Node::Expression(&assignment.lhs)]
+                }
+                Expression::Conditional(conditional) => vec![Node::Expression(&conditional.condition)],
+                Expression::Match(r#match) => vec![Node::Expression(&r#match.expression)],
+                _ => node.children(),
+            },
+            _ => node.children(),
+        };
+
+        children.into_iter().any(|child| search(child, target))
+    }
+
+    search(Node::Expression(root), target_span)
+}
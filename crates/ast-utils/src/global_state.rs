@@ -0,0 +1,197 @@
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::identifier::normalize_identifier;
+
+/// PHP's superglobals, as spelled in source.
+pub const SUPERGLOBALS: &[&str] =
+    &["$_GET", "$_POST", "$_COOKIE", "$_REQUEST", "$_SERVER", "$_SESSION", "$_FILES", "$_ENV", "$GLOBALS"];
+
+/// Functions that mutate process-wide state when called.
+const GLOBAL_STATE_MUTATORS: &[&str] =
+    &["setlocale", "date_default_timezone_set", "putenv", "ini_set", "set_time_limit", "chdir", "umask"];
+
+/// One access to global state inside a function-like body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GlobalStateUsage {
+    /// A superglobal read or write; `name` is one of [`SUPERGLOBALS`].
+    /// Writes are assignments (including array writes and appends like
+    /// `$_SESSION['k'][] = ...`) whose left-hand side bottoms out in the
+    /// superglobal.
+    Superglobal { span: Span, name: &'static str, write: bool },
+    /// One variable bound by a `global` statement.
+    GlobalBinding { span: Span, variable: String },
+    /// A call to a process-wide mutator such as `setlocale` or `putenv`.
+    Mutator { span: Span, function: String },
+}
+
+impl GlobalStateUsage {
+    pub fn span(&self) -> Span {
+        match self {
+            GlobalStateUsage::Superglobal { span, .. }
+            | GlobalStateUsage::GlobalBinding { span, .. }
+            | GlobalStateUsage::Mutator { span, .. } => *span,
+        }
+    }
+}
+
+/// Collect every global-state access in `body`, in source order.
+///
+/// Superglobals are found wherever a direct variable can appear —
+/// including inside string interpolation — and classified read or write
+/// by whether an enclosing assignment's target bottoms out in them.
+/// Nested closures, named functions, and anonymous classes are not
+/// descended into (their accesses are their own problem); arrow function
+/// bodies are, since they execute in the enclosing call.
+pub fn collect_global_state_usages(interner: &ThreadedInterner, body: &Block) -> Vec<GlobalStateUsage> {
+    let mut usages = Vec::new();
+    let mut write_spans: Vec<Span> = Vec::new();
+
+    // First pass: find assignment targets rooted in a superglobal, so the
+    // second pass can classify those variable occurrences as writes.
+    let mut stack = vec![Node::Block(body)];
+    while let Some(node) = stack.pop() {
+        match node {
+            Node::Closure(_) | Node::Function(_) | Node::AnonymousClass(_) => continue,
+            Node::Expression(Expression::Assignment(assignment)) => {
+                if let Some(variable) = assignment_target_base(&assignment.lhs) {
+                    write_spans.push(variable.span());
+                }
+                stack.extend(node.children());
+            }
+            _ => stack.extend(node.children()),
+        }
+    }
+
+    let mut stack = vec![Node::Block(body)];
+    while let Some(node) = stack.pop() {
+        match node {
+            Node::Closure(_) | Node::Function(_) | Node::AnonymousClass(_) => continue,
+            Node::DirectVariable(variable) => {
+                let name = interner.lookup(&variable.name);
+                if let Some(superglobal) = SUPERGLOBALS.iter().find(|candidate| **candidate == name) {
+                    let span = variable.span();
+                    usages.push(GlobalStateUsage::Superglobal {
+                        span,
+                        name: superglobal,
+                        write: write_spans.contains(&span),
+                    });
+                }
+            }
+            Node::Statement(Statement::Global(global)) => {
+                for variable in global.variables.iter() {
+                    if let Variable::Direct(direct) = variable {
+                        usages.push(GlobalStateUsage::GlobalBinding {
+                            span: direct.span(),
+                            variable: interner.lookup(&direct.name).to_owned(),
+                        });
+                    }
+                }
+                stack.extend(node.children());
+            }
+            Node::Expression(Expression::Call(Call::Function(call))) => {
+                if let Expression::Identifier(identifier) = call.function.as_ref() {
+                    let name = normalize_identifier(identifier, interner, true);
+                    if GLOBAL_STATE_MUTATORS.contains(&name.as_str()) {
+                        usages.push(GlobalStateUsage::Mutator { span: call.span(), function: name });
+                    }
+                }
+                stack.extend(node.children());
+            }
+            _ => stack.extend(node.children()),
+        }
+    }
+
+    usages.sort_by_key(|usage| usage.span().start.offset);
+    usages
+}
+
+/// The direct variable an assignment target bottoms out in, drilling
+/// through array accesses and appends: `$_SESSION['a']['b'][] = ...`
+/// roots in `$_SESSION`.
+fn assignment_target_base(target: &Expression) -> Option<&DirectVariable> {
+    match target {
+        Expression::Variable(Variable::Direct(variable)) => Some(variable),
+        Expression::ArrayAccess(access) => assignment_target_base(&access.array),
+        Expression::ArrayAppend(append) => assignment_target_base(&append.array),
+        Expression::Parenthesized(inner) => assignment_target_base(&inner.expression),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_interner::ThreadedInterner;
+
+    use super::*;
+
+    fn usages_in(source: &str) -> Vec<GlobalStateUsage> {
+        let interner = ThreadedInterner::new();
+        let (program, error) = mago_parser::parse_source_text(&interner, source);
+        assert!(error.is_none(), "test source must parse");
+
+        let mut stack = vec![Node::Program(&program)];
+        while let Some(node) = stack.pop() {
+            if let Node::Function(function) = node {
+                return collect_global_state_usages(&interner, &function.body);
+            }
+            stack.extend(node.children());
+        }
+
+        panic!("no function in test source");
+    }
+
+    #[test]
+    fn test_superglobal_reads_and_writes_are_distinguished() {
+        let usages = usages_in("<?php function f() { $x = $_GET['id']; $_SESSION['x'][] = $x; }");
+        assert_eq!(usages.len(), 2);
+        assert!(matches!(&usages[0], GlobalStateUsage::Superglobal { name: "$_GET", write: false, .. }));
+        assert!(matches!(&usages[1], GlobalStateUsage::Superglobal { name: "$_SESSION", write: true, .. }));
+    }
+
+    #[test]
+    fn test_globals_array_write() {
+        let usages = usages_in("<?php function f() { $GLOBALS['x'] = 1; }");
+        assert!(matches!(&usages[0], GlobalStateUsage::Superglobal { name: "$GLOBALS", write: true, .. }));
+    }
+
+    #[test]
+    fn test_superglobal_inside_string_interpolation_counts_as_read() {
+        let usages = usages_in("<?php function f() { echo \"host: {$_SERVER['HTTP_HOST']}\"; }");
+        assert!(matches!(&usages[0], GlobalStateUsage::Superglobal { name: "$_SERVER", write: false, .. }));
+    }
+
+    #[test]
+    fn test_global_statement_bindings() {
+        let usages = usages_in("<?php function f() { global $db, $config; }");
+        let names: Vec<&str> = usages
+            .iter()
+            .filter_map(|usage| match usage {
+                GlobalStateUsage::GlobalBinding { variable, .. } => Some(variable.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(names, vec!["$db", "$config"]);
+    }
+
+    #[test]
+    fn test_mutator_calls_are_collected_case_insensitively() {
+        let usages = usages_in("<?php function f() { \\PutEnv('TZ=UTC'); date_default_timezone_set('UTC'); }");
+        let functions: Vec<&str> = usages
+            .iter()
+            .filter_map(|usage| match usage {
+                GlobalStateUsage::Mutator { function, .. } => Some(function.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(functions, vec!["putenv", "date_default_timezone_set"]);
+    }
+
+    #[test]
+    fn test_nested_closures_are_not_descended_into() {
+        let usages = usages_in("<?php function f() { $g = function () { return $_POST; }; }");
+        assert!(usages.is_empty());
+    }
+}
@@ -0,0 +1,46 @@
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+
+/// Every label declared in the block, recursing through nested statements
+/// but not into nested function-likes — labels are function-scoped in PHP.
+pub fn collect_labels(block: &Block) -> Vec<&Label> {
+    let mut labels = Vec::new();
+    let mut stack = vec![Node::Block(block)];
+    while let Some(node) = stack.pop() {
+        match node {
+            Node::Closure(_) | Node::ArrowFunction(_) | Node::AnonymousClass(_) => continue,
+            Node::Label(label) => labels.push(label),
+            _ => stack.extend(node.children()),
+        }
+    }
+
+    labels
+}
+
+/// Every `goto` statement in the block, same scoping rules as
+/// [`collect_labels`].
+pub fn collect_gotos(block: &Block) -> Vec<&Goto> {
+    let mut gotos = Vec::new();
+    let mut stack = vec![Node::Block(block)];
+    while let Some(node) = stack.pop() {
+        match node {
+            Node::Closure(_) | Node::ArrowFunction(_) | Node::AnonymousClass(_) => continue,
+            Node::Goto(goto) => gotos.push(goto),
+            _ => stack.extend(node.children()),
+        }
+    }
+
+    gotos
+}
+
+/// Whether the `goto` names one of the collected labels.
+///
+/// Note that name resolution is necessary but not sufficient for validity:
+/// PHP additionally forbids jumping *into* a loop or `switch` body, which
+/// depends on the relative nesting of the `goto` and its label and is left
+/// to the caller (the engine reports it as a fatal error at compile time).
+pub fn goto_targets_defined_label(interner: &ThreadedInterner, goto: &Goto, labels: &[&Label]) -> bool {
+    let target = interner.lookup(&goto.label.value);
+
+    labels.iter().any(|label| interner.lookup(&label.name.value) == target)
+}
@@ -0,0 +1,326 @@
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::identifier::normalize_identifier;
+
+/// A violation of the DNF type grammar or of typed-constant rules,
+/// detected on an already-parsed [`Hint`].
+///
+/// The parser accepts the general hint shape so it can keep going and
+/// report *these* targeted diagnostics instead of a generic
+/// unexpected-token error at the first `&` or `(`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HintViolation {
+    /// An intersection appearing as a direct union member, e.g.
+    /// `A&B|C`: since PHP 8.2, intersections mixed with unions must be
+    /// parenthesized (`(A&B)|C`).
+    UnparenthesizedIntersectionInUnion { span: Span },
+    /// A parenthesized group whose content is not an intersection, e.g.
+    /// `(A|B)&C` or `((A&B))`: DNF allows parentheses only around
+    /// intersections, and only one level deep.
+    ParenthesesAroundNonIntersection { span: Span },
+    /// `void`, `never`, or `callable` in a class-constant type, where
+    /// PHP 8.3 disallows them.
+    DisallowedConstantType { span: Span, name: &'static str },
+    /// The same type listed twice in one union, e.g. `int|int` or
+    /// `(A&B)|(B&A)`.
+    DuplicateUnionMember { span: Span, name: String },
+    /// `null` unioned with an already-nullable member, e.g. `?T|null`.
+    RedundantNullable { span: Span },
+}
+
+impl HintViolation {
+    pub fn span(&self) -> Span {
+        match self {
+            HintViolation::UnparenthesizedIntersectionInUnion { span }
+            | HintViolation::ParenthesesAroundNonIntersection { span }
+            | HintViolation::DisallowedConstantType { span, .. }
+            | HintViolation::DuplicateUnionMember { span, .. }
+            | HintViolation::RedundantNullable { span } => *span,
+        }
+    }
+}
+
+/// Validate a hint against the PHP 8.2 DNF grammar and report duplicate
+/// and redundant union members, in source order.
+///
+/// The grammar checks: an intersection may appear bare (`A&B`) or as a
+/// parenthesized union member (`(A&B)|C`), but never unparenthesized
+/// inside a union; parentheses may wrap only intersections; intersection
+/// members must be simple types. The redundancy checks: no union member
+/// may repeat (comparison is by normalized name, so `int|INT` and
+/// `(A&B)|(B&A)` are duplicates), and `null` is redundant next to a `?`-
+/// nullable member.
+pub fn collect_hint_violations(hint: &Hint, interner: &ThreadedInterner) -> Vec<HintViolation> {
+    let mut violations = Vec::new();
+    check_hint(hint, interner, Context::TopLevel, &mut violations);
+    violations.sort_by_key(|violation| violation.span().start.offset);
+    violations
+}
+
+/// Validate a typed class constant: the hint must satisfy
+/// [`collect_hint_violations`] and additionally may not contain `void`,
+/// `never`, or `callable` anywhere. Constants without a hint are fine —
+/// the type is optional.
+pub fn collect_constant_hint_violations(
+    constant: &ClassLikeConstant,
+    interner: &ThreadedInterner,
+) -> Vec<HintViolation> {
+    let Some(hint) = &constant.hint else {
+        return Vec::new();
+    };
+
+    let mut violations = collect_hint_violations(hint, interner);
+    collect_disallowed_constant_types(hint, &mut violations);
+    violations.sort_by_key(|violation| violation.span().start.offset);
+    violations
+}
+
+/// Where in the hint tree a sub-hint sits; the DNF grammar is entirely
+/// positional.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Context {
+    TopLevel,
+    UnionMember,
+    IntersectionMember,
+    Parenthesized,
+}
+
+fn check_hint(hint: &Hint, interner: &ThreadedInterner, context: Context, violations: &mut Vec<HintViolation>) {
+    match hint {
+        Hint::Union(_) => {
+            // `A|B|C` parses as nested binary unions; flattening first
+            // means one redundancy check and one context per member.
+            let members = flatten_union(hint);
+
+            check_union_redundancy(&members, interner, violations);
+
+            for member in members {
+                check_hint(member, interner, Context::UnionMember, violations);
+            }
+        }
+        Hint::Intersection(_) => {
+            if context == Context::UnionMember {
+                violations.push(HintViolation::UnparenthesizedIntersectionInUnion { span: hint.span() });
+            }
+
+            for member in flatten_intersection(hint) {
+                check_hint(member, interner, Context::IntersectionMember, violations);
+            }
+        }
+        Hint::Parenthesized(parenthesized) => {
+            if !matches!(parenthesized.hint.as_ref(), Hint::Intersection(_)) {
+                violations.push(HintViolation::ParenthesesAroundNonIntersection { span: hint.span() });
+            }
+
+            check_hint(&parenthesized.hint, interner, Context::Parenthesized, violations);
+        }
+        Hint::Nullable(nullable) => {
+            check_hint(&nullable.hint, interner, context, violations);
+        }
+        _ => {}
+    }
+}
+
+/// Report duplicates and `?T|null` redundancy among one union's members.
+fn check_union_redundancy(members: &[&Hint], interner: &ThreadedInterner, violations: &mut Vec<HintViolation>) {
+    let mut seen: Vec<String> = Vec::new();
+    let has_nullable_member = members.iter().any(|member| matches!(member, Hint::Nullable(_)));
+
+    for member in members {
+        if has_nullable_member && matches!(member, Hint::Null(_)) {
+            violations.push(HintViolation::RedundantNullable { span: member.span() });
+        }
+
+        let Some(name) = canonical_name(member, interner) else {
+            continue;
+        };
+
+        if seen.contains(&name) {
+            violations.push(HintViolation::DuplicateUnionMember { span: member.span(), name });
+        } else {
+            seen.push(name);
+        }
+    }
+}
+
+fn collect_disallowed_constant_types(hint: &Hint, violations: &mut Vec<HintViolation>) {
+    match hint {
+        Hint::Void(_) => violations.push(HintViolation::DisallowedConstantType { span: hint.span(), name: "void" }),
+        Hint::Never(_) => violations.push(HintViolation::DisallowedConstantType { span: hint.span(), name: "never" }),
+        Hint::Callable(_) => {
+            violations.push(HintViolation::DisallowedConstantType { span: hint.span(), name: "callable" })
+        }
+        Hint::Union(union) => {
+            collect_disallowed_constant_types(&union.left, violations);
+            collect_disallowed_constant_types(&union.right, violations);
+        }
+        Hint::Intersection(intersection) => {
+            collect_disallowed_constant_types(&intersection.left, violations);
+            collect_disallowed_constant_types(&intersection.right, violations);
+        }
+        Hint::Parenthesized(parenthesized) => collect_disallowed_constant_types(&parenthesized.hint, violations),
+        Hint::Nullable(nullable) => collect_disallowed_constant_types(&nullable.hint, violations),
+        _ => {}
+    }
+}
+
+/// A union's members in source order, flattening the parser's binary
+/// nesting: `A|B|C` parses as `(A|B)|C` but is one three-member union.
+fn flatten_union(hint: &Hint) -> Vec<&Hint> {
+    match hint {
+        Hint::Union(union) => {
+            let mut members = flatten_union(&union.left);
+            members.extend(flatten_union(&union.right));
+            members
+        }
+        _ => vec![hint],
+    }
+}
+
+fn flatten_intersection(hint: &Hint) -> Vec<&Hint> {
+    match hint {
+        Hint::Intersection(intersection) => {
+            let mut members = flatten_intersection(&intersection.left);
+            members.extend(flatten_intersection(&intersection.right));
+            members
+        }
+        _ => vec![hint],
+    }
+}
+
+/// The canonical form of a union member for duplicate detection:
+/// lowercased, `\`-stripped, with intersection members sorted so
+/// `(A&B)` and `(B&A)` — the same type — compare equal. Nullable members
+/// return `None`; `?A` and `?B` differing only in the base are not
+/// duplicates of each other, and `?T|null` is reported separately.
+fn canonical_name(hint: &Hint, interner: &ThreadedInterner) -> Option<String> {
+    match hint {
+        Hint::Identifier(identifier) => Some(normalize_identifier(identifier, interner, true)),
+        Hint::Parenthesized(parenthesized) => canonical_name(&parenthesized.hint, interner),
+        Hint::Intersection(_) => {
+            let mut names = flatten_intersection(hint)
+                .into_iter()
+                .map(|member| canonical_name(member, interner))
+                .collect::<Option<Vec<String>>>()?;
+            names.sort();
+            Some(names.join("&"))
+        }
+        Hint::Null(_) => Some("null".to_owned()),
+        Hint::True(_) => Some("true".to_owned()),
+        Hint::False(_) => Some("false".to_owned()),
+        Hint::Array(_) => Some("array".to_owned()),
+        Hint::Callable(_) => Some("callable".to_owned()),
+        Hint::Static(_) => Some("static".to_owned()),
+        Hint::Void(_) => Some("void".to_owned()),
+        Hint::Never(_) => Some("never".to_owned()),
+        Hint::Float(_) => Some("float".to_owned()),
+        Hint::Bool(_) => Some("bool".to_owned()),
+        Hint::Integer(_) => Some("int".to_owned()),
+        Hint::String(_) => Some("string".to_owned()),
+        Hint::Object(_) => Some("object".to_owned()),
+        Hint::Mixed(_) => Some("mixed".to_owned()),
+        Hint::Iterable(_) => Some("iterable".to_owned()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_interner::ThreadedInterner;
+
+    use super::*;
+
+    fn violations_for_parameter(hint_source: &str) -> Vec<HintViolation> {
+        let interner = ThreadedInterner::new();
+        let source = format!("<?php function f({hint_source} $x) {{}}");
+        let (program, error) = mago_parser::parse_source_text(&interner, &source);
+        assert!(error.is_none(), "test source must parse");
+
+        let mut stack = vec![Node::Program(&program)];
+        while let Some(node) = stack.pop() {
+            if let Node::Function(function) = node {
+                let parameter = function.parameter_list.parameters.iter().next().expect("one parameter");
+                let hint = parameter.hint.as_ref().expect("parameter must be typed");
+                return collect_hint_violations(hint, &interner);
+            }
+            stack.extend(node.children());
+        }
+
+        panic!("no function in test source");
+    }
+
+    fn constant_violations(source: &str) -> Vec<HintViolation> {
+        let interner = ThreadedInterner::new();
+        let (program, error) = mago_parser::parse_source_text(&interner, source);
+        assert!(error.is_none(), "test source must parse");
+
+        let mut stack = vec![Node::Program(&program)];
+        while let Some(node) = stack.pop() {
+            if let Node::ClassLikeConstant(constant) = node {
+                return collect_constant_hint_violations(constant, &interner);
+            }
+            stack.extend(node.children());
+        }
+
+        panic!("no class constant in test source");
+    }
+
+    #[test]
+    fn test_valid_dnf_types_pass() {
+        assert!(violations_for_parameter("(A&B)|C").is_empty());
+        assert!(violations_for_parameter("(A&B)|(C&D)|null").is_empty());
+        assert!(violations_for_parameter("A&B").is_empty());
+        assert!(violations_for_parameter("int|string").is_empty());
+    }
+
+    #[test]
+    fn test_bare_intersection_inside_a_union_is_reported() {
+        let violations = violations_for_parameter("A&B|C");
+
+        assert!(violations
+            .iter()
+            .any(|violation| matches!(violation, HintViolation::UnparenthesizedIntersectionInUnion { .. })));
+    }
+
+    #[test]
+    fn test_parentheses_may_wrap_only_intersections() {
+        let violations = violations_for_parameter("(A|B)&C");
+        assert!(violations
+            .iter()
+            .any(|violation| matches!(violation, HintViolation::ParenthesesAroundNonIntersection { .. })));
+    }
+
+    #[test]
+    fn test_duplicate_union_members_including_reordered_intersections() {
+        assert!(matches!(
+            violations_for_parameter("int|string|int").as_slice(),
+            [HintViolation::DuplicateUnionMember { name, .. }] if name == "int",
+        ));
+
+        assert!(violations_for_parameter("(A&B)|(B&A)")
+            .iter()
+            .any(|violation| matches!(violation, HintViolation::DuplicateUnionMember { .. })));
+    }
+
+    #[test]
+    fn test_null_next_to_a_nullable_member_is_redundant() {
+        let violations = violations_for_parameter("?Foo|null");
+
+        assert!(violations.iter().any(|violation| matches!(violation, HintViolation::RedundantNullable { .. })));
+    }
+
+    #[test]
+    fn test_constant_types_reject_void_never_and_callable() {
+        let violations = constant_violations("<?php class C { const callable F = 'strlen'; }");
+        assert!(matches!(
+            violations.as_slice(),
+            [HintViolation::DisallowedConstantType { name: "callable", .. }],
+        ));
+
+        assert!(constant_violations("<?php class C { const int|string V = 1; }").is_empty());
+        assert!(constant_violations("<?php class C { const UNTYPED = 1; }").is_empty());
+    }
+}
@@ -0,0 +1,88 @@
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+
+/// Produce the canonical form of an identifier for name matching.
+///
+/// Normalization strips the leading `\` (a fully-qualified name and the
+/// same name resolved relatively are the same symbol) and lowercases
+/// segments according to PHP's rules:
+///
+/// - with `case_insensitive` (classes, functions, namespaces), every
+///   segment is lowercased;
+/// - without it (constants), the namespace segments are still lowercased
+///   — namespace lookup is always case-insensitive — but the final
+///   segment keeps its case, because `FOO` and `foo` are different
+///   constants.
+///
+/// All three identifier variants (`Local`, `Qualified`,
+/// `FullyQualified`) normalize identically; two identifiers refer to the
+/// same symbol exactly when their normalized forms are equal. Compare
+/// the output against literals written without a leading `\`, e.g.
+/// `normalize_identifier(identifier, interner, true) == "strlen"`.
+pub fn normalize_identifier(identifier: &Identifier, interner: &ThreadedInterner, case_insensitive: bool) -> String {
+    let raw = interner.lookup(&identifier.value());
+    let raw = raw.trim_start_matches('\\');
+
+    if case_insensitive {
+        return raw.to_ascii_lowercase();
+    }
+
+    match raw.rfind('\\') {
+        Some(separator) => {
+            let (namespace, name) = raw.split_at(separator);
+            let mut normalized = namespace.to_ascii_lowercase();
+            normalized.push_str(name);
+            normalized
+        }
+        None => raw.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_interner::ThreadedInterner;
+
+    use super::*;
+
+    fn normalized(source: &str, case_insensitive: bool) -> String {
+        let interner = ThreadedInterner::new();
+        let source = format!("<?php {source}();");
+        let (program, error) = mago_parser::parse_source_text(&interner, &source);
+        assert!(error.is_none(), "test source must parse");
+
+        let mut stack = vec![Node::Program(&program)];
+        while let Some(node) = stack.pop() {
+            if let Node::Expression(Expression::Identifier(identifier)) = node {
+                return normalize_identifier(identifier, &interner, case_insensitive);
+            }
+            stack.extend(node.children());
+        }
+
+        panic!("no identifier in `{source}`");
+    }
+
+    #[test]
+    fn test_leading_backslash_is_stripped() {
+        assert_eq!(normalized("\\strlen", true), "strlen");
+        assert_eq!(normalized("strlen", true), "strlen");
+    }
+
+    #[test]
+    fn test_case_insensitive_lowercases_every_segment() {
+        assert_eq!(normalized("\\Acme\\Util\\StrLen", true), "acme\\util\\strlen");
+    }
+
+    #[test]
+    fn test_constants_keep_final_segment_case() {
+        assert_eq!(normalized("\\Acme\\Util\\MY_CONST", false), "acme\\util\\MY_CONST");
+        assert_eq!(normalized("MY_CONST", false), "MY_CONST");
+    }
+
+    #[test]
+    fn test_variants_normalize_identically() {
+        // A fully-qualified, a qualified, and a local spelling of the same
+        // symbol all normalize to one form.
+        assert_eq!(normalized("\\Acme\\Helper", true), normalized("Acme\\helper", true));
+        assert_eq!(normalized("helper", true), normalized("\\Helper", true));
+    }
+}
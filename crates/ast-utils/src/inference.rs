@@ -0,0 +1,193 @@
+use mago_ast::*;
+
+/// The coarse type an expression syntactically evaluates to.
+///
+/// This is deliberately not a type system: `Unknown` is the honest answer
+/// for anything involving a variable, call, or property — rules built on
+/// this must treat `Unknown` as "do not fire", never as a guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InferredType {
+    Int,
+    Float,
+    String,
+    Bool,
+    Array,
+    Null,
+    Unknown,
+}
+
+/// Classify an expression by syntactic evidence alone.
+///
+/// Covered, in order of reliability:
+///
+/// - literals (`1`, `1.5`, `'x'`, `true`, `null`) and array literals;
+/// - casts — `(int)`, `(float)`, `(string)`, `(bool)`, `(array)` pin the
+///   result type regardless of the operand;
+/// - `.` concatenation (always string), comparisons (always bool), `!`
+///   (always bool), unary `-`/`+` (numeric, preserving the operand type);
+/// - arithmetic: `int op int` is `Int` for `+`, `-`, `*`, `%`, but *not*
+///   for `/` (PHP returns a float unless the division is exact) or `**`
+///   (overflows to float) — both classify as `Unknown`. Any `Float`
+///   operand makes the result `Float`.
+///
+/// Everything else — variables, calls, properties, `??`, ternaries — is
+/// `Unknown`.
+pub fn classify_literal_type(expression: &Expression) -> InferredType {
+    match expression {
+        Expression::Parenthesized(inner) => classify_literal_type(&inner.expression),
+        Expression::Literal(literal) => match literal {
+            Literal::Integer(_) => InferredType::Int,
+            Literal::Float(_) => InferredType::Float,
+            Literal::String(_) => InferredType::String,
+            Literal::True(_) | Literal::False(_) => InferredType::Bool,
+            Literal::Null(_) => InferredType::Null,
+        },
+        Expression::Array(_) | Expression::LegacyArray(_) => InferredType::Array,
+        Expression::UnaryPrefix(unary) => classify_unary(unary),
+        Expression::Binary(binary) => classify_binary(binary),
+        _ => InferredType::Unknown,
+    }
+}
+
+fn classify_unary(unary: &UnaryPrefix) -> InferredType {
+    match &unary.operator {
+        UnaryPrefixOperator::IntCast(..) | UnaryPrefixOperator::IntegerCast(..) => InferredType::Int,
+        UnaryPrefixOperator::FloatCast(..)
+        | UnaryPrefixOperator::DoubleCast(..)
+        | UnaryPrefixOperator::RealCast(..) => InferredType::Float,
+        UnaryPrefixOperator::StringCast(..) | UnaryPrefixOperator::BinaryCast(..) => InferredType::String,
+        UnaryPrefixOperator::BoolCast(..) | UnaryPrefixOperator::BooleanCast(..) => InferredType::Bool,
+        UnaryPrefixOperator::ArrayCast(..) => InferredType::Array,
+        UnaryPrefixOperator::Not(_) => InferredType::Bool,
+        UnaryPrefixOperator::Minus(_) | UnaryPrefixOperator::Plus(_) => {
+            match classify_literal_type(&unary.operand) {
+                numeric @ (InferredType::Int | InferredType::Float) => numeric,
+                _ => InferredType::Unknown,
+            }
+        }
+        _ => InferredType::Unknown,
+    }
+}
+
+fn classify_binary(binary: &Binary) -> InferredType {
+    // Comparisons yield bool — except `<=>`, whose result is an int.
+    if matches!(binary.operator, BinaryOperator::Spaceship(_)) {
+        return InferredType::Int;
+    }
+    if is_boolean_comparison(&binary.operator) {
+        return InferredType::Bool;
+    }
+
+    if matches!(binary.operator, BinaryOperator::StringConcat(_)) {
+        return InferredType::String;
+    }
+
+    let arithmetic = matches!(
+        binary.operator,
+        BinaryOperator::Addition(_)
+            | BinaryOperator::Subtraction(_)
+            | BinaryOperator::Multiplication(_)
+            | BinaryOperator::Modulo(_)
+    );
+    if !arithmetic {
+        // `/` and `**` on integers can produce floats; everything else
+        // (bitwise, logical, coalesce, ...) is out of scope here.
+        return InferredType::Unknown;
+    }
+
+    match (classify_literal_type(&binary.lhs), classify_literal_type(&binary.rhs)) {
+        (InferredType::Int, InferredType::Int) => InferredType::Int,
+        (InferredType::Float, InferredType::Int | InferredType::Float)
+        | (InferredType::Int, InferredType::Float) => InferredType::Float,
+        _ => InferredType::Unknown,
+    }
+}
+
+fn is_boolean_comparison(operator: &BinaryOperator) -> bool {
+    matches!(
+        operator,
+        BinaryOperator::Equal(_)
+            | BinaryOperator::NotEqual(_)
+            | BinaryOperator::Identical(_)
+            | BinaryOperator::NotIdentical(_)
+            | BinaryOperator::AngledNotEqual(_)
+            | BinaryOperator::LessThan(_)
+            | BinaryOperator::LessThanOrEqual(_)
+            | BinaryOperator::GreaterThan(_)
+            | BinaryOperator::GreaterThanOrEqual(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_interner::ThreadedInterner;
+
+    use super::*;
+
+    fn classify(source: &str) -> InferredType {
+        let interner = ThreadedInterner::new();
+        let source = format!("<?php $r = {source};");
+        let (program, error) = mago_parser::parse_source_text(&interner, &source);
+        assert!(error.is_none(), "test source must parse");
+
+        let mut stack = vec![Node::Program(&program)];
+        while let Some(node) = stack.pop() {
+            if let Node::Expression(Expression::Assignment(assignment)) = node {
+                return classify_literal_type(&assignment.rhs);
+            }
+            stack.extend(node.children());
+        }
+
+        panic!("no assignment in test source");
+    }
+
+    #[test]
+    fn test_literals_classify_directly() {
+        assert_eq!(classify("1"), InferredType::Int);
+        assert_eq!(classify("1.5"), InferredType::Float);
+        assert_eq!(classify("'x'"), InferredType::String);
+        assert_eq!(classify("true"), InferredType::Bool);
+        assert_eq!(classify("null"), InferredType::Null);
+        assert_eq!(classify("[1, 2]"), InferredType::Array);
+    }
+
+    #[test]
+    fn test_casts_pin_the_type() {
+        assert_eq!(classify("(int) $x"), InferredType::Int);
+        assert_eq!(classify("(float) foo()"), InferredType::Float);
+        assert_eq!(classify("(string) $x"), InferredType::String);
+        assert_eq!(classify("(bool) $x"), InferredType::Bool);
+        assert_eq!(classify("(array) $x"), InferredType::Array);
+    }
+
+    #[test]
+    fn test_concatenation_and_comparison() {
+        assert_eq!(classify("'a' . $x"), InferredType::String);
+        assert_eq!(classify("$a === $b"), InferredType::Bool);
+        assert_eq!(classify("$a < $b"), InferredType::Bool);
+        assert_eq!(classify("$a <=> $b"), InferredType::Int);
+        assert_eq!(classify("!$a"), InferredType::Bool);
+    }
+
+    #[test]
+    fn test_arithmetic_result_rules() {
+        assert_eq!(classify("1 + 2"), InferredType::Int);
+        assert_eq!(classify("1 + 2 * 3"), InferredType::Int);
+        assert_eq!(classify("1 % 2"), InferredType::Int);
+        assert_eq!(classify("1.0 + 2"), InferredType::Float);
+        assert_eq!(classify("-1"), InferredType::Int);
+        assert_eq!(classify("-1.5"), InferredType::Float);
+
+        // `/` and `**` escape the int domain at runtime.
+        assert_eq!(classify("4 / 2"), InferredType::Unknown);
+        assert_eq!(classify("2 ** 3"), InferredType::Unknown);
+    }
+
+    #[test]
+    fn test_variables_and_calls_are_unknown() {
+        assert_eq!(classify("$x"), InferredType::Unknown);
+        assert_eq!(classify("foo()"), InferredType::Unknown);
+        assert_eq!(classify("$a + $b"), InferredType::Unknown);
+        assert_eq!(classify("$a ?? 1"), InferredType::Unknown);
+    }
+}
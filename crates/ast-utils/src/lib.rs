@@ -0,0 +1,24 @@
+pub mod array_keys;
+pub mod assignment;
+pub mod associativity;
+pub mod closures;
+pub mod control_flow;
+pub mod docblock;
+pub mod enclosing;
+pub mod enums;
+pub mod evaluation;
+pub mod global_state;
+pub mod goto;
+pub mod hints;
+pub mod identifier;
+pub mod inference;
+pub mod local_types;
+pub mod lookup;
+pub mod modifier_order;
+pub mod negation;
+pub mod string_literals;
+pub mod structural_eq;
+pub mod ternary;
+pub mod throws;
+pub mod variables;
+pub mod visibility;
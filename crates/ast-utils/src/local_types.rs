@@ -0,0 +1,648 @@
+use std::collections::HashMap;
+
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::control_flow::statement_terminates;
+use crate::identifier::normalize_identifier;
+use crate::inference::classify_literal_type;
+use crate::inference::InferredType;
+
+/// Forward local type inference over one function-like body.
+///
+/// A deliberately modest dataflow pass: per variable, per statement
+/// boundary, a conservative union of [`TypeAtom`]s derived from parameter
+/// hints, literal assignments, `new` expressions, oracle-known function
+/// returns, and condition narrowing (`instanceof`, the `is_*` family,
+/// `=== null` / `!== null`). Anything it cannot model — compound
+/// assignments, destructuring, by-reference captures, merge points with
+/// incompatible branches it cannot represent — widens to [`TypeUnion::Mixed`],
+/// so a non-`Mixed` answer is a promise, never a guess. No generics, no
+/// array shapes.
+///
+/// The lint context exposes the result as `type_of(variable, at_span)`,
+/// memoizing one [`LocalTypes`] per enclosing function-like; rules should
+/// go through that rather than running the pass themselves.
+pub fn infer_local_types(
+    interner: &ThreadedInterner,
+    parameters: Option<&FunctionLikeParameterList>,
+    body: &Block,
+    oracle: &dyn TypeOracle,
+) -> LocalTypes {
+    let mut state: HashMap<String, TypeUnion> = HashMap::new();
+
+    if let Some(parameters) = parameters {
+        for parameter in parameters.parameters.iter() {
+            let name = interner.lookup(&parameter.variable.name).to_owned();
+            let union = match &parameter.hint {
+                Some(hint) => hint_union(hint, interner),
+                None => TypeUnion::Mixed,
+            };
+            state.insert(name, union);
+        }
+    }
+
+    let mut pass = Pass { interner, oracle, snapshots: Vec::new() };
+    pass.run(body.statements.as_slice(), &mut state);
+    pass.snapshots.push((body.span().end.offset, state));
+
+    LocalTypes { snapshots: pass.snapshots }
+}
+
+/// External knowledge the pass consults for call return types — backed by
+/// the codebase index and stubs in the linter, by nothing in tests.
+/// Extension point: richer oracles (method returns, property types) plug
+/// in here without touching the dataflow itself.
+pub trait TypeOracle {
+    /// The return type of a free function, by normalized (lowercased,
+    /// unqualified) name; `None` when unknown.
+    fn function_return(&self, _name: &str) -> Option<TypeUnion> {
+        None
+    }
+}
+
+/// The oracle that knows nothing; every call returns `Mixed`.
+pub struct NoOracle;
+
+impl TypeOracle for NoOracle {}
+
+/// One indivisible type the pass tracks.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TypeAtom {
+    Null,
+    Bool,
+    Int,
+    Float,
+    String,
+    Array,
+    /// An object; the normalized class name when known, `None` for a bare
+    /// `object`.
+    Object(Option<String>),
+}
+
+/// A conservative union of atoms, or `Mixed` when the pass gave up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeUnion {
+    Mixed,
+    Of(Vec<TypeAtom>),
+}
+
+impl TypeUnion {
+    /// A union of the given atoms; empty input collapses to `Mixed`
+    /// rather than inventing an uninhabited type.
+    pub fn of(atoms: impl IntoIterator<Item = TypeAtom>) -> TypeUnion {
+        let mut atoms: Vec<TypeAtom> = atoms.into_iter().collect();
+        atoms.sort();
+        atoms.dedup();
+
+        if atoms.is_empty() { TypeUnion::Mixed } else { TypeUnion::Of(atoms) }
+    }
+
+    pub fn single(atom: TypeAtom) -> TypeUnion {
+        TypeUnion::Of(vec![atom])
+    }
+
+    pub fn is_mixed(&self) -> bool {
+        matches!(self, TypeUnion::Mixed)
+    }
+
+    /// Whether the union certainly excludes `null`. `Mixed` may be null.
+    pub fn is_non_null(&self) -> bool {
+        match self {
+            TypeUnion::Mixed => false,
+            TypeUnion::Of(atoms) => !atoms.contains(&TypeAtom::Null),
+        }
+    }
+
+    /// The merge of two control-flow branches.
+    pub fn merged(&self, other: &TypeUnion) -> TypeUnion {
+        match (self, other) {
+            (TypeUnion::Of(left), TypeUnion::Of(right)) => {
+                TypeUnion::of(left.iter().chain(right.iter()).cloned())
+            }
+            _ => TypeUnion::Mixed,
+        }
+    }
+
+    /// The union with one atom removed — the `else` side of an `is_*`
+    /// check. Removing from `Mixed`, or removing the last atom, yields
+    /// `Mixed`: the pass cannot represent "anything but X" or "nothing".
+    fn without(&self, atom: &TypeAtom) -> TypeUnion {
+        match self {
+            TypeUnion::Of(atoms) if atoms.len() > 1 => {
+                TypeUnion::of(atoms.iter().filter(|candidate| *candidate != atom).cloned())
+            }
+            _ => TypeUnion::Mixed,
+        }
+    }
+}
+
+/// The pass result: per-variable types at every statement boundary.
+#[derive(Debug, Clone)]
+pub struct LocalTypes {
+    /// `(offset, state)` snapshots in ascending offset order: the state
+    /// *entering* each statement, plus one at the body's end.
+    snapshots: Vec<(usize, HashMap<String, TypeUnion>)>,
+}
+
+impl LocalTypes {
+    /// The type of `variable` (spelled with its `$`) at the statement
+    /// boundary nearest before `at`; `Mixed` for unknown variables and
+    /// positions before the first statement.
+    pub fn type_of(&self, variable: &str, at: Span) -> TypeUnion {
+        let index = self.snapshots.partition_point(|(offset, _)| *offset <= at.start.offset);
+        if index == 0 {
+            return TypeUnion::Mixed;
+        }
+
+        self.snapshots[index - 1].1.get(variable).cloned().unwrap_or(TypeUnion::Mixed)
+    }
+}
+
+struct Pass<'a> {
+    interner: &'a ThreadedInterner,
+    oracle: &'a dyn TypeOracle,
+    snapshots: Vec<(usize, HashMap<String, TypeUnion>)>,
+}
+
+impl Pass<'_> {
+    fn run(&mut self, statements: &[Statement], state: &mut HashMap<String, TypeUnion>) {
+        for statement in statements {
+            self.snapshots.push((statement.span().start.offset, state.clone()));
+            self.apply_statement(statement, state);
+        }
+    }
+
+    fn apply_statement(&mut self, statement: &Statement, state: &mut HashMap<String, TypeUnion>) {
+        match statement {
+            Statement::Expression(expression) => self.apply_expression(&expression.expression, state),
+            Statement::Block(block) => self.run(block.statements.as_slice(), state),
+            Statement::If(r#if) => self.apply_if(statement, r#if, state),
+            Statement::While(_) | Statement::DoWhile(_) | Statement::For(_) | Statement::Foreach(_) => {
+                self.apply_loop(statement, state)
+            }
+            Statement::Return(r#return) => {
+                if let Some(value) = &r#return.value {
+                    self.apply_expression(value, state);
+                }
+            }
+            _ => {
+                // Statements the pass does not model: widen whatever they
+                // assign, so the answer stays conservative.
+                widen_assigned_variables(Node::Statement(statement), self.interner, state);
+            }
+        }
+    }
+
+    fn apply_if(&mut self, statement: &Statement, r#if: &If, state: &mut HashMap<String, TypeUnion>) {
+        self.apply_expression(&r#if.condition, state);
+
+        // `elseif` chains multiply the merge points beyond what this pass
+        // represents, and colon-delimited bodies are not worth a second
+        // code path; widen and move on.
+        let IfBody::Statement(body) = &r#if.body else {
+            widen_assigned_variables(Node::Statement(statement), self.interner, state);
+            return;
+        };
+        if r#if.body.has_else_if() {
+            widen_assigned_variables(Node::Statement(statement), self.interner, state);
+            return;
+        }
+
+        let (mut then_state, mut else_state) = self.narrowed(&r#if.condition, state);
+
+        self.run(std::slice::from_ref(&body.statement), &mut then_state);
+        let then_terminates = statement_terminates(&body.statement);
+
+        let else_terminates = match &body.else_clause {
+            Some(clause) => {
+                self.run(std::slice::from_ref(clause.statement.as_ref()), &mut else_state);
+                statement_terminates(&clause.statement)
+            }
+            None => false,
+        };
+
+        // Early returns narrow the fall-through: `if ($x === null) {
+        // return; }` leaves only the else state alive.
+        *state = match (then_terminates, else_terminates) {
+            (true, true) => state.clone(),
+            (true, false) => else_state,
+            (false, true) => then_state,
+            (false, false) => merge_states(&then_state, &else_state),
+        };
+    }
+
+    fn apply_loop(&mut self, statement: &Statement, state: &mut HashMap<String, TypeUnion>) {
+        // A loop body may run zero or many times; a fixpoint is more than
+        // this pass attempts. Everything assigned inside (including
+        // `foreach` targets) widens to `Mixed` both inside and after.
+        widen_assigned_variables(Node::Statement(statement), self.interner, state);
+
+        // Still walk braced bodies so statements inside get snapshots.
+        match statement {
+            Statement::Foreach(foreach) => {
+                for variable in foreach_target_variables(&foreach.target) {
+                    state.insert(self.interner.lookup(&variable.name).to_owned(), TypeUnion::Mixed);
+                }
+
+                if let ForeachBody::Statement(body) = &foreach.body {
+                    self.run(std::slice::from_ref(body), state);
+                }
+            }
+            Statement::While(r#while) => {
+                if let WhileBody::Statement(body) = &r#while.body {
+                    self.run(std::slice::from_ref(body), state);
+                }
+            }
+            Statement::For(r#for) => {
+                if let ForBody::Statement(body) = &r#for.body {
+                    self.run(std::slice::from_ref(body), state);
+                }
+            }
+            Statement::DoWhile(do_while) => {
+                self.run(std::slice::from_ref(do_while.statement.as_ref()), state);
+            }
+            _ => unreachable!("apply_loop is only called for loop statements"),
+        }
+    }
+
+    /// Record assignments found anywhere in an expression.
+    fn apply_expression(&mut self, expression: &Expression, state: &mut HashMap<String, TypeUnion>) {
+        let mut stack = vec![Node::Expression(expression)];
+        while let Some(node) = stack.pop() {
+            match node {
+                Node::Closure(_) | Node::ArrowFunction(_) | Node::Function(_) | Node::AnonymousClass(_) => continue,
+                Node::Expression(Expression::Assignment(assignment)) => {
+                    if let Expression::Variable(Variable::Direct(variable)) = assignment.lhs.as_ref() {
+                        let name = self.interner.lookup(&variable.name).to_owned();
+                        let union = if matches!(assignment.operator, AssignmentOperator::Assign(_)) {
+                            self.infer(&assignment.rhs, state)
+                        } else {
+                            TypeUnion::Mixed
+                        };
+                        state.insert(name, union);
+                    } else {
+                        widen_assigned_variables(node, self.interner, state);
+                    }
+
+                    stack.push(Node::Expression(&assignment.rhs));
+                }
+                _ => stack.extend(node.children()),
+            }
+        }
+    }
+
+    /// The type of an expression under the current state.
+    fn infer(&self, expression: &Expression, state: &HashMap<String, TypeUnion>) -> TypeUnion {
+        match classify_literal_type(expression) {
+            InferredType::Int => return TypeUnion::single(TypeAtom::Int),
+            InferredType::Float => return TypeUnion::single(TypeAtom::Float),
+            InferredType::String => return TypeUnion::single(TypeAtom::String),
+            InferredType::Bool => return TypeUnion::single(TypeAtom::Bool),
+            InferredType::Array => return TypeUnion::single(TypeAtom::Array),
+            InferredType::Null => return TypeUnion::single(TypeAtom::Null),
+            InferredType::Unknown => {}
+        }
+
+        match expression {
+            Expression::Variable(Variable::Direct(variable)) => {
+                state.get(self.interner.lookup(&variable.name)).cloned().unwrap_or(TypeUnion::Mixed)
+            }
+            Expression::Instantiation(instantiation) => match instantiation.class.as_ref() {
+                Expression::Identifier(identifier) => TypeUnion::single(TypeAtom::Object(Some(
+                    normalize_identifier(identifier, self.interner, true),
+                ))),
+                _ => TypeUnion::single(TypeAtom::Object(None)),
+            },
+            Expression::Call(Call::Function(call)) => match call.function.as_ref() {
+                Expression::Identifier(identifier) => self
+                    .oracle
+                    .function_return(&normalize_identifier(identifier, self.interner, true))
+                    .unwrap_or(TypeUnion::Mixed),
+                _ => TypeUnion::Mixed,
+            },
+            _ => TypeUnion::Mixed,
+        }
+    }
+
+    /// The states for a condition's true and false branches.
+    fn narrowed(
+        &self,
+        condition: &Expression,
+        state: &HashMap<String, TypeUnion>,
+    ) -> (HashMap<String, TypeUnion>, HashMap<String, TypeUnion>) {
+        let mut then_state = state.clone();
+        let mut else_state = state.clone();
+        self.narrow_into(condition, &mut then_state, &mut else_state);
+        (then_state, else_state)
+    }
+
+    fn narrow_into(
+        &self,
+        condition: &Expression,
+        then_state: &mut HashMap<String, TypeUnion>,
+        else_state: &mut HashMap<String, TypeUnion>,
+    ) {
+        match condition {
+            Expression::Parenthesized(inner) => self.narrow_into(&inner.expression, then_state, else_state),
+            Expression::UnaryPrefix(unary) if unary.operator.is_not() => {
+                self.narrow_into(&unary.operand, else_state, then_state);
+            }
+            Expression::Binary(binary) => match &binary.operator {
+                BinaryOperator::Instanceof(_) => {
+                    if let (Expression::Variable(Variable::Direct(variable)), Expression::Identifier(class)) =
+                        (binary.lhs.as_ref(), binary.rhs.as_ref())
+                    {
+                        then_state.insert(
+                            self.interner.lookup(&variable.name).to_owned(),
+                            TypeUnion::single(TypeAtom::Object(Some(normalize_identifier(
+                                class,
+                                self.interner,
+                                true,
+                            )))),
+                        );
+                        // The false branch learns nothing: the variable may
+                        // still be a subclass-free object or anything else.
+                    }
+                }
+                BinaryOperator::Identical(_) => self.narrow_null_check(binary, then_state, else_state),
+                BinaryOperator::NotIdentical(_) => self.narrow_null_check(binary, else_state, then_state),
+                // `&&` narrows its true branch cumulatively; the false
+                // branch is a disjunction this pass cannot represent.
+                BinaryOperator::And(_) => {
+                    let mut ignored = else_state.clone();
+                    self.narrow_into(&binary.lhs, then_state, &mut ignored);
+                    let mut ignored = else_state.clone();
+                    self.narrow_into(&binary.rhs, then_state, &mut ignored);
+                }
+                _ => {}
+            },
+            Expression::Call(Call::Function(call)) => {
+                let Expression::Identifier(identifier) = call.function.as_ref() else {
+                    return;
+                };
+                let Some(argument) = first_argument_variable(call) else {
+                    return;
+                };
+                let Some(atom) = is_check_atom(&normalize_identifier(identifier, self.interner, true)) else {
+                    return;
+                };
+
+                let name = self.interner.lookup(&argument.name).to_owned();
+                let current = else_state.get(&name).cloned().unwrap_or(TypeUnion::Mixed);
+                then_state.insert(name.clone(), TypeUnion::single(atom.clone()));
+                else_state.insert(name, current.without(&atom));
+            }
+            _ => {}
+        }
+    }
+
+    /// Narrow `$x === null` style checks: `null_state` is the branch where
+    /// the variable is null, `other_state` the one where it is not.
+    fn narrow_null_check(
+        &self,
+        binary: &Binary,
+        null_state: &mut HashMap<String, TypeUnion>,
+        other_state: &mut HashMap<String, TypeUnion>,
+    ) {
+        let variable = match (binary.lhs.as_ref(), binary.rhs.as_ref()) {
+            (Expression::Variable(Variable::Direct(variable)), Expression::Literal(Literal::Null(_)))
+            | (Expression::Literal(Literal::Null(_)), Expression::Variable(Variable::Direct(variable))) => variable,
+            _ => return,
+        };
+
+        let name = self.interner.lookup(&variable.name).to_owned();
+        let current = other_state.get(&name).cloned().unwrap_or(TypeUnion::Mixed);
+        null_state.insert(name.clone(), TypeUnion::single(TypeAtom::Null));
+        other_state.insert(name, current.without(&TypeAtom::Null));
+    }
+}
+
+/// The atom an `is_*` predicate asserts, by normalized function name.
+fn is_check_atom(function: &str) -> Option<TypeAtom> {
+    Some(match function {
+        "is_string" => TypeAtom::String,
+        "is_int" | "is_integer" | "is_long" => TypeAtom::Int,
+        "is_float" | "is_double" => TypeAtom::Float,
+        "is_bool" => TypeAtom::Bool,
+        "is_array" => TypeAtom::Array,
+        "is_null" => TypeAtom::Null,
+        "is_object" => TypeAtom::Object(None),
+        _ => return None,
+    })
+}
+
+fn first_argument_variable(call: &FunctionCall) -> Option<&DirectVariable> {
+    let argument = call.argument_list.arguments.as_slice().first()?;
+    match argument.value() {
+        Expression::Variable(Variable::Direct(variable)) => Some(variable),
+        _ => None,
+    }
+}
+
+/// Merge two branch states variable-wise; a variable missing from one
+/// side was never assigned there and merges as `Mixed`.
+fn merge_states(
+    left: &HashMap<String, TypeUnion>,
+    right: &HashMap<String, TypeUnion>,
+) -> HashMap<String, TypeUnion> {
+    let mut merged = HashMap::new();
+    for (name, union) in left {
+        merged.insert(
+            name.clone(),
+            match right.get(name) {
+                Some(other) => union.merged(other),
+                None => TypeUnion::Mixed,
+            },
+        );
+    }
+    for name in right.keys() {
+        merged.entry(name.clone()).or_insert(TypeUnion::Mixed);
+    }
+
+    merged
+}
+
+/// Set every variable assigned anywhere under `node` to `Mixed`.
+fn widen_assigned_variables(node: Node<'_>, interner: &ThreadedInterner, state: &mut HashMap<String, TypeUnion>) {
+    let mut stack = vec![node];
+    while let Some(node) = stack.pop() {
+        match node {
+            Node::Closure(_) | Node::ArrowFunction(_) | Node::Function(_) | Node::AnonymousClass(_) => continue,
+            Node::Expression(Expression::Assignment(assignment)) => {
+                let mut targets = vec![assignment.lhs.as_ref()];
+                while let Some(target) = targets.pop() {
+                    match target {
+                        Expression::Variable(Variable::Direct(variable)) => {
+                            state.insert(interner.lookup(&variable.name).to_owned(), TypeUnion::Mixed);
+                        }
+                        Expression::Parenthesized(inner) => targets.push(&inner.expression),
+                        _ => {}
+                    }
+                }
+
+                stack.extend(node.children());
+            }
+            Node::Foreach(foreach) => {
+                for variable in foreach_target_variables(&foreach.target) {
+                    state.insert(interner.lookup(&variable.name).to_owned(), TypeUnion::Mixed);
+                }
+
+                stack.extend(node.children());
+            }
+            _ => stack.extend(node.children()),
+        }
+    }
+}
+
+fn foreach_target_variables(target: &ForeachTarget) -> Vec<&DirectVariable> {
+    let mut variables = Vec::new();
+    let mut expressions = match target {
+        ForeachTarget::Value(value) => vec![&value.value],
+        ForeachTarget::KeyValue(key_value) => vec![&key_value.key, &key_value.value],
+    };
+
+    while let Some(expression) = expressions.pop() {
+        match expression {
+            Expression::Variable(Variable::Direct(variable)) => variables.push(variable),
+            Expression::UnaryPrefix(unary) => expressions.push(&unary.operand),
+            _ => {}
+        }
+    }
+
+    variables
+}
+
+/// Map a declared hint to a union; shapes beyond this pass (`callable`,
+/// intersections, DNF) come out `Mixed`.
+fn hint_union(hint: &Hint, interner: &ThreadedInterner) -> TypeUnion {
+    match hint {
+        Hint::Identifier(identifier) => {
+            TypeUnion::single(TypeAtom::Object(Some(normalize_identifier(identifier, interner, true))))
+        }
+        Hint::Nullable(nullable) => hint_union(&nullable.hint, interner).merged(&TypeUnion::single(TypeAtom::Null)),
+        Hint::Union(union) => hint_union(&union.left, interner).merged(&hint_union(&union.right, interner)),
+        Hint::Null(_) => TypeUnion::single(TypeAtom::Null),
+        Hint::Bool(_) | Hint::True(_) | Hint::False(_) => TypeUnion::single(TypeAtom::Bool),
+        Hint::Integer(_) => TypeUnion::single(TypeAtom::Int),
+        Hint::Float(_) => TypeUnion::single(TypeAtom::Float),
+        Hint::String(_) => TypeUnion::single(TypeAtom::String),
+        Hint::Array(_) => TypeUnion::single(TypeAtom::Array),
+        Hint::Object(_) => TypeUnion::single(TypeAtom::Object(None)),
+        _ => TypeUnion::Mixed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_interner::ThreadedInterner;
+
+    use super::*;
+
+    /// Infer over the first function in `source` and return the type of
+    /// `variable` at the statement whose source text starts with `at`.
+    fn type_at(source: &str, variable: &str, at: &str) -> TypeUnion {
+        let interner = ThreadedInterner::new();
+        let (program, error) = mago_parser::parse_source_text(&interner, source);
+        assert!(error.is_none(), "test source must parse");
+
+        let offset = source.find(at).expect("marker must occur in source");
+
+        let mut stack = vec![Node::Program(&program)];
+        while let Some(node) = stack.pop() {
+            if let Node::Function(function) = node {
+                let types =
+                    infer_local_types(&interner, Some(&function.parameter_list), &function.body, &NoOracle);
+                let mut span = function.span();
+                span.start.offset = offset;
+                return types.type_of(variable, span);
+            }
+            stack.extend(node.children());
+        }
+
+        panic!("no function in test source");
+    }
+
+    #[test]
+    fn test_parameter_hints_and_literal_assignments() {
+        let source = "<?php function f(int $n, ?string $s) { $x = 1.5; return $x; }";
+
+        assert_eq!(type_at(source, "$n", "return"), TypeUnion::single(TypeAtom::Int));
+        assert_eq!(type_at(source, "$s", "return"), TypeUnion::of([TypeAtom::Null, TypeAtom::String]));
+        assert_eq!(type_at(source, "$x", "return"), TypeUnion::single(TypeAtom::Float));
+    }
+
+    #[test]
+    fn test_if_else_narrowing_merges_at_the_join() {
+        let source = "<?php function f(?string $s) {
+            if ($s !== null) {
+                one($s);
+            } else {
+                two($s);
+            }
+            after($s);
+        }";
+
+        assert_eq!(type_at(source, "$s", "one("), TypeUnion::single(TypeAtom::String));
+        assert_eq!(type_at(source, "$s", "two("), TypeUnion::single(TypeAtom::Null));
+        assert_eq!(type_at(source, "$s", "after("), TypeUnion::of([TypeAtom::Null, TypeAtom::String]));
+    }
+
+    #[test]
+    fn test_early_return_narrows_the_fall_through() {
+        let source = "<?php function f(?string $s) {
+            if ($s === null) {
+                return;
+            }
+            after($s);
+        }";
+
+        assert_eq!(type_at(source, "$s", "after("), TypeUnion::single(TypeAtom::String));
+    }
+
+    #[test]
+    fn test_instanceof_and_is_checks() {
+        let source = "<?php function f($u) {
+            if ($u instanceof User) {
+                known($u);
+            }
+            if (is_int($u)) {
+                integral($u);
+            }
+        }";
+
+        assert_eq!(
+            type_at(source, "$u", "known("),
+            TypeUnion::single(TypeAtom::Object(Some("user".to_owned()))),
+        );
+        assert_eq!(type_at(source, "$u", "integral("), TypeUnion::single(TypeAtom::Int));
+    }
+
+    #[test]
+    fn test_loops_widen_reassigned_variables() {
+        let source = "<?php function f(array $items) {
+            $x = 1;
+            foreach ($items as $item) {
+                $x = maybe($item);
+            }
+            after($x, $item);
+        }";
+
+        assert_eq!(type_at(source, "$x", "after("), TypeUnion::Mixed);
+        assert_eq!(type_at(source, "$item", "after("), TypeUnion::Mixed);
+        assert_eq!(type_at(source, "$items", "after("), TypeUnion::single(TypeAtom::Array));
+    }
+
+    #[test]
+    fn test_unmodeled_constructs_widen_instead_of_guessing() {
+        let source = "<?php function f() {
+            $x = 1;
+            $x .= '!';
+            after($x);
+        }";
+
+        assert_eq!(type_at(source, "$x", "after("), TypeUnion::Mixed);
+    }
+}
@@ -0,0 +1,52 @@
+use mago_ast::*;
+use mago_span::HasSpan;
+
+/// Find the innermost AST node whose span contains the given byte offset.
+///
+/// Starting from the program node, this descends into the child whose span
+/// contains `offset` and returns the deepest match. Spans use exclusive end
+/// semantics, so an offset equal to a node's end offset is *not* considered
+/// contained by that node.
+///
+/// When several children of a node contain the offset (which can happen for
+/// zero-length or overlapping spans produced during error recovery), the child
+/// with the smallest span is preferred, as it is the most precise match.
+///
+/// Returns `None` if the offset falls outside the program entirely, e.g. past
+/// the end of the source.
+pub fn find_innermost_node_at<'a>(program: &'a Program, offset: usize) -> Option<Node<'a>> {
+    let root = Node::Program(program);
+    if !span_contains_offset(root.span(), offset) {
+        return None;
+    }
+
+    let mut current = root;
+    loop {
+        let mut innermost: Option<Node<'a>> = None;
+        for child in current.children() {
+            if !span_contains_offset(child.span(), offset) {
+                continue;
+            }
+
+            innermost = match innermost {
+                Some(previous) if span_length(previous.span()) <= span_length(child.span()) => Some(previous),
+                _ => Some(child),
+            };
+        }
+
+        match innermost {
+            Some(child) => current = child,
+            None => return Some(current),
+        }
+    }
+}
+
+#[inline]
+fn span_contains_offset(span: mago_span::Span, offset: usize) -> bool {
+    span.start.offset <= offset && offset < span.end.offset
+}
+
+#[inline]
+fn span_length(span: mago_span::Span) -> usize {
+    span.end.offset - span.start.offset
+}
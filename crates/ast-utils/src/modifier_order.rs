@@ -0,0 +1,123 @@
+//! Canonical modifier ordering, shared by the formatter's modifier printing
+//! and the linter's modifier-order fix so the two can never disagree.
+
+use mago_ast::*;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+/// A configurable canonical order for declaration modifiers.
+///
+/// The default groups are: visibility (including asymmetric `*(set)`
+/// visibility), `abstract`/`final`, `static`, `readonly`. Modifiers within
+/// one group keep their relative source order, making the sort stable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModifierOrder {
+    groups: Vec<Vec<String>>,
+}
+
+impl Default for ModifierOrder {
+    fn default() -> Self {
+        Self {
+            groups: vec![
+                vec![
+                    "public".into(),
+                    "protected".into(),
+                    "private".into(),
+                    "public(set)".into(),
+                    "protected(set)".into(),
+                    "private(set)".into(),
+                ],
+                vec!["abstract".into(), "final".into()],
+                vec!["static".into()],
+                vec!["readonly".into()],
+            ],
+        }
+    }
+}
+
+impl ModifierOrder {
+    /// Build an order from configuration: one entry per group, modifiers
+    /// separated by `|`, e.g. `["public|protected|private", "final", "static"]`.
+    pub fn from_groups(groups: &[String]) -> Self {
+        Self {
+            groups: groups
+                .iter()
+                .map(|group| group.split('|').map(|modifier| modifier.trim().to_ascii_lowercase()).collect())
+                .collect(),
+        }
+    }
+
+    /// The sort rank of a modifier keyword; unknown keywords (and `var`,
+    /// which normalization must never touch) sort last, unmoved.
+    pub fn rank_of(&self, keyword: &str) -> usize {
+        let keyword = keyword.to_ascii_lowercase();
+        self.groups
+            .iter()
+            .position(|group| group.iter().any(|candidate| *candidate == keyword))
+            .unwrap_or(self.groups.len())
+    }
+
+    /// Compute the replacements that reorder `modifiers` canonically.
+    ///
+    /// Each returned `(span, text)` rewrites one modifier token with the
+    /// source text of the modifier that belongs at that position, so the
+    /// original casing of every keyword is preserved and comments between
+    /// modifiers stay exactly where they were written. Returns an empty
+    /// vector when the sequence is already in order — including any
+    /// sequence containing `var`, which is left for the visibility rule.
+    pub fn reorder_replacements(&self, modifiers: &Sequence<Modifier>, source: &str) -> Vec<(Span, String)> {
+        let keywords: Vec<&str> = modifiers.iter().map(|modifier| modifier.keyword()).collect();
+        if keywords.iter().any(|keyword| keyword.eq_ignore_ascii_case("var")) {
+            return Vec::new();
+        }
+
+        let mut order: Vec<usize> = (0..keywords.len()).collect();
+        order.sort_by_key(|&index| self.rank_of(keywords[index]));
+
+        if order.iter().enumerate().all(|(position, &index)| position == index) {
+            return Vec::new();
+        }
+
+        order
+            .iter()
+            .enumerate()
+            .filter(|&(position, &index)| position != index)
+            .map(|(position, &index)| {
+                let destination = modifiers.as_slice()[position].span();
+                let origin = modifiers.as_slice()[index].span();
+
+                (destination, source[origin.to_range()].to_owned())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ModifierOrder;
+
+    #[test]
+    fn test_default_ranks() {
+        let order = ModifierOrder::default();
+        assert!(order.rank_of("public") < order.rank_of("final"));
+        assert!(order.rank_of("final") < order.rank_of("static"));
+        assert!(order.rank_of("static") < order.rank_of("readonly"));
+        // Casing is irrelevant, as with all PHP keywords.
+        assert_eq!(order.rank_of("PUBLIC"), order.rank_of("public"));
+        // `var` is unknown to the order: it must sort last and stay put.
+        assert_eq!(order.rank_of("var"), order.rank_of("anything-unknown"));
+    }
+
+    #[test]
+    fn test_asymmetric_visibility_sorts_with_visibility() {
+        let order = ModifierOrder::default();
+        assert_eq!(order.rank_of("private(set)"), order.rank_of("public"));
+        assert!(order.rank_of("private(set)") < order.rank_of("static"));
+    }
+
+    #[test]
+    fn test_configured_groups() {
+        let order = ModifierOrder::from_groups(&["final|abstract".into(), "public|protected|private".into()]);
+        assert!(order.rank_of("final") < order.rank_of("private"));
+    }
+}
@@ -0,0 +1,186 @@
+use mago_ast::*;
+use mago_formatter::print_modified;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::inference::classify_literal_type;
+use crate::inference::InferredType;
+
+/// How [`negate_expression_with`] treats ordering comparisons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NegationOptions {
+    /// Invert `<` / `<=` / `>` / `>=` even when an operand is a known
+    /// float. `!($a < $b)` and `$a >= $b` differ when a side is `NAN`
+    /// (every ordering comparison with `NAN` is false), so the default
+    /// wraps those instead of inverting.
+    pub invert_float_comparisons: bool,
+}
+
+impl Default for NegationOptions {
+    fn default() -> Self {
+        Self { invert_float_comparisons: false }
+    }
+}
+
+/// The source text of the simplest correct negation of `expression`.
+///
+/// In order of preference:
+///
+/// - `!$x` drops the `!`; `true` / `false` swap;
+/// - comparisons invert their operator in place (`===` ↔ `!==`, `==` ↔
+///   `!=`, `<` ↔ `>=`, ...), splicing only the operator so every other
+///   byte — spacing, comments, nested formatting — survives. Ordering
+///   comparisons with a syntactically known float operand are exempt by
+///   default (see [`NegationOptions`]);
+/// - self-delimiting expressions (variables, calls, `isset(...)`,
+///   parenthesized groups, ...) get a `!` prefix;
+/// - everything else is wrapped as `!(...)`, which is never wrong, only
+///   uglier.
+pub fn negate_expression(expression: &Expression, source: &str) -> String {
+    negate_expression_with(expression, source, NegationOptions::default())
+}
+
+/// [`negate_expression`] with explicit [`NegationOptions`].
+pub fn negate_expression_with(expression: &Expression, source: &str, options: NegationOptions) -> String {
+    match expression {
+        // `!x` → `x`. The operand's own text is already delimited
+        // correctly — `!($a && $b)` keeps its parentheses.
+        Expression::UnaryPrefix(unary) if unary.operator.is_not() => text_of(unary.operand.span(), source),
+
+        Expression::Literal(Literal::True(_)) => "false".to_owned(),
+        Expression::Literal(Literal::False(_)) => "true".to_owned(),
+
+        Expression::Binary(binary) => match inverted_operator(&binary.operator) {
+            Some((operator_span, inverted, ordering)) => {
+                if ordering && !options.invert_float_comparisons && has_float_operand(binary) {
+                    return wrap(expression, source);
+                }
+
+                match print_modified(expression, source, &[(operator_span, inverted.to_owned())]) {
+                    Ok(negated) => negated,
+                    Err(_) => wrap(expression, source),
+                }
+            }
+            None => wrap(expression, source),
+        },
+
+        // A bare `!` binds tighter than any binary operator, so it is
+        // only safe on expressions that are a single unit already.
+        Expression::Variable(_)
+        | Expression::Call(_)
+        | Expression::Construct(_)
+        | Expression::Parenthesized(_)
+        | Expression::Literal(_) => format!("!{}", text_of(expression.span(), source)),
+
+        _ => wrap(expression, source),
+    }
+}
+
+/// `(operator span, inverted spelling, is an ordering comparison)`.
+fn inverted_operator(operator: &BinaryOperator) -> Option<(Span, &'static str, bool)> {
+    match operator {
+        BinaryOperator::Identical(span) => Some((*span, "!==", false)),
+        BinaryOperator::NotIdentical(span) => Some((*span, "===", false)),
+        BinaryOperator::Equal(span) => Some((*span, "!=", false)),
+        BinaryOperator::NotEqual(span) | BinaryOperator::AngledNotEqual(span) => Some((*span, "==", false)),
+        BinaryOperator::LessThan(span) => Some((*span, ">=", true)),
+        BinaryOperator::LessThanOrEqual(span) => Some((*span, ">", true)),
+        BinaryOperator::GreaterThan(span) => Some((*span, "<=", true)),
+        BinaryOperator::GreaterThanOrEqual(span) => Some((*span, "<", true)),
+        _ => None,
+    }
+}
+
+fn has_float_operand(binary: &Binary) -> bool {
+    classify_literal_type(&binary.lhs) == InferredType::Float
+        || classify_literal_type(&binary.rhs) == InferredType::Float
+}
+
+fn wrap(expression: &Expression, source: &str) -> String {
+    format!("!({})", text_of(expression.span(), source))
+}
+
+fn text_of(span: Span, source: &str) -> String {
+    source[span.to_range()].to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_interner::ThreadedInterner;
+
+    use super::*;
+
+    fn negate(condition: &str) -> String {
+        negate_with(condition, NegationOptions::default())
+    }
+
+    fn negate_with(condition: &str, options: NegationOptions) -> String {
+        let interner = ThreadedInterner::new();
+        let source = format!("<?php if ({condition}) {{}}");
+        let (program, error) = mago_parser::parse_source_text(&interner, &source);
+        assert!(error.is_none(), "test source must parse");
+
+        let mut stack = vec![Node::Program(&program)];
+        while let Some(node) = stack.pop() {
+            if let Node::Statement(Statement::If(r#if)) = node {
+                return negate_expression_with(&r#if.condition, &source, options);
+            }
+            stack.extend(node.children());
+        }
+
+        panic!("no if in test source");
+    }
+
+    #[test]
+    fn test_every_comparison_operator_inverts() {
+        assert_eq!(negate("$a === $b"), "$a !== $b");
+        assert_eq!(negate("$a !== $b"), "$a === $b");
+        assert_eq!(negate("$a == $b"), "$a != $b");
+        assert_eq!(negate("$a != $b"), "$a == $b");
+        assert_eq!(negate("$a <> $b"), "$a == $b");
+        assert_eq!(negate("$a < $b"), "$a >= $b");
+        assert_eq!(negate("$a <= $b"), "$a > $b");
+        assert_eq!(negate("$a > $b"), "$a <= $b");
+        assert_eq!(negate("$a >= $b"), "$a < $b");
+    }
+
+    #[test]
+    fn test_inversion_splices_only_the_operator() {
+        assert_eq!(negate("$a   ===   foo( $b )"), "$a   !==   foo( $b )");
+    }
+
+    #[test]
+    fn test_not_prefix_is_dropped_and_booleans_swap() {
+        assert_eq!(negate("!$x"), "$x");
+        assert_eq!(negate("!($a && $b)"), "($a && $b)");
+        assert_eq!(negate("true"), "false");
+        assert_eq!(negate("false"), "true");
+    }
+
+    #[test]
+    fn test_self_delimiting_expressions_take_a_bare_not() {
+        assert_eq!(negate("$x"), "!$x");
+        assert_eq!(negate("isset($x)"), "!isset($x)");
+        assert_eq!(negate("foo($a, $b)"), "!foo($a, $b)");
+    }
+
+    #[test]
+    fn test_logical_operators_fall_back_to_wrapping() {
+        // De Morgan expansion is a style choice, not a negation — the
+        // wrap preserves evaluation exactly.
+        assert_eq!(negate("$a && $b"), "!($a && $b)");
+        assert_eq!(negate("$a || $b"), "!($a || $b)");
+        assert_eq!(negate("$a instanceof B"), "!($a instanceof B)");
+        assert_eq!(negate("$a ?? $b"), "!($a ?? $b)");
+    }
+
+    #[test]
+    fn test_float_ordering_comparisons_wrap_by_default() {
+        // `!(1.5 < $b)` and `1.5 >= $b` disagree when `$b` is NAN.
+        assert_eq!(negate("1.5 < $b"), "!(1.5 < $b)");
+        assert_eq!(negate_with("1.5 < $b", NegationOptions { invert_float_comparisons: true }), "1.5 >= $b");
+
+        // Equality inversion is NAN-safe and always allowed.
+        assert_eq!(negate("1.5 === $b"), "1.5 !== $b");
+    }
+}
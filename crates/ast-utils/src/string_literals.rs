@@ -0,0 +1,187 @@
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+/// A string literal found in a program, with its decoded runtime value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StringLiteralRef {
+    pub span: Span,
+    /// The raw source text, quotes and escape sequences included.
+    pub raw: String,
+    /// The value after resolving escape sequences per the quoting rules.
+    pub decoded: String,
+}
+
+/// Collect every string literal in the program, including the literal parts
+/// of interpolated and heredoc strings (interpolated expressions are
+/// skipped), with escape sequences resolved in `decoded`.
+///
+/// Single-quoted strings only recognize `\\` and `\'`; double-quoted and
+/// heredoc bodies additionally resolve `\n`, `\t`, `\r`, `\v`, `\e`, `\f`,
+/// `\$`, `\"`, octal, `\xNN`, and `\u{...}` escapes, mirroring PHP's own
+/// rules. Unknown escapes are preserved verbatim, as PHP does.
+pub fn collect_string_literals(interner: &ThreadedInterner, program: &Program) -> Vec<StringLiteralRef> {
+    let mut literals = Vec::new();
+    let mut stack = vec![Node::Program(program)];
+    while let Some(node) = stack.pop() {
+        match node {
+            Node::LiteralString(literal) => {
+                let raw = interner.lookup(&literal.value).to_owned();
+                let decoded = decode_literal(&raw);
+
+                literals.push(StringLiteralRef { span: literal.span(), raw, decoded });
+            }
+            Node::StringPart(StringPart::Literal(part)) => {
+                let raw = interner.lookup(&part.value).to_owned();
+                let decoded = unescape(&raw, true);
+
+                literals.push(StringLiteralRef { span: part.span(), raw, decoded });
+            }
+            _ => {
+                stack.extend(node.children());
+            }
+        }
+    }
+
+    literals.sort_by_key(|literal| literal.span.start.offset);
+    literals
+}
+
+/// Decode a quoted literal: strip the surrounding quotes and unescape the
+/// body according to whether it is single- or double-quoted.
+pub fn decode_literal(raw: &str) -> String {
+    match raw.as_bytes().first() {
+        Some(b'\'') => unescape(raw.get(1..raw.len().saturating_sub(1)).unwrap_or(""), false),
+        Some(b'"') => unescape(raw.get(1..raw.len().saturating_sub(1)).unwrap_or(""), true),
+        _ => unescape(raw, true),
+    }
+}
+
+/// Resolve escape sequences in a string body. With `double_quoted` false,
+/// only `\\` and `\'` are recognized.
+fn unescape(body: &str, double_quoted: bool) -> String {
+    let mut decoded = String::with_capacity(body.len());
+    let mut characters = body.chars().peekable();
+    while let Some(character) = characters.next() {
+        if character != '\\' {
+            decoded.push(character);
+            continue;
+        }
+
+        let Some(&escaped) = characters.peek() else {
+            decoded.push('\\');
+            break;
+        };
+
+        if !double_quoted {
+            match escaped {
+                '\\' | '\'' => {
+                    decoded.push(escaped);
+                    characters.next();
+                }
+                _ => decoded.push('\\'),
+            }
+
+            continue;
+        }
+
+        characters.next();
+        match escaped {
+            'n' => decoded.push('\n'),
+            't' => decoded.push('\t'),
+            'r' => decoded.push('\r'),
+            'v' => decoded.push('\u{0B}'),
+            'e' => decoded.push('\u{1B}'),
+            'f' => decoded.push('\u{0C}'),
+            '\\' => decoded.push('\\'),
+            '$' => decoded.push('$'),
+            '"' => decoded.push('"'),
+            'x' => {
+                let mut value = 0u32;
+                let mut digits = 0;
+                while digits < 2 {
+                    match characters.peek().and_then(|c| c.to_digit(16)) {
+                        Some(digit) => {
+                            value = value * 16 + digit;
+                            characters.next();
+                            digits += 1;
+                        }
+                        None => break,
+                    }
+                }
+
+                if digits == 0 {
+                    decoded.push_str("\\x");
+                } else {
+                    decoded.push(char::from(value as u8));
+                }
+            }
+            'u' => {
+                if characters.peek() == Some(&'{') {
+                    characters.next();
+                    let mut value = 0u32;
+                    let mut valid = false;
+                    for c in characters.by_ref() {
+                        if c == '}' {
+                            valid = true;
+                            break;
+                        }
+
+                        match c.to_digit(16) {
+                            Some(digit) => value = value * 16 + digit,
+                            None => break,
+                        }
+                    }
+
+                    match char::from_u32(value) {
+                        Some(c) if valid => decoded.push(c),
+                        _ => decoded.push_str("\\u"),
+                    }
+                } else {
+                    decoded.push_str("\\u");
+                }
+            }
+            '0'..='7' => {
+                let mut value = escaped.to_digit(8).unwrap_or(0);
+                let mut digits = 1;
+                while digits < 3 {
+                    match characters.peek().and_then(|c| c.to_digit(8)) {
+                        Some(digit) => {
+                            value = value * 8 + digit;
+                            characters.next();
+                            digits += 1;
+                        }
+                        None => break,
+                    }
+                }
+
+                decoded.push(char::from((value & 0xFF) as u8));
+            }
+            other => {
+                // PHP keeps unrecognized escapes verbatim, backslash included.
+                decoded.push('\\');
+                decoded.push(other);
+            }
+        }
+    }
+
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::unescape;
+
+    #[test]
+    fn test_single_quoted_rules() {
+        assert_eq!(unescape("a\\'b\\\\c\\nd", false), "a'b\\c\\nd");
+    }
+
+    #[test]
+    fn test_double_quoted_rules() {
+        assert_eq!(unescape("a\\tb\\n", true), "a\tb\n");
+        assert_eq!(unescape("\\x41\\u{1F600}\\101", true), "A\u{1F600}A");
+        assert_eq!(unescape("\\q", true), "\\q");
+    }
+}
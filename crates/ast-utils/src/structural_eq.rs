@@ -0,0 +1,61 @@
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+
+/// Span-insensitive structural equality of two nodes.
+///
+/// Two nodes are structurally equal when they have the same shape and the
+/// same terminal values, regardless of where they appear in the source.
+/// Normalization rules:
+///
+/// - redundant parentheses are skipped, so `($x)` equals `$x`;
+/// - identifiers used for function, class, and constant names compare
+///   ASCII-case-insensitively, matching PHP's resolution rules;
+/// - string literals and variable names compare exactly — `$Foo` and
+///   `$foo` are different variables, `'a'` and `'A'` different strings.
+///
+/// This is the comparison the duplicate-condition lint and the formatter
+/// idempotency tests share.
+pub fn structurally_equal(interner: &ThreadedInterner, a: Node<'_>, b: Node<'_>) -> bool {
+    let a = unwrap_parentheses(a);
+    let b = unwrap_parentheses(b);
+
+    if a.kind() != b.kind() {
+        return false;
+    }
+
+    match (a, b) {
+        (Node::DirectVariable(left), Node::DirectVariable(right)) => {
+            interner.lookup(&left.name) == interner.lookup(&right.name)
+        }
+        (Node::LiteralString(left), Node::LiteralString(right)) => {
+            interner.lookup(&left.value) == interner.lookup(&right.value)
+        }
+        (Node::LiteralInteger(left), Node::LiteralInteger(right)) => left.value == right.value,
+        (Node::LiteralFloat(left), Node::LiteralFloat(right)) => left.value == right.value,
+        (Node::Identifier(left), Node::Identifier(right)) => {
+            interner.lookup(&left.value()).eq_ignore_ascii_case(interner.lookup(&right.value()))
+        }
+        (Node::LocalIdentifier(left), Node::LocalIdentifier(right)) => {
+            interner.lookup(&left.value).eq_ignore_ascii_case(interner.lookup(&right.value))
+        }
+        _ => {
+            let left_children = a.children();
+            let right_children = b.children();
+
+            left_children.len() == right_children.len()
+                && left_children
+                    .into_iter()
+                    .zip(right_children)
+                    .all(|(left, right)| structurally_equal(interner, left, right))
+        }
+    }
+}
+
+fn unwrap_parentheses(node: Node<'_>) -> Node<'_> {
+    let mut current = node;
+    while let Node::Expression(Expression::Parenthesized(inner)) = current {
+        current = Node::Expression(&inner.expression);
+    }
+
+    current
+}
@@ -0,0 +1,88 @@
+use mago_ast::*;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+/// Find ternaries nested directly inside another ternary without
+/// parentheses, returning the span of each *inner* conditional — the text
+/// a fixer wraps in `(` `)`.
+///
+/// Since PHP 8.0, relying on ternary associativity is a compile error:
+/// `$a ? $b : $c ? $d : $e` and the short-ternary chain `$a ?: $b ?: $c`
+/// are fatal, because the conditional that historically bound left ends
+/// up as the condition (or else branch) of the outer one. A conditional
+/// nested in the *then* branch (`$a ? $b ? $c : $d : $e`) is delimited by
+/// the surrounding `?` and `:` and stays legal, but it is reported too:
+/// the helper's consumers want parentheses in every nesting position, and
+/// wrapping is behavior-preserving there as well.
+///
+/// Only direct nesting counts. An [`Expression::Parenthesized`] between
+/// the two conditionals means the author already disambiguated, and a
+/// ternary further down (inside a call argument, say) needs nothing.
+pub fn find_unparenthesized_nested_ternaries(program: &Program) -> Vec<Span> {
+    let mut spans = Vec::new();
+
+    let mut stack = vec![Node::Program(program)];
+    while let Some(node) = stack.pop() {
+        if let Node::Expression(Expression::Conditional(conditional)) = node {
+            let mut operands: Vec<&Expression> = vec![&conditional.condition, &conditional.r#else];
+            if let Some(then) = conditional.then.as_ref() {
+                operands.push(then);
+            }
+
+            for operand in operands {
+                if let Expression::Conditional(inner) = operand {
+                    spans.push(inner.span());
+                }
+            }
+        }
+
+        stack.extend(node.children());
+    }
+
+    spans.sort_by_key(|span| span.start.offset);
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_interner::ThreadedInterner;
+
+    use super::*;
+
+    fn nested_in(source: &str) -> Vec<String> {
+        let interner = ThreadedInterner::new();
+        let (program, error) = mago_parser::parse_source_text(&interner, source);
+        assert!(error.is_none(), "test source must parse");
+
+        find_unparenthesized_nested_ternaries(&program)
+            .into_iter()
+            .map(|span| source[span.to_range()].to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_left_associative_chain_reports_the_inner_ternary() {
+        assert_eq!(nested_in("<?php $x = $a ? $b : $c ? $d : $e;"), vec!["$a ? $b : $c"]);
+    }
+
+    #[test]
+    fn test_short_ternary_chain_is_reported() {
+        assert_eq!(nested_in("<?php $x = $a ?: $b ?: $c;"), vec!["$a ?: $b"]);
+    }
+
+    #[test]
+    fn test_nesting_in_the_then_branch_is_reported() {
+        assert_eq!(nested_in("<?php $x = $a ? $b ? $c : $d : $e;"), vec!["$b ? $c : $d"]);
+    }
+
+    #[test]
+    fn test_parenthesized_nesting_is_fine() {
+        assert!(nested_in("<?php $x = ($a ? $b : $c) ? $d : $e;").is_empty());
+        assert!(nested_in("<?php $x = $a ? $b : ($c ? $d : $e);").is_empty());
+    }
+
+    #[test]
+    fn test_indirect_nesting_is_not_direct_nesting() {
+        assert!(nested_in("<?php $x = $a ? f($b ? $c : $d) : $e;").is_empty());
+    }
+}
@@ -0,0 +1,47 @@
+use mago_ast::*;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+/// A `throw` site found in a block.
+#[derive(Debug, Clone, Copy)]
+pub enum ThrownType<'a> {
+    /// `throw new X(...)` — the thrown class is statically known.
+    Known(&'a Identifier),
+    /// `throw $variable`, `throw $this->factory->make()`, ... — the thrown
+    /// type cannot be determined from the throw site alone.
+    Unknown(Span),
+}
+
+/// Collect the exception types a block throws directly.
+///
+/// Each `throw` whose expression is an instantiation contributes its class
+/// identifier; every other thrown expression shape is recorded as
+/// [`ThrownType::Unknown`] so `@throws` verifiers can distinguish "throws
+/// nothing" from "throws something we can't name". The traversal does not
+/// descend into nested function-likes (closures, arrow functions, anonymous
+/// classes): their throws are not throws of this block.
+pub fn collect_directly_thrown_types(block: &Block) -> Vec<ThrownType<'_>> {
+    let mut thrown = Vec::new();
+    let mut stack: Vec<Node<'_>> = vec![Node::Block(block)];
+    while let Some(node) = stack.pop() {
+        match node {
+            Node::Closure(_) | Node::ArrowFunction(_) | Node::AnonymousClass(_) => continue,
+            Node::Throw(r#throw) => {
+                match &r#throw.exception {
+                    Expression::Instantiation(instantiation) => match instantiation.class.as_ref() {
+                        Expression::Identifier(identifier) => thrown.push(ThrownType::Known(identifier)),
+                        other => thrown.push(ThrownType::Unknown(other.span())),
+                    },
+                    other => thrown.push(ThrownType::Unknown(other.span())),
+                }
+
+                // An instantiation's arguments can themselves throw; keep
+                // walking into the expression.
+                stack.extend(node.children().into_iter().rev());
+            }
+            _ => stack.extend(node.children().into_iter().rev()),
+        }
+    }
+
+    thrown
+}
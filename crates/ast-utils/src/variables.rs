@@ -0,0 +1,86 @@
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+/// Spans of every use of the direct variable `name` (including the `$`)
+/// under `node`, in source order.
+///
+/// Scope boundaries are respected the way PHP does:
+///
+/// - closure bodies are fresh scopes and are skipped, but a `use ($x)` /
+///   `use (&$x)` clause naming the variable counts — by-value capture
+///   reads the enclosing scope's value at creation time;
+/// - arrow function bodies capture implicitly and are traversed;
+/// - nested `function` declarations and anonymous class bodies are
+///   skipped entirely. This conservatively ignores expressions inside
+///   them that *do* evaluate in the enclosing scope (constructor
+///   arguments of an anonymous class), trading a missed use for never
+///   inventing one.
+///
+/// Dynamic access (`$$name`, `${$name}`, `compact()`) is invisible to
+/// this collection; callers should treat an empty result as "no direct
+/// use", not proof the variable is dead.
+pub fn find_variable_usages(interner: &ThreadedInterner, node: Node<'_>, name: &str) -> Vec<Span> {
+    let mut usages = Vec::new();
+    let mut stack = vec![node];
+    while let Some(node) = stack.pop() {
+        match node {
+            Node::DirectVariable(variable) if interner.lookup(&variable.name) == name => {
+                usages.push(variable.span());
+            }
+            Node::Closure(closure) => {
+                if let Some(use_clause) = &closure.use_clause {
+                    for captured in use_clause.variables.iter() {
+                        if interner.lookup(&captured.variable.name) == name {
+                            usages.push(captured.variable.span());
+                        }
+                    }
+                }
+            }
+            Node::Function(_) | Node::AnonymousClass(_) => {}
+            _ => stack.extend(node.children()),
+        }
+    }
+
+    usages.sort_by_key(|span| span.start.offset);
+    usages
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_interner::ThreadedInterner;
+
+    use super::*;
+
+    fn usage_count(source: &str, name: &str) -> usize {
+        let interner = ThreadedInterner::new();
+        let (program, error) = mago_parser::parse_source_text(&interner, source);
+        assert!(error.is_none(), "test source must parse");
+
+        find_variable_usages(&interner, Node::Program(&program), name).len()
+    }
+
+    #[test]
+    fn test_counts_direct_uses_in_order() {
+        assert_eq!(usage_count("<?php $a = 1; echo $a + $a;", "$a"), 3);
+        assert_eq!(usage_count("<?php $a = 1;", "$b"), 0);
+    }
+
+    #[test]
+    fn test_closure_bodies_are_fresh_scopes_but_captures_count() {
+        assert_eq!(usage_count("<?php $f = function () { $v = 1; return $v; };", "$v"), 0);
+        assert_eq!(usage_count("<?php $f = function () use ($v) { return $v; };", "$v"), 1);
+        assert_eq!(usage_count("<?php $f = function () use (&$v) {};", "$v"), 1);
+    }
+
+    #[test]
+    fn test_arrow_functions_capture_implicitly() {
+        assert_eq!(usage_count("<?php $f = fn () => $v + 1;", "$v"), 1);
+    }
+
+    #[test]
+    fn test_nested_function_declarations_are_skipped() {
+        assert_eq!(usage_count("<?php function helper() { return $v; }", "$v"), 0);
+    }
+}
@@ -0,0 +1,108 @@
+use mago_ast::*;
+
+/// A class member's effective visibility.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Visibility {
+    Public,
+    Protected,
+    Private,
+}
+
+/// The effective visibility a modifier list grants, encoding PHP's
+/// defaulting rules in one place: no visibility modifier means `public`
+/// (methods, constants, promoted parameters alike), and the legacy `var`
+/// is `public` too. Asymmetric `*(set)` modifiers govern only writes and
+/// do not affect the (get) visibility computed here.
+pub fn member_visibility(modifiers: &[Modifier]) -> Visibility {
+    for modifier in modifiers {
+        if modifier.keyword().eq_ignore_ascii_case("private") {
+            return Visibility::Private;
+        }
+        if modifier.keyword().eq_ignore_ascii_case("protected") {
+            return Visibility::Protected;
+        }
+        if modifier.keyword().eq_ignore_ascii_case("public") || modifier.keyword().eq_ignore_ascii_case("var") {
+            return Visibility::Public;
+        }
+    }
+
+    Visibility::Public
+}
+
+/// Whether the list contains `static`.
+pub fn is_static(modifiers: &[Modifier]) -> bool {
+    has_keyword(modifiers, "static")
+}
+
+/// Whether the list contains `abstract`.
+pub fn is_abstract(modifiers: &[Modifier]) -> bool {
+    has_keyword(modifiers, "abstract")
+}
+
+/// Whether the list contains `final`.
+pub fn is_final(modifiers: &[Modifier]) -> bool {
+    has_keyword(modifiers, "final")
+}
+
+/// Whether the list contains `readonly`.
+pub fn is_readonly(modifiers: &[Modifier]) -> bool {
+    has_keyword(modifiers, "readonly")
+}
+
+fn has_keyword(modifiers: &[Modifier], keyword: &str) -> bool {
+    modifiers.iter().any(|modifier| modifier.keyword().eq_ignore_ascii_case(keyword))
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_interner::ThreadedInterner;
+
+    use super::*;
+
+    fn with_first_method<R>(source: &str, check: impl FnOnce(&Method) -> R) -> R {
+        let interner = ThreadedInterner::new();
+        let (program, error) = mago_parser::parse_source_text(&interner, source);
+        assert!(error.is_none(), "test source must parse");
+
+        let mut stack = vec![Node::Program(&program)];
+        while let Some(node) = stack.pop() {
+            if let Node::Method(method) = node {
+                return check(method);
+            }
+            stack.extend(node.children());
+        }
+
+        panic!("no method in test source");
+    }
+
+    #[test]
+    fn test_explicit_visibility_wins_regardless_of_position_and_case() {
+        with_first_method("<?php class C { final PROTECTED static function m() {} }", |method| {
+            assert_eq!(member_visibility(method.modifiers.as_slice()), Visibility::Protected);
+            assert!(is_static(method.modifiers.as_slice()));
+            assert!(is_final(method.modifiers.as_slice()));
+            assert!(!is_abstract(method.modifiers.as_slice()));
+        });
+    }
+
+    #[test]
+    fn test_missing_visibility_defaults_to_public() {
+        with_first_method("<?php class C { static function m() {} }", |method| {
+            assert_eq!(member_visibility(method.modifiers.as_slice()), Visibility::Public);
+        });
+
+        with_first_method("<?php class C { function m() {} }", |method| {
+            assert_eq!(member_visibility(method.modifiers.as_slice()), Visibility::Public);
+            assert!(!is_static(method.modifiers.as_slice()));
+        });
+    }
+
+    #[test]
+    fn test_abstract_and_private() {
+        with_first_method("<?php abstract class C { abstract private function m(); }", |method| {
+            assert_eq!(member_visibility(method.modifiers.as_slice()), Visibility::Private);
+            assert!(is_abstract(method.modifiers.as_slice()));
+            assert!(!is_readonly(method.modifiers.as_slice()));
+        });
+    }
+}
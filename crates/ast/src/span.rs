@@ -0,0 +1,118 @@
+//! Span conventions for declaration nodes.
+//!
+//! The convention: a declaration's span covers everything that is
+//! syntactically part of the declaration — its attribute lists and its
+//! modifiers — but *not* its docblock, which is trivia. Diagnostics that
+//! underline a method therefore start at its first `#[` attribute (or first
+//! modifier) rather than at the `function` keyword, and every child span is
+//! contained in its parent's span, which trivia attachment in the formatter
+//! depends on.
+//!
+//! The `span_consistency` integration test parses a fixture corpus and
+//! asserts both properties for every node.
+
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::ast::*;
+
+/// Join the span of the first attribute list (if any) with the natural
+/// start of the declaration, so attributes are always covered.
+#[inline]
+fn declaration_start(attribute_lists: &[AttributeList], fallback: Span) -> Span {
+    match attribute_lists.first() {
+        Some(attributes) => attributes.span(),
+        None => fallback,
+    }
+}
+
+impl HasSpan for Function {
+    fn span(&self) -> Span {
+        declaration_start(&self.attribute_lists, self.function.span()).join(self.body.span())
+    }
+}
+
+impl HasSpan for Method {
+    fn span(&self) -> Span {
+        let start = match self.modifiers.first() {
+            Some(modifier) => modifier.span(),
+            None => self.function.span(),
+        };
+
+        declaration_start(&self.attribute_lists, start).join(self.body.span())
+    }
+}
+
+impl HasSpan for Class {
+    fn span(&self) -> Span {
+        let start = match self.modifiers.first() {
+            Some(modifier) => modifier.span(),
+            None => self.class.span(),
+        };
+
+        declaration_start(&self.attribute_lists, start).join(self.right_brace)
+    }
+}
+
+impl HasSpan for PlainProperty {
+    fn span(&self) -> Span {
+        let start = match self.modifiers.first() {
+            Some(modifier) => modifier.span(),
+            None => match &self.var {
+                Some(var) => var.span(),
+                None => self.hint.as_ref().map_or_else(|| self.first_variable().span(), HasSpan::span),
+            },
+        };
+
+        declaration_start(&self.attribute_lists, start).join(self.terminator.span())
+    }
+}
+
+impl HasSpan for ClassLikeConstant {
+    fn span(&self) -> Span {
+        let start = match self.modifiers.first() {
+            Some(modifier) => modifier.span(),
+            None => self.r#const.span(),
+        };
+
+        declaration_start(&self.attribute_lists, start).join(self.terminator.span())
+    }
+}
+
+impl HasSpan for EnumCase {
+    fn span(&self) -> Span {
+        declaration_start(&self.attribute_lists, self.case.span()).join(self.terminator.span())
+    }
+}
+
+impl HasSpan for PropertyHook {
+    fn span(&self) -> Span {
+        let start = match self.modifiers.first() {
+            Some(modifier) => modifier.span(),
+            None => self.name.span(),
+        };
+
+        declaration_start(&self.attribute_lists, start).join(self.body.span())
+    }
+}
+
+impl HasSpan for FunctionLikeParameter {
+    fn span(&self) -> Span {
+        // Promoted parameters carry visibility modifiers and attributes;
+        // both belong to the parameter's span.
+        let start = match self.modifiers.first() {
+            Some(modifier) => modifier.span(),
+            None => match &self.hint {
+                Some(hint) => hint.span(),
+                None => self.variable.span(),
+            },
+        };
+
+        let end = match &self.default_value {
+            Some(default_value) => default_value.span(),
+            None => self.variable.span(),
+        };
+
+        declaration_start(&self.attribute_lists, start).join(end)
+    }
+}
@@ -0,0 +1,80 @@
+//! Every node's span must contain its children's spans, and declaration
+//! spans must start at their first attribute list. See the convention notes
+//! in `src/span.rs`.
+
+use mago_ast::Node;
+use mago_interner::ThreadedInterner;
+use mago_span::HasSpan;
+
+const CORPUS: &[&str] = &[
+    "<?php function foo(int $a = 1, string ...$rest): void {}",
+    "<?php #[Attr] function foo() {}",
+    "<?php final class A { #[Attr] public readonly int $x = 1; }",
+    "<?php abstract class B extends A implements I { #[Attr] final protected const int C = 1; }",
+    "<?php class C { #[Attr] public static function m(#[Attr] private int $promoted = 2) {} }",
+    "<?php enum Suit: string { #[Attr] case Hearts = 'h'; case Spades = 's'; }",
+    "<?php class D { public int $x { #[Attr] final get => $this->x; set($value) { $this->x = $value; } } }",
+    "<?php trait T { abstract protected function m(): static; }",
+    "<?php interface I { public function m(): never; }",
+];
+
+fn assert_children_contained(source: &str, node: Node<'_>) {
+    let span = node.span();
+    for child in node.children() {
+        let child_span = child.span();
+        assert!(
+            span.start.offset <= child_span.start.offset && child_span.end.offset <= span.end.offset,
+            "{:?} span {}..{} does not contain child {:?} span {}..{} in `{source}`",
+            node.kind(),
+            span.start.offset,
+            span.end.offset,
+            child.kind(),
+            child_span.start.offset,
+            child_span.end.offset,
+        );
+
+        assert_children_contained(source, child);
+    }
+}
+
+fn assert_declarations_start_at_attributes(source: &str, node: Node<'_>) {
+    let has_attributes = match node {
+        Node::Function(function) => !function.attribute_lists.is_empty(),
+        Node::Method(method) => !method.attribute_lists.is_empty(),
+        Node::Class(class) => !class.attribute_lists.is_empty(),
+        Node::PlainProperty(property) => !property.attribute_lists.is_empty(),
+        Node::ClassLikeConstant(constant) => !constant.attribute_lists.is_empty(),
+        Node::EnumCase(case) => !case.attribute_lists.is_empty(),
+        Node::PropertyHook(hook) => !hook.attribute_lists.is_empty(),
+        Node::FunctionLikeParameter(parameter) => !parameter.attribute_lists.is_empty(),
+        _ => false,
+    };
+
+    if has_attributes {
+        let span = node.span();
+        let text = &source[span.start.offset..span.end.offset];
+        assert!(
+            text.starts_with("#["),
+            "{:?} span must start at its first attribute, got `{}` in `{source}`",
+            node.kind(),
+            &text[..text.len().min(16)],
+        );
+    }
+
+    for child in node.children() {
+        assert_declarations_start_at_attributes(source, child);
+    }
+}
+
+#[test]
+fn test_spans_are_consistent_across_the_corpus() {
+    let interner = ThreadedInterner::new();
+    for source in CORPUS {
+        let (program, error) = mago_parser::parse_source_text(&interner, source);
+        assert!(error.is_none(), "corpus entry must parse: {error:?} in `{source}`");
+
+        let root = Node::Program(&program);
+        assert_children_contained(source, root);
+        assert_declarations_start_at_attributes(source, root);
+    }
+}
@@ -0,0 +1,356 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::ChangeOperation;
+
+/// A concrete, ordered set of text changes ready to be applied to a source.
+///
+/// Unlike a `FixPlan`, a change set carries no safety information: the
+/// filtering threshold has already been applied. Operations are kept sorted
+/// by their starting offset in the original source; ties are broken by a
+/// fixed kind priority (see [`ChangeSet::from_operations`]) so the result
+/// never depends on the order rules happened to push their fixes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangeSet {
+    operations: Vec<ChangeOperation>,
+}
+
+impl ChangeSet {
+    /// Collect and order operations for application.
+    ///
+    /// The order is fully deterministic: ascending start offset, then —
+    /// for operations sharing a start offset — replacements and deletions
+    /// before inserts, then ascending end offset. The tie-break is what
+    /// makes multi-rule output reproducible: an insert and a deletion at
+    /// the same offset used to apply in push order, which differs run to
+    /// run depending on rule iteration. With deletions ordered first, the
+    /// deletion wins and the insert at its start offset is skipped as
+    /// conflicting — consistently, every run. Inserts sharing an offset
+    /// with each other still apply in push order (the sort is stable).
+    pub fn from_operations(operations: impl IntoIterator<Item = ChangeOperation>) -> Self {
+        let mut operations: Vec<_> = operations.into_iter().collect();
+        operations.sort_by_key(|operation| (start_offset(operation), kind_priority(operation), end_offset(operation)));
+
+        Self { operations }
+    }
+
+    pub fn operations(&self) -> &[ChangeOperation] {
+        &self.operations
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Apply all changes to `source`, producing the fixed text.
+    ///
+    /// Every operation's range refers to the *original* `source` — the
+    /// contract stated on [`crate::FixOperation`] — so earlier edits never
+    /// shift the offsets later ones were computed from. Conflict
+    /// resolution is unchanged: overlapping operations keep the one
+    /// starting first and skip the rest. Anchored operations whose
+    /// expected text no longer matches are skipped as stale; use
+    /// [`ChangeSet::apply_with_stats`] when the caller needs to report
+    /// how many were.
+    pub fn apply(&self, source: &str) -> String {
+        self.apply_with_stats(source).0
+    }
+
+    /// Like [`ChangeSet::apply`], also counting what happened to each
+    /// operation so callers can surface "N fixes skipped: file changed
+    /// since the plan was computed" instead of silently dropping them.
+    pub fn apply_with_stats(&self, source: &str) -> (String, ApplyStats) {
+        let mut stats = ApplyStats::default();
+
+        // First pass, in ascending offset order: decide which operations
+        // survive staleness and overlap checks, against original offsets.
+        let mut kept: Vec<(usize, usize, &str)> = Vec::with_capacity(self.operations.len());
+        let mut cursor = 0usize;
+        for operation in &self.operations {
+            let (start, end, text) = match operation {
+                ChangeOperation::Insert { offset, text } => (*offset, *offset, text.as_str()),
+                ChangeOperation::Replace { span, text, .. } => (span.start.offset, span.end.offset, text.as_str()),
+                ChangeOperation::Delete { span, .. } => (span.start.offset, span.end.offset, ""),
+            };
+
+            if is_stale(operation, source) {
+                stats.skipped_stale += 1;
+                continue;
+            }
+
+            if start < cursor || end > source.len() {
+                stats.skipped_conflicting += 1;
+                continue;
+            }
+
+            kept.push((start, end, text));
+            cursor = end;
+            stats.applied += 1;
+        }
+
+        // Second pass, back to front: splicing from the end means every
+        // remaining range still points at unshifted original offsets, so
+        // no offset bookkeeping is needed even though edits change the
+        // string's length. Insertions sharing an offset keep their report
+        // order because the later one is spliced first and ends up after.
+        let mut result = source.to_owned();
+        for (start, end, text) in kept.into_iter().rev() {
+            result.replace_range(start..end, text);
+        }
+
+        (result, stats)
+    }
+}
+
+/// What happened to each operation during one [`ChangeSet::apply_with_stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ApplyStats {
+    pub applied: usize,
+    /// Anchored operations whose expected text no longer matched the file.
+    pub skipped_stale: usize,
+    /// Operations overlapping an earlier one, or out of bounds.
+    pub skipped_conflicting: usize,
+}
+
+/// Whether an anchored operation's range has drifted from the text it was
+/// planned against. Unanchored operations are never stale.
+fn is_stale(operation: &ChangeOperation, source: &str) -> bool {
+    match operation {
+        ChangeOperation::Insert { .. } => false,
+        ChangeOperation::Replace { span, expected: Some(expected), .. }
+        | ChangeOperation::Delete { span, expected: Some(expected) } => {
+            source.get(span.to_range()) != Some(expected.as_str())
+        }
+        ChangeOperation::Replace { expected: None, .. } | ChangeOperation::Delete { expected: None, .. } => false,
+    }
+}
+
+impl ChangeSet {
+    /// Whether applying this change set to `source` would reproduce
+    /// `source` byte for byte.
+    ///
+    /// Callers use this to treat "fixed" files that did not actually change
+    /// as nothing-to-do instead of reporting phantom fixes.
+    pub fn is_noop(&self, source: &str) -> bool {
+        self.operations.iter().all(|operation| is_noop_operation(operation, source))
+    }
+
+    /// Drop every operation whose output equals the bytes it replaces —
+    /// empty inserts, deletes of empty spans, and replacements identical to
+    /// the original slice.
+    ///
+    /// Running this before diffing keeps the reported change count honest:
+    /// a "normalize quotes" fix over already-normalized code yields an
+    /// empty set rather than a diff with zero visible lines.
+    pub fn without_noops(mut self, source: &str) -> Self {
+        self.operations.retain(|operation| !is_noop_operation(operation, source));
+        self
+    }
+}
+
+/// Whether the operation leaves `source` unchanged.
+fn is_noop_operation(operation: &ChangeOperation, source: &str) -> bool {
+    match operation {
+        ChangeOperation::Insert { text, .. } => text.is_empty(),
+        ChangeOperation::Replace { span, text, .. } => {
+            source.get(span.to_range()).is_some_and(|original| original == text)
+        }
+        ChangeOperation::Delete { span, .. } => span.length() == 0,
+    }
+}
+
+impl ChangeSet {
+    /// Split the change set into independent hunks for interactive
+    /// "apply this hunk?" workflows, like `git add -p`.
+    ///
+    /// Operations are grouped into one hunk per contiguous region of the
+    /// source: two operations land in the same hunk when their line ranges,
+    /// each widened by `context_lines`, touch or overlap. Hunks never
+    /// overlap, each is individually applicable, and applying every hunk
+    /// reproduces the full fix because the operations are partitioned, not
+    /// duplicated.
+    pub fn into_hunks(&self, source: &str, context_lines: usize) -> Vec<ChangeSet> {
+        let mut hunks: Vec<ChangeSet> = Vec::new();
+        let mut current: Vec<ChangeOperation> = Vec::new();
+        let mut current_end_line = 0usize;
+
+        for operation in &self.operations {
+            let start_line = line_of_offset(source, start_offset(operation));
+            let end_line = line_of_offset(source, end_offset(operation));
+
+            if current.is_empty() || start_line.saturating_sub(context_lines) <= current_end_line + context_lines {
+                current_end_line = current_end_line.max(end_line);
+            } else {
+                hunks.push(ChangeSet { operations: std::mem::take(&mut current) });
+                current_end_line = end_line;
+            }
+
+            current.push(operation.clone());
+        }
+
+        if !current.is_empty() {
+            hunks.push(ChangeSet { operations: current });
+        }
+
+        hunks
+    }
+}
+
+/// The 1-based line containing the byte `offset`.
+fn line_of_offset(source: &str, offset: usize) -> usize {
+    source.as_bytes().iter().take(offset.min(source.len())).filter(|&&byte| byte == b'\n').count() + 1
+}
+
+fn end_offset(operation: &ChangeOperation) -> usize {
+    match operation {
+        ChangeOperation::Insert { offset, .. } => *offset,
+        ChangeOperation::Replace { span, .. } | ChangeOperation::Delete { span, .. } => span.end.offset,
+    }
+}
+
+pub(crate) fn start_offset(operation: &ChangeOperation) -> usize {
+    match operation {
+        ChangeOperation::Insert { offset, .. } => *offset,
+        ChangeOperation::Replace { span, .. } | ChangeOperation::Delete { span, .. } => span.start.offset,
+    }
+}
+
+/// Tie-break for operations sharing a start offset: range-consuming
+/// operations order before zero-width inserts.
+fn kind_priority(operation: &ChangeOperation) -> u8 {
+    match operation {
+        ChangeOperation::Replace { .. } | ChangeOperation::Delete { .. } => 0,
+        ChangeOperation::Insert { .. } => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_span::FileId;
+    use mago_span::Position;
+    use mago_span::Span;
+
+    use super::*;
+
+    fn span(start: usize, end: usize) -> Span {
+        Span::new(Position::new(FileId(0), start, 1), Position::new(FileId(0), end, 1))
+    }
+
+    #[test]
+    fn test_identical_replacement_is_a_noop() {
+        let source = "echo 'hello';";
+        let set = ChangeSet::from_operations([ChangeOperation::Replace { span: span(5, 12), text: "'hello'".to_owned(), expected: None }]);
+
+        assert!(set.is_noop(source));
+        assert!(set.without_noops(source).is_empty());
+    }
+
+    #[test]
+    fn test_length_changing_edit_does_not_shift_later_ranges() {
+        // Two insertions straddling a deletion: the first insertion grows
+        // the text, the deletion shrinks it, and the second insertion's
+        // offset still refers to the original source. Sequential forward
+        // application against the evolving string would misplace both the
+        // deletion and the trailing insertion.
+        let source = "alpha beta gamma";
+        let set = ChangeSet::from_operations([
+            ChangeOperation::Insert { offset: 0, text: "<<".to_owned() },
+            ChangeOperation::Delete { span: span(5, 10), expected: None },
+            ChangeOperation::Insert { offset: 16, text: ">>".to_owned() },
+        ]);
+
+        assert_eq!(set.apply(source), "<<alpha gamma>>");
+    }
+
+    #[test]
+    fn test_insertions_at_one_offset_keep_their_order() {
+        let set = ChangeSet::from_operations([
+            ChangeOperation::Insert { offset: 3, text: "1".to_owned() },
+            ChangeOperation::Insert { offset: 3, text: "2".to_owned() },
+        ]);
+
+        assert_eq!(set.apply("abcdef"), "abc12def");
+    }
+
+    #[test]
+    fn test_delete_and_insert_at_one_offset_apply_identically_in_either_push_order() {
+        // The deletion orders first regardless of push order; the insert
+        // at its start offset then conflicts and is skipped. Both push
+        // orders must produce the same text and the same stats.
+        let source = "abcdeXY";
+        let delete_first = ChangeSet::from_operations([
+            ChangeOperation::Delete { span: span(3, 5), expected: None },
+            ChangeOperation::Insert { offset: 3, text: "I".to_owned() },
+        ]);
+        let insert_first = ChangeSet::from_operations([
+            ChangeOperation::Insert { offset: 3, text: "I".to_owned() },
+            ChangeOperation::Delete { span: span(3, 5), expected: None },
+        ]);
+
+        assert_eq!(delete_first, insert_first);
+
+        let (fixed, stats) = delete_first.apply_with_stats(source);
+        assert_eq!(fixed, "abcXY");
+        assert_eq!(stats, ApplyStats { applied: 1, skipped_stale: 0, skipped_conflicting: 1 });
+    }
+
+    #[test]
+    fn test_insert_at_a_deletion_end_offset_survives() {
+        // Only the start offset is contested; an insert at the *end* of a
+        // removed range is outside it and applies.
+        let source = "abcdeXY";
+        let set = ChangeSet::from_operations([
+            ChangeOperation::Insert { offset: 5, text: "I".to_owned() },
+            ChangeOperation::Delete { span: span(3, 5), expected: None },
+        ]);
+
+        assert_eq!(set.apply(source), "abcIXY");
+    }
+
+    #[test]
+    fn test_stale_anchored_operation_is_skipped_and_counted() {
+        use crate::FixPlan;
+        use crate::SafetyClassification;
+
+        let planned_against = "echo \"hello\";";
+        let plan = FixPlan::new()
+            .replace(span(5, 12), "'hello'", SafetyClassification::Safe)
+            .anchored(planned_against);
+
+        // The file drifted: the range no longer holds the planned text.
+        let drifted = "echo \"howdy\";";
+        let (fixed, stats) = plan.to_change_set(SafetyClassification::Safe).apply_with_stats(drifted);
+
+        assert_eq!(fixed, drifted, "a stale operation must not touch the file");
+        assert_eq!(stats, ApplyStats { applied: 0, skipped_stale: 1, skipped_conflicting: 0 });
+
+        // Against unchanged content the same plan applies normally.
+        let (fixed, stats) = plan.to_change_set(SafetyClassification::Safe).apply_with_stats(planned_against);
+        assert_eq!(fixed, "echo 'hello';");
+        assert_eq!(stats.applied, 1);
+    }
+
+    #[test]
+    fn test_unanchored_operations_apply_without_verification() {
+        let set = ChangeSet::from_operations([ChangeOperation::Delete { span: span(4, 12), expected: None }]);
+
+        let (fixed, stats) = set.apply_with_stats("echo \"hello\";");
+        assert_eq!(fixed, "echo;");
+        assert_eq!(stats.skipped_stale, 0);
+    }
+
+    #[test]
+    fn test_real_changes_survive_noop_filtering() {
+        let source = "echo \"hello\";";
+        let set = ChangeSet::from_operations([
+            ChangeOperation::Replace { span: span(5, 12), text: "'hello'".to_owned(), expected: None },
+            ChangeOperation::Insert { offset: 13, text: String::new() },
+        ]);
+
+        assert!(!set.is_noop(source));
+
+        let filtered = set.without_noops(source);
+        assert_eq!(filtered.operations().len(), 1);
+        assert_eq!(filtered.apply(source), "echo 'hello';");
+    }
+}
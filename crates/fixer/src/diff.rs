@@ -0,0 +1,290 @@
+use crate::ChangeOperation;
+use crate::ChangeSet;
+use crate::change_set::start_offset;
+
+/// A run of text in the original/fixed pair, derived from a change set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    Unchanged(String),
+    Deleted(String),
+    Inserted(String),
+}
+
+impl ChangeSet {
+    /// Decompose the application of this change set to `source` into runs
+    /// of unchanged, deleted, and inserted text, in output order.
+    pub fn to_changes(&self, source: &str) -> Vec<Change> {
+        let mut changes = Vec::new();
+        let mut cursor = 0usize;
+
+        let mut push = |changes: &mut Vec<Change>, change: Change| {
+            let empty = match &change {
+                Change::Unchanged(text) | Change::Deleted(text) | Change::Inserted(text) => text.is_empty(),
+            };
+
+            if !empty {
+                changes.push(change);
+            }
+        };
+
+        for operation in self.operations() {
+            let start = start_offset(operation);
+            if start < cursor || start > source.len() {
+                continue;
+            }
+
+            push(&mut changes, Change::Unchanged(source[cursor..start].to_owned()));
+            match operation {
+                ChangeOperation::Insert { text, .. } => {
+                    push(&mut changes, Change::Inserted(text.clone()));
+                    cursor = start;
+                }
+                ChangeOperation::Replace { span, text, .. } => {
+                    push(&mut changes, Change::Deleted(source[span.to_range()].to_owned()));
+                    push(&mut changes, Change::Inserted(text.clone()));
+                    cursor = span.end.offset;
+                }
+                ChangeOperation::Delete { span, .. } => {
+                    push(&mut changes, Change::Deleted(source[span.to_range()].to_owned()));
+                    cursor = span.end.offset;
+                }
+            }
+        }
+
+        push(&mut changes, Change::Unchanged(source[cursor..].to_owned()));
+        changes
+    }
+
+    /// Render the change set as aligned side-by-side line pairs.
+    ///
+    /// The left column is the original, the right the fixed text; `None`
+    /// marks the side where a line was purely inserted or deleted.
+    /// Unchanged runs appear on both sides, deleted/inserted runs are
+    /// paired line-by-line (a replacement pairs its old and new lines
+    /// positionally). Lines longer than `width` are truncated with `…`;
+    /// pass `usize::MAX` to disable truncation.
+    pub fn to_side_by_side(&self, source: &str, width: usize) -> Vec<(Option<String>, Option<String>)> {
+        // Rebuild full left/right line sequences, then align them.
+        let mut left_lines: Vec<Option<String>> = Vec::new();
+        let mut right_lines: Vec<Option<String>> = Vec::new();
+        let mut left_partial = String::new();
+        let mut right_partial = String::new();
+
+        fn feed(partial: &mut String, lines: &mut Vec<Option<String>>, text: &str) {
+            for character in text.chars() {
+                if character == '\n' {
+                    lines.push(Some(std::mem::take(partial)));
+                } else {
+                    partial.push(character);
+                }
+            }
+        }
+
+        for change in self.to_changes(source) {
+            match change {
+                Change::Unchanged(text) => {
+                    // Re-align the columns before a shared run: the side
+                    // with fewer completed lines gets `None` padding so the
+                    // unchanged text starts on the same row in both.
+                    while left_lines.len() < right_lines.len() {
+                        left_lines.push(None);
+                    }
+                    while right_lines.len() < left_lines.len() {
+                        right_lines.push(None);
+                    }
+
+                    feed(&mut left_partial, &mut left_lines, &text);
+                    feed(&mut right_partial, &mut right_lines, &text);
+                }
+                Change::Deleted(text) => feed(&mut left_partial, &mut left_lines, &text),
+                Change::Inserted(text) => feed(&mut right_partial, &mut right_lines, &text),
+            }
+        }
+
+        if !left_partial.is_empty() || !right_partial.is_empty() {
+            left_lines.push(if left_partial.is_empty() { None } else { Some(left_partial) });
+            right_lines.push(if right_partial.is_empty() { None } else { Some(right_partial) });
+        }
+
+        while left_lines.len() < right_lines.len() {
+            left_lines.push(None);
+        }
+        while right_lines.len() < left_lines.len() {
+            right_lines.push(None);
+        }
+
+        let truncate = |line: Option<String>| {
+            line.map(|line| {
+                if line.chars().count() > width {
+                    let mut truncated: String = line.chars().take(width.saturating_sub(1)).collect();
+                    truncated.push('…');
+                    truncated
+                } else {
+                    line
+                }
+            })
+        };
+
+        left_lines.into_iter().zip(right_lines).map(|(left, right)| (truncate(left), truncate(right))).collect()
+    }
+}
+
+/// Aggregate size of a change set's effect, in the shape
+/// `git diff --stat` reports: lines and characters added and removed.
+///
+/// A run that does not end in a newline still touches the line it leaves
+/// partial — replacing `two` with `TWO` mid-line counts one line removed
+/// and one added — so the counts match what a line-based diff would show.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChangeStats {
+    pub lines_added: usize,
+    pub lines_removed: usize,
+    pub chars_inserted: usize,
+    pub chars_deleted: usize,
+}
+
+impl ChangeStats {
+    /// Render as the conventional `+N −M lines` summary fragment.
+    pub fn summary(&self) -> String {
+        format!("+{} \u{2212}{} lines", self.lines_added, self.lines_removed)
+    }
+
+    fn count(text: &str) -> (usize, usize) {
+        let mut lines = 0usize;
+        let mut chars = 0usize;
+        let mut partial = false;
+
+        for character in text.chars() {
+            chars += 1;
+            if character == '\n' {
+                lines += 1;
+                partial = false;
+            } else {
+                partial = true;
+            }
+        }
+
+        (lines + usize::from(partial), chars)
+    }
+}
+
+impl ChangeSet {
+    /// Aggregate what applying this change set to `source` adds and
+    /// removes, for CLI summaries like `fixed 3 files (+12 −5 lines)`.
+    ///
+    /// A pure aggregation over [`ChangeSet::to_changes`]: inserted runs
+    /// count toward added, deleted runs toward removed, unchanged runs
+    /// toward neither.
+    pub fn stats(&self, source: &str) -> ChangeStats {
+        let mut stats = ChangeStats::default();
+
+        for change in self.to_changes(source) {
+            match change {
+                Change::Unchanged(_) => {}
+                Change::Deleted(text) => {
+                    let (lines, chars) = ChangeStats::count(&text);
+                    stats.lines_removed += lines;
+                    stats.chars_deleted += chars;
+                }
+                Change::Inserted(text) => {
+                    let (lines, chars) = ChangeStats::count(&text);
+                    stats.lines_added += lines;
+                    stats.chars_inserted += chars;
+                }
+            }
+        }
+
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_span::FileId;
+    use mago_span::Position;
+    use mago_span::Span;
+
+    use super::*;
+
+    fn span(start: usize, end: usize) -> Span {
+        Span::new(Position::new(FileId(0), start, 1), Position::new(FileId(0), end, 1))
+    }
+
+    #[test]
+    fn test_side_by_side_pairs_replaced_lines() {
+        let source = "one\ntwo\nthree\n";
+        let set = ChangeSet::from_operations([ChangeOperation::Replace {
+            span: span(4, 7),
+            text: "TWO".to_owned(),
+            expected: None,
+        }]);
+
+        let pairs = set.to_side_by_side(source, 80);
+        assert_eq!(
+            pairs,
+            vec![
+                (Some("one".to_owned()), Some("one".to_owned())),
+                (Some("two".to_owned()), Some("TWO".to_owned())),
+                (Some("three".to_owned()), Some("three".to_owned())),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_stats_count_whole_line_insertions_and_deletions() {
+        let source = "a\nb\nc\n";
+        let set = ChangeSet::from_operations([
+            ChangeOperation::Insert { offset: 0, text: "header\nsubheader\n".to_owned() },
+            ChangeOperation::Delete { span: span(2, 4), expected: None },
+        ]);
+
+        let stats = set.stats(source);
+        assert_eq!(stats.lines_added, 2);
+        assert_eq!(stats.lines_removed, 1);
+        assert_eq!(stats.chars_inserted, 17);
+        assert_eq!(stats.chars_deleted, 2);
+        assert_eq!(stats.summary(), "+2 \u{2212}1 lines");
+    }
+
+    #[test]
+    fn test_stats_count_partial_line_runs_as_one_line() {
+        // A mid-line replacement neither adds nor removes a newline, but
+        // it rewrites one line on each side.
+        let source = "one\ntwo\nthree\n";
+        let set = ChangeSet::from_operations([ChangeOperation::Replace {
+            span: span(4, 7),
+            text: "TWO!".to_owned(),
+            expected: None,
+        }]);
+
+        let stats = set.stats(source);
+        assert_eq!(stats.lines_added, 1);
+        assert_eq!(stats.lines_removed, 1);
+        assert_eq!(stats.chars_inserted, 4);
+        assert_eq!(stats.chars_deleted, 3);
+    }
+
+    #[test]
+    fn test_stats_of_an_empty_set_are_zero() {
+        assert_eq!(ChangeSet::default().stats("anything\n"), ChangeStats::default());
+    }
+
+    #[test]
+    fn test_side_by_side_marks_pure_insertions_and_deletions() {
+        let source = "a\nb\n";
+        let set = ChangeSet::from_operations([
+            ChangeOperation::Insert { offset: 0, text: "header\n".to_owned() },
+            ChangeOperation::Delete { span: span(2, 4), expected: None },
+        ]);
+
+        let pairs = set.to_side_by_side(source, 80);
+        assert_eq!(
+            pairs,
+            vec![
+                (None, Some("header".to_owned())),
+                (Some("a".to_owned()), Some("a".to_owned())),
+                (Some("b".to_owned()), None),
+            ],
+        );
+    }
+}
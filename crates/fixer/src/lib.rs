@@ -0,0 +1,290 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_span::Span;
+
+pub use crate::change_set::ApplyStats;
+pub use crate::change_set::ChangeSet;
+pub use crate::diff::Change;
+pub use crate::diff::ChangeStats;
+pub use crate::lines::CleanupOptions;
+
+mod change_set;
+mod diff;
+mod lines;
+
+/// How confident we are that applying a fix preserves program behavior.
+///
+/// Variants are ordered from least to most risky, so `Safe <
+/// PotentiallyUnsafe < Unsafe` and `Ord` comparisons can be used to clamp or
+/// threshold classifications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub enum SafetyClassification {
+    #[default]
+    Safe,
+    PotentiallyUnsafe,
+    Unsafe,
+}
+
+/// A single text change, expressed against original source offsets.
+///
+/// `expected` optionally anchors a span-based operation to the text it was
+/// planned against: when set, application verifies the range still holds
+/// exactly those bytes and skips the operation as stale otherwise. Plans
+/// that are cached or shipped across process boundaries (LSP code
+/// actions) should be anchored with [`FixPlan::anchored`] so drifted
+/// files are never corrupted by offsets computed against old content.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChangeOperation {
+    Insert { offset: usize, text: String },
+    Replace { span: Span, text: String, expected: Option<String> },
+    Delete { span: Span, expected: Option<String> },
+}
+
+/// A change together with its safety classification.
+///
+/// Offsets and spans always refer to the **original** source the plan was
+/// computed from, never to partially-fixed text: application splices
+/// back-to-front precisely so that a length-changing edit cannot shift
+/// the ranges of operations before it. Rules therefore emit spans
+/// straight from the AST without compensating for their own earlier
+/// edits.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixOperation {
+    pub safety_classification: SafetyClassification,
+    pub operation: ChangeOperation,
+}
+
+/// An ordered collection of fix operations produced by one or more rules.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixPlan {
+    operations: Vec<FixOperation>,
+}
+
+impl FixPlan {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(mut self, offset: usize, text: impl Into<String>, safety: SafetyClassification) -> Self {
+        self.operations
+            .push(FixOperation { safety_classification: safety, operation: ChangeOperation::Insert { offset, text: text.into() } });
+        self
+    }
+
+    pub fn replace(mut self, span: Span, text: impl Into<String>, safety: SafetyClassification) -> Self {
+        self.operations.push(FixOperation {
+            safety_classification: safety,
+            operation: ChangeOperation::Replace { span, text: text.into(), expected: None },
+        });
+        self
+    }
+
+    pub fn delete(mut self, span: Span, safety: SafetyClassification) -> Self {
+        self.operations
+            .push(FixOperation { safety_classification: safety, operation: ChangeOperation::Delete { span, expected: None } });
+        self
+    }
+
+    /// Anchor every span-based operation to the text it targets in
+    /// `source`.
+    ///
+    /// Call this at planning time, while `source` is the content the spans
+    /// were computed from. Application then verifies each anchored range
+    /// still holds the expected bytes and skips stale operations instead
+    /// of corrupting a file that changed in the meantime. Plans applied
+    /// immediately, in the same process that produced them, do not need
+    /// anchoring.
+    pub fn anchored(mut self, source: &str) -> Self {
+        for operation in &mut self.operations {
+            match &mut operation.operation {
+                ChangeOperation::Replace { span, expected, .. } | ChangeOperation::Delete { span, expected } => {
+                    *expected = source.get(span.to_range()).map(str::to_owned);
+                }
+                ChangeOperation::Insert { .. } => {}
+            }
+        }
+
+        self
+    }
+
+    pub fn operations(&self) -> &[FixOperation] {
+        &self.operations
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Merge another plan's operations into this one.
+    pub fn merge(mut self, other: FixPlan) -> Self {
+        self.operations.extend(other.operations);
+        self
+    }
+
+    /// Clamp every operation's classification to at most `cap` riskiness.
+    ///
+    /// Use `with_max_safety(SafetyClassification::Safe)` to declare that a
+    /// composite plan is safe as a whole even if parts were classified more
+    /// cautiously.
+    pub fn with_max_safety(mut self, cap: SafetyClassification) -> Self {
+        for operation in &mut self.operations {
+            operation.safety_classification = operation.safety_classification.min(cap);
+        }
+
+        self
+    }
+
+    /// Raise every operation's classification to at least `floor` riskiness.
+    ///
+    /// Use `with_min_safety(SafetyClassification::PotentiallyUnsafe)` when
+    /// combining individually-safe edits whose combination is not obviously
+    /// safe; the plan then only applies at a more permissive threshold.
+    pub fn with_min_safety(mut self, floor: SafetyClassification) -> Self {
+        for operation in &mut self.operations {
+            operation.safety_classification = operation.safety_classification.max(floor);
+        }
+
+        self
+    }
+
+    /// The riskiest classification present in the plan, if any operation exists.
+    pub fn get_minimum_safety_classification(&self) -> SafetyClassification {
+        self.operations.iter().map(|operation| operation.safety_classification).max().unwrap_or_default()
+    }
+
+    /// Resolve the plan into a concrete change set, keeping only operations
+    /// classified at or below the `threshold` riskiness.
+    pub fn to_change_set(&self, threshold: SafetyClassification) -> ChangeSet {
+        ChangeSet::from_operations(
+            self.operations
+                .iter()
+                .filter(|operation| operation.safety_classification <= threshold)
+                .map(|operation| operation.operation.clone()),
+        )
+    }
+
+    /// Apply the plan to `source`, honoring the safety `threshold`.
+    ///
+    /// No-op operations — replacements identical to the text they replace —
+    /// are dropped first, so a plan that "changes" nothing produces an
+    /// empty change set and callers can report it as such.
+    pub fn execute(&self, source: &str, threshold: SafetyClassification) -> String {
+        self.to_change_set(threshold).without_noops(source).apply(source)
+    }
+}
+
+/// Apply `plan` to `content`, returning the result only when it differs.
+///
+/// `None` means nothing to write: every operation was filtered by the
+/// safety threshold, was a no-op, or the output matched the input byte
+/// for byte. Callers writing files should treat `None` as "leave the file
+/// untouched" so timestamps and watchers see no spurious change.
+///
+/// Line endings: when the dominant line ending of `content` is CRLF,
+/// newlines in *inserted* text are normalized to CRLF too. Rules always
+/// emit plain `\n`, so this is the one place where a CRLF file stays
+/// uniformly CRLF instead of accumulating mixed endings fix by fix.
+pub fn apply_plan_to_content(content: &str, plan: &FixPlan, max_safety: SafetyClassification) -> Option<String> {
+    let mut plan = plan.clone();
+    if dominant_line_ending_is_crlf(content) {
+        for operation in &mut plan.operations {
+            match &mut operation.operation {
+                ChangeOperation::Insert { text, .. } | ChangeOperation::Replace { text, .. } => {
+                    *text = normalize_to_crlf(text);
+                }
+                ChangeOperation::Delete { .. } => {}
+            }
+        }
+    }
+
+    let fixed = plan.execute(content, max_safety);
+    (fixed != content).then_some(fixed)
+}
+
+/// Apply `plan` to the file at `path`, writing only when changes occurred.
+///
+/// The write is atomic: the new content goes to a temporary sibling file
+/// which is then renamed over the original, so a crash mid-write never
+/// leaves a truncated source file. Returns whether the file was written.
+pub fn apply_plan_to_file(
+    path: &std::path::Path,
+    content: &str,
+    plan: &FixPlan,
+    max_safety: SafetyClassification,
+) -> std::io::Result<bool> {
+    let Some(fixed) = apply_plan_to_content(content, plan, max_safety) else {
+        return Ok(false);
+    };
+
+    // Append to the full file name instead of replacing the extension:
+    // `foo.php` and `foo.phtml` fixed in parallel must not collide on one
+    // `foo.mago-fix.tmp`. The process id keeps concurrent runs apart too.
+    let mut temporary_name = path.file_name().map(ToOwned::to_owned).unwrap_or_default();
+    temporary_name.push(format!(".mago-fix.{}.tmp", std::process::id()));
+    let temporary = path.with_file_name(temporary_name);
+
+    std::fs::write(&temporary, &fixed)?;
+    std::fs::rename(&temporary, path)?;
+    Ok(true)
+}
+
+/// Whether `content` uses CRLF for the majority of its line endings.
+fn dominant_line_ending_is_crlf(content: &str) -> bool {
+    let crlf = content.matches("\r\n").count();
+    let total = content.matches('\n').count();
+    crlf * 2 > total
+}
+
+/// Replace every bare `\n` with `\r\n`, leaving existing `\r\n` intact.
+fn normalize_to_crlf(text: &str) -> String {
+    text.replace("\r\n", "\n").replace('\n', "\r\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_span::FileId;
+    use mago_span::Position;
+
+    use super::*;
+
+    fn span(start: usize, end: usize) -> Span {
+        Span::new(Position::new(FileId(0), start, 1), Position::new(FileId(0), end, 1))
+    }
+
+    #[test]
+    fn test_unchanged_content_returns_none() {
+        let content = "echo 'ok';";
+        let noop = FixPlan::new().replace(span(5, 9), "'ok'", SafetyClassification::Safe);
+
+        assert_eq!(apply_plan_to_content(content, &noop, SafetyClassification::Safe), None);
+        assert_eq!(apply_plan_to_content(content, &FixPlan::new(), SafetyClassification::Safe), None);
+    }
+
+    #[test]
+    fn test_threshold_filtering_can_make_a_plan_a_noop() {
+        let content = "echo 'ok';";
+        let unsafe_only = FixPlan::new().delete(span(0, 5), SafetyClassification::Unsafe);
+
+        assert_eq!(apply_plan_to_content(content, &unsafe_only, SafetyClassification::Safe), None);
+        assert!(apply_plan_to_content(content, &unsafe_only, SafetyClassification::Unsafe).is_some());
+    }
+
+    #[test]
+    fn test_inserted_newlines_match_a_crlf_file() {
+        let content = "a();\r\nb();\r\n";
+        let plan = FixPlan::new().insert(0, "header();\n", SafetyClassification::Safe);
+
+        let fixed = apply_plan_to_content(content, &plan, SafetyClassification::Safe).expect("content changes");
+        assert_eq!(fixed, "header();\r\na();\r\nb();\r\n");
+    }
+
+    #[test]
+    fn test_lf_files_keep_lf_insertions() {
+        let content = "a();\nb();\n";
+        let plan = FixPlan::new().insert(0, "header();\n", SafetyClassification::Safe);
+
+        let fixed = apply_plan_to_content(content, &plan, SafetyClassification::Safe).expect("content changes");
+        assert_eq!(fixed, "header();\na();\nb();\n");
+    }
+}
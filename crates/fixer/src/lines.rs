@@ -0,0 +1,284 @@
+use mago_span::FileId;
+use mago_span::Position;
+use mago_span::Span;
+
+use crate::FixPlan;
+use crate::SafetyClassification;
+
+impl FixPlan {
+    /// Ensure `content` ends with exactly one `\n`.
+    ///
+    /// Idempotent: appends a single newline when none is present, deletes
+    /// surplus trailing newlines when there are several, and adds no
+    /// operation at all when the file already ends correctly. `\r\n`
+    /// terminators are preserved — only whole extra terminators are
+    /// trimmed.
+    pub fn ensure_trailing_newline(self, file_id: FileId, content: &str, safety: SafetyClassification) -> Self {
+        let bytes = content.as_bytes();
+        if bytes.is_empty() {
+            return self;
+        }
+
+        if !bytes.ends_with(b"\n") {
+            return self.insert(bytes.len(), "\n", safety);
+        }
+
+        // Find where the run of trailing line terminators begins, keeping
+        // the first of them.
+        let mut keep_end = bytes.len();
+        loop {
+            let before = match bytes[..keep_end].strip_suffix(b"\r\n") {
+                Some(before) => before.len(),
+                None => match bytes[..keep_end].strip_suffix(b"\n") {
+                    Some(before) => before.len(),
+                    None => break,
+                },
+            };
+
+            keep_end = before;
+        }
+
+        let first_terminator_end =
+            if bytes[keep_end..].starts_with(b"\r\n") { keep_end + 2 } else { keep_end + 1 };
+
+        if first_terminator_end == bytes.len() {
+            return self;
+        }
+
+        let line = line_number(content, first_terminator_end);
+        self.delete(
+            Span::new(
+                Position::new(file_id, first_terminator_end, line),
+                Position::new(file_id, bytes.len(), line_number(content, bytes.len())),
+            ),
+            safety,
+        )
+    }
+
+    /// Delete the whole line containing byte `offset`, terminator included.
+    ///
+    /// The range runs from the byte after the previous `\n` (or the start of
+    /// the file) through the line's own terminator (or the end of the file
+    /// for an unterminated last line), so deleting a blank line leaves no
+    /// residue.
+    pub fn delete_line_containing(self, file_id: FileId, offset: usize, content: &str, safety: SafetyClassification) -> Self {
+        let bytes = content.as_bytes();
+        let offset = offset.min(bytes.len());
+
+        let start = match bytes[..offset].iter().rposition(|&byte| byte == b'\n') {
+            Some(newline) => newline + 1,
+            None => 0,
+        };
+
+        let end = match bytes[offset..].iter().position(|&byte| byte == b'\n') {
+            Some(newline) => offset + newline + 1,
+            None => bytes.len(),
+        };
+
+        if start == end {
+            return self;
+        }
+
+        self.delete(
+            Span::new(
+                Position::new(file_id, start, line_number(content, start)),
+                Position::new(file_id, end, line_number(content, end)),
+            ),
+            safety,
+        )
+    }
+}
+
+/// What [`FixPlan::delete_with_cleanup`] tidies around the deleted range.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CleanupOptions {
+    /// Consume the comma (and surrounding whitespace) following the range,
+    /// or — when the range holds the last element — the comma before it.
+    pub trim_comma: bool,
+    /// Extend the deletion over whitespace so no doubled blank line
+    /// remains where the range was.
+    pub collapse_blank_lines: bool,
+    /// When the deletion leaves its line empty, remove the whole line.
+    pub remove_empty_line: bool,
+}
+
+impl FixPlan {
+    /// Delete `span` plus the punctuation and whitespace residue the bare
+    /// deletion would leave behind.
+    ///
+    /// This is the shared implementation of "remove an array element /
+    /// argument / list item": the trailing comma goes with the element (or
+    /// the leading comma, for a final element), and the surrounding
+    /// whitespace is tightened so neither `[1, , 3]` nor a stray blank
+    /// line survives. The cleanup inspects `content` around the range and
+    /// emits one widened delete operation.
+    pub fn delete_with_cleanup(
+        self,
+        span: Span,
+        content: &str,
+        options: CleanupOptions,
+        safety: SafetyClassification,
+    ) -> Self {
+        let bytes = content.as_bytes();
+        let mut start = span.start.offset.min(bytes.len());
+        let mut end = span.end.offset.min(bytes.len());
+
+        if options.trim_comma {
+            // Prefer the trailing comma; fall back to a leading one so the
+            // last element of `[$a, $b]` removes the `, ` before it.
+            let mut probe = end;
+            while probe < bytes.len() && (bytes[probe] == b' ' || bytes[probe] == b'\t') {
+                probe += 1;
+            }
+
+            if probe < bytes.len() && bytes[probe] == b',' {
+                end = probe + 1;
+                // A single space after the consumed comma belongs to it.
+                if end < bytes.len() && bytes[end] == b' ' {
+                    end += 1;
+                }
+            } else {
+                let mut probe = start;
+                while probe > 0 && (bytes[probe - 1] == b' ' || bytes[probe - 1] == b'\t' || bytes[probe - 1] == b'\n')
+                {
+                    probe -= 1;
+                }
+
+                if probe > 0 && bytes[probe - 1] == b',' {
+                    start = probe - 1;
+                }
+            }
+        }
+
+        if options.remove_empty_line {
+            // If only whitespace remains on the line, take the whole line.
+            let line_start = bytes[..start].iter().rposition(|&byte| byte == b'\n').map_or(0, |index| index + 1);
+            let line_end = bytes[end..].iter().position(|&byte| byte == b'\n').map_or(bytes.len(), |index| end + index + 1);
+
+            let before_is_blank = bytes[line_start..start].iter().all(|byte| matches!(byte, b' ' | b'\t'));
+            let after_is_blank = bytes[end..line_end].iter().all(|byte| matches!(byte, b' ' | b'\t' | b'\n'));
+
+            if before_is_blank && after_is_blank {
+                start = line_start;
+                end = line_end;
+            }
+        }
+
+        if options.collapse_blank_lines {
+            // If a blank line precedes and follows the deleted range, eat
+            // one of them so the two do not fuse into a doubled gap.
+            while content[end..].starts_with('\n') && content[..start].ends_with("\n\n") {
+                start -= 1;
+            }
+        }
+
+        if start == end {
+            return self;
+        }
+
+        self.delete(
+            Span::new(
+                Position::new(span.start.file_id, start, line_number(content, start)),
+                Position::new(span.start.file_id, end, line_number(content, end)),
+            ),
+            safety,
+        )
+    }
+}
+
+/// The 1-based line of byte `offset` in `content`.
+fn line_number(content: &str, offset: usize) -> usize {
+    content.as_bytes().iter().take(offset).filter(|&&byte| byte == b'\n').count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ChangeOperation;
+
+    fn apply(plan: FixPlan, content: &str) -> String {
+        plan.execute(content, SafetyClassification::Safe)
+    }
+
+    #[test]
+    fn test_ensure_trailing_newline_is_idempotent() {
+        let file = FileId(0);
+        assert_eq!(apply(FixPlan::new().ensure_trailing_newline(file, "a", SafetyClassification::Safe), "a"), "a\n");
+        assert_eq!(apply(FixPlan::new().ensure_trailing_newline(file, "a\n", SafetyClassification::Safe), "a\n"), "a\n");
+        assert_eq!(
+            apply(FixPlan::new().ensure_trailing_newline(file, "a\n\n\n", SafetyClassification::Safe), "a\n\n\n"),
+            "a\n",
+        );
+        assert_eq!(
+            apply(FixPlan::new().ensure_trailing_newline(file, "a\r\n\r\n", SafetyClassification::Safe), "a\r\n\r\n"),
+            "a\r\n",
+        );
+        assert!(FixPlan::new().ensure_trailing_newline(file, "a\n", SafetyClassification::Safe).is_empty());
+    }
+
+    #[test]
+    fn test_delete_line_containing_includes_terminator() {
+        let file = FileId(0);
+        let content = "one\n\nthree";
+
+        // The blank line (offset 4) disappears without residue.
+        assert_eq!(
+            apply(FixPlan::new().delete_line_containing(file, 4, content, SafetyClassification::Safe), content),
+            "one\nthree",
+        );
+
+        // The unterminated last line is removed up to the end of the file.
+        assert_eq!(
+            apply(FixPlan::new().delete_line_containing(file, 7, content, SafetyClassification::Safe), content),
+            "one\n\n",
+        );
+    }
+
+    #[test]
+    fn test_delete_with_cleanup_takes_the_trailing_comma() {
+        let file = FileId(0);
+        let content = "[$a, $b, $c]";
+        let span = Span::new(Position::new(file, 5, 1), Position::new(file, 7, 1));
+
+        let options = CleanupOptions { trim_comma: true, ..CleanupOptions::default() };
+        assert_eq!(
+            apply(FixPlan::new().delete_with_cleanup(span, content, options, SafetyClassification::Safe), content),
+            "[$a, $c]",
+        );
+    }
+
+    #[test]
+    fn test_delete_with_cleanup_takes_the_leading_comma_for_the_last_element() {
+        let file = FileId(0);
+        let content = "[$a, $b]";
+        let span = Span::new(Position::new(file, 5, 1), Position::new(file, 7, 1));
+
+        let options = CleanupOptions { trim_comma: true, ..CleanupOptions::default() };
+        assert_eq!(
+            apply(FixPlan::new().delete_with_cleanup(span, content, options, SafetyClassification::Safe), content),
+            "[$a]",
+        );
+    }
+
+    #[test]
+    fn test_delete_with_cleanup_removes_the_emptied_line() {
+        let file = FileId(0);
+        let content = "[\n    $a,\n    $b,\n]";
+        let span = Span::new(Position::new(file, 14, 3), Position::new(file, 16, 3));
+
+        let options = CleanupOptions { trim_comma: true, remove_empty_line: true, ..CleanupOptions::default() };
+        assert_eq!(
+            apply(FixPlan::new().delete_with_cleanup(span, content, options, SafetyClassification::Safe), content),
+            "[\n    $a,\n]",
+        );
+    }
+
+    #[test]
+    fn test_operations_carry_whole_line_ranges() {
+        let file = FileId(0);
+        let plan = FixPlan::new().delete_line_containing(file, 5, "ab\ncdef\ngh", SafetyClassification::Safe);
+        let [operation] = plan.operations() else { panic!("expected one operation") };
+        let ChangeOperation::Delete { span, .. } = &operation.operation else { panic!("expected a delete") };
+        assert_eq!((span.start.offset, span.end.offset), (3, 8));
+    }
+}
@@ -0,0 +1,157 @@
+//! Short array syntax: `array(...)` → `[...]` and `list(...)` → `[...]`.
+//!
+//! Like the control-structure pass, the transformations are `(Span,
+//! String)` replacement pairs against the original source, shared between
+//! the formatter and the `short-array-syntax` lint rule. Only the
+//! delimiters are touched — the keyword plus opening parenthesis becomes
+//! `[` and the closing parenthesis becomes `]` — so nested structure,
+//! keys, by-reference elements, skipped destructuring slots, trailing
+//! commas, and comments between the delimiters are preserved byte for
+//! byte. Nested `array(list(...))` constructs convert because each node
+//! contributes its own pair of edits.
+
+use mago_ast::*;
+use mago_span::Span;
+
+/// Settings for the array-syntax pass.
+///
+/// Short `list` destructuring (`[$a, $b] = ...`) requires PHP 7.1; the
+/// caller decides the target version and disables `short_lists` below it.
+/// `array()` literals have had short syntax since 5.4 and are gated
+/// separately only for symmetry.
+#[derive(Debug, Clone, Copy)]
+pub struct ArraySyntaxSettings {
+    pub short_arrays: bool,
+    pub short_lists: bool,
+}
+
+impl Default for ArraySyntaxSettings {
+    fn default() -> Self {
+        Self { short_arrays: true, short_lists: true }
+    }
+}
+
+/// The edits converting one `array(...)` literal to `[...]`.
+pub fn edits_for_legacy_array(array: &LegacyArray, settings: &ArraySyntaxSettings) -> Vec<(Span, String)> {
+    if !settings.short_arrays {
+        return Vec::new();
+    }
+
+    delimiter_edits(array.array, array.left_parenthesis, array.right_parenthesis)
+}
+
+/// The edits converting one `list(...)` target to `[...]`.
+///
+/// This applies wherever the `list` node occurs — assignment left-hand
+/// sides, `foreach` targets, and nested slots inside another `list` or
+/// short destructuring pattern.
+pub fn edits_for_list(list: &List, settings: &ArraySyntaxSettings) -> Vec<(Span, String)> {
+    if !settings.short_lists {
+        return Vec::new();
+    }
+
+    delimiter_edits(list.list, list.left_parenthesis, list.right_parenthesis)
+}
+
+/// Replace `keyword ... (` with `[` and `)` with `]`, leaving the interior
+/// untouched.
+fn delimiter_edits(keyword: Span, left_parenthesis: Span, right_parenthesis: Span) -> Vec<(Span, String)> {
+    vec![(keyword.join(left_parenthesis), "[".to_owned()), (right_parenthesis, "]".to_owned())]
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_interner::ThreadedInterner;
+    use mago_span::HasSpan;
+
+    use super::*;
+    use crate::print_modified;
+
+    /// Convert every legacy array and `list` in `source` and return the
+    /// full formatted text.
+    fn convert(source: &str, settings: &ArraySyntaxSettings) -> String {
+        let interner = ThreadedInterner::new();
+        let (program, error) = mago_parser::parse_source_text(&interner, source);
+        assert!(error.is_none(), "test source must parse");
+
+        let mut edits = Vec::new();
+        let mut stack = vec![Node::Program(&program)];
+        while let Some(node) = stack.pop() {
+            match node {
+                Node::Expression(Expression::LegacyArray(array)) => {
+                    edits.extend(edits_for_legacy_array(array, settings));
+                }
+                Node::Expression(Expression::List(list)) => {
+                    edits.extend(edits_for_list(list, settings));
+                }
+                _ => {}
+            }
+            stack.extend(node.children());
+        }
+
+        print_modified(&program.span(), source, &edits).expect("edits must splice")
+    }
+
+    #[test]
+    fn test_flat_array_with_keys_and_trailing_comma() {
+        assert_eq!(
+            convert("<?php $a = array('k' => 1, 2, );", &ArraySyntaxSettings::default()),
+            "<?php $a = ['k' => 1, 2, ];",
+        );
+    }
+
+    #[test]
+    fn test_nested_mixed_array_and_list() {
+        assert_eq!(
+            convert(
+                "<?php list($a, list($b, $c)) = array(1, array(2, 3));",
+                &ArraySyntaxSettings::default(),
+            ),
+            "<?php [$a, [$b, $c]] = [1, [2, 3]];",
+        );
+    }
+
+    #[test]
+    fn test_by_reference_and_skipped_elements_survive() {
+        assert_eq!(
+            convert("<?php list(, , &$c) = $rows;", &ArraySyntaxSettings::default()),
+            "<?php [, , &$c] = $rows;",
+        );
+    }
+
+    #[test]
+    fn test_foreach_list_target() {
+        assert_eq!(
+            convert("<?php foreach ($pairs as list($k, $v)) {}", &ArraySyntaxSettings::default()),
+            "<?php foreach ($pairs as [$k, $v]) {}",
+        );
+    }
+
+    #[test]
+    fn test_comments_inside_the_parentheses_are_carried() {
+        assert_eq!(
+            convert(
+                "<?php $a = array( /* keep me */ 1, 2 );",
+                &ArraySyntaxSettings::default(),
+            ),
+            "<?php $a = [ /* keep me */ 1, 2 ];",
+        );
+    }
+
+    #[test]
+    fn test_keyword_space_before_parenthesis_collapses() {
+        assert_eq!(
+            convert("<?php $a = ARRAY (1);", &ArraySyntaxSettings::default()),
+            "<?php $a = [1];",
+        );
+    }
+
+    #[test]
+    fn test_lists_are_preserved_below_php_71() {
+        let settings = ArraySyntaxSettings { short_arrays: true, short_lists: false };
+        assert_eq!(
+            convert("<?php list($a) = array(1);", &settings),
+            "<?php list($a) = [1];",
+        );
+    }
+}
@@ -0,0 +1,314 @@
+//! Attribute list layout.
+//!
+//! Declaration-level attributes are normalized to either one `#[...]`
+//! group per declaration or one `#[...]` line per attribute; argument
+//! lists that push a line past the configured width wrap the same way
+//! call arguments do (one argument per line, trailing comma). Inline
+//! positions — parameters, properties, anonymous classes, closures — keep
+//! their attributes on the same line as the construct unless too long.
+//!
+//! The pass rewrites only the attribute block itself: a docblock preceding
+//! the attributes is outside the rewritten span by construction, so the
+//! required `docblock, attributes, declaration` ordering is preserved.
+
+use crate::FormatSettings;
+
+/// How multiple attributes on one declaration are grouped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AttributeStyle {
+    /// Every attribute on its own line: `#[A]` then `#[B]`.
+    #[default]
+    OnePerLine,
+    /// All attributes share one group: `#[A, B]`.
+    Grouped,
+}
+
+/// Reformat the attribute block of a declaration.
+///
+/// `raw` is the source slice covering the declaration's attribute lists
+/// (from the first `#[` to the closing `]` of the last list, including the
+/// whitespace between lists); `indent` is the declaration's leading
+/// indentation. The result ends with a newline plus `indent`, ready to be
+/// followed by the declaration itself.
+pub fn format_declaration_attributes(raw: &str, settings: &FormatSettings, indent: &str) -> String {
+    let attributes = split_attributes(raw);
+    if attributes.is_empty() {
+        return String::new();
+    }
+
+    let mut out = String::new();
+    match settings.attribute_style {
+        AttributeStyle::OnePerLine => {
+            for attribute in &attributes {
+                out.push_str(&render_group(std::slice::from_ref(attribute), settings, indent));
+                out.push('\n');
+                out.push_str(indent);
+            }
+        }
+        AttributeStyle::Grouped => {
+            out.push_str(&render_group(&attributes, settings, indent));
+            out.push('\n');
+            out.push_str(indent);
+        }
+    }
+
+    out
+}
+
+/// Reformat attributes appearing inline (parameters, properties, anonymous
+/// classes, closures). They stay on one line, separated from what follows
+/// by a single space; only over-long argument lists are wrapped, since a
+/// line break before an inline construct would detach the attribute from
+/// its target.
+pub fn format_inline_attributes(raw: &str, settings: &FormatSettings, column: usize) -> String {
+    let attributes = split_attributes(raw);
+    if attributes.is_empty() {
+        return String::new();
+    }
+
+    let single_line = attributes.iter().map(|attribute| format!("#[{attribute}]")).collect::<Vec<_>>().join(" ");
+
+    if column + single_line.len() <= settings.line_width {
+        return single_line;
+    }
+
+    // Too long: keep the attributes inline but wrap each one's arguments.
+    attributes
+        .iter()
+        .map(|attribute| render_group(std::slice::from_ref(attribute), settings, &" ".repeat(column)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Split an attribute block into individual attributes (name plus argument
+/// list), flattening `#[A, B]` groups and multiple `#[...]` lists alike.
+fn split_attributes(raw: &str) -> Vec<String> {
+    let mut attributes = Vec::new();
+    let mut rest = raw;
+
+    while let Some(start) = rest.find("#[") {
+        let body_start = start + 2;
+        let Some(length) = matching_bracket(&rest[body_start..]) else {
+            break;
+        };
+
+        for attribute in split_top_level(&rest[body_start..body_start + length]) {
+            let attribute = attribute.trim();
+            if !attribute.is_empty() {
+                attributes.push(attribute.to_owned());
+            }
+        }
+
+        rest = &rest[body_start + length + 1..];
+    }
+
+    attributes
+}
+
+/// Render one `#[...]` group, wrapping argument lists that overflow.
+fn render_group(attributes: &[String], settings: &FormatSettings, indent: &str) -> String {
+    let inline = format!("#[{}]", attributes.join(", "));
+    if indent.len() + inline.len() <= settings.line_width {
+        return inline;
+    }
+
+    let mut out = String::from("#[");
+    for (index, attribute) in attributes.iter().enumerate() {
+        if index > 0 {
+            out.push_str(", ");
+        }
+        out.push_str(&wrap_arguments(attribute, settings, indent));
+    }
+    out.push(']');
+
+    out
+}
+
+/// Wrap one attribute's argument list, one argument per line with a
+/// trailing comma — the same shape call arguments take when wrapped.
+fn wrap_arguments(attribute: &str, settings: &FormatSettings, indent: &str) -> String {
+    let Some(open) = attribute.find('(') else {
+        return attribute.to_owned();
+    };
+    if !attribute.ends_with(')') {
+        return attribute.to_owned();
+    }
+
+    let name = &attribute[..open];
+    let arguments = split_top_level(&attribute[open + 1..attribute.len() - 1]);
+    if arguments.is_empty() {
+        return attribute.to_owned();
+    }
+
+    let inner = format!("{indent}    ");
+    let mut out = format!("{name}(\n");
+    for argument in arguments {
+        out.push_str(&inner);
+        out.push_str(argument.trim());
+        out.push_str(",\n");
+    }
+    out.push_str(indent);
+    out.push(')');
+
+    out
+}
+
+/// Split on commas at the top nesting level, ignoring commas inside
+/// parentheses, brackets, braces, and string literals.
+fn split_top_level(body: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0usize;
+    let mut in_string: Option<u8> = None;
+    let bytes = body.as_bytes();
+
+    let mut index = 0;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        match in_string {
+            Some(quote) => {
+                if byte == b'\\' {
+                    index += 1;
+                } else if byte == quote {
+                    in_string = None;
+                }
+            }
+            None => match byte {
+                b'\'' | b'"' => in_string = Some(byte),
+                b'(' | b'[' | b'{' => depth += 1,
+                b')' | b']' | b'}' => depth = depth.saturating_sub(1),
+                b',' if depth == 0 => {
+                    parts.push(&body[start..index]);
+                    start = index + 1;
+                }
+                _ => {}
+            },
+        }
+
+        index += 1;
+    }
+
+    if !body[start..].trim().is_empty() {
+        parts.push(&body[start..]);
+    }
+
+    parts
+}
+
+/// The length of the bracketed body starting after `#[`, i.e. the index of
+/// the matching `]`, accounting for nesting and strings.
+fn matching_bracket(body: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut in_string: Option<u8> = None;
+    let bytes = body.as_bytes();
+
+    let mut index = 0;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        match in_string {
+            Some(quote) => {
+                if byte == b'\\' {
+                    index += 1;
+                } else if byte == quote {
+                    in_string = None;
+                }
+            }
+            None => match byte {
+                b'\'' | b'"' => in_string = Some(byte),
+                b'(' | b'[' | b'{' => depth += 1,
+                b')' | b'}' => depth = depth.saturating_sub(1),
+                b']' => {
+                    if depth == 0 {
+                        return Some(index);
+                    }
+                    depth -= 1;
+                }
+                _ => {}
+            },
+        }
+
+        index += 1;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(style: AttributeStyle, width: usize) -> FormatSettings {
+        FormatSettings { line_width: width, attribute_style: style, ..FormatSettings::default() }
+    }
+
+    #[test]
+    fn test_group_is_split_into_one_attribute_per_line() {
+        let formatted =
+            format_declaration_attributes("#[Route('/a'), Cache(ttl: 60)]", &settings(AttributeStyle::OnePerLine, 120), "");
+
+        assert_eq!(formatted, "#[Route('/a')]\n#[Cache(ttl: 60)]\n");
+    }
+
+    #[test]
+    fn test_separate_lists_are_merged_into_one_group() {
+        let formatted =
+            format_declaration_attributes("#[Route('/a')]\n#[Cache(ttl: 60)]", &settings(AttributeStyle::Grouped, 120), "");
+
+        assert_eq!(formatted, "#[Route('/a'), Cache(ttl: 60)]\n");
+    }
+
+    #[test]
+    fn test_order_across_lists_is_preserved() {
+        let formatted = format_declaration_attributes(
+            "#[First]\n#[Second, Third]",
+            &settings(AttributeStyle::OnePerLine, 120),
+            "    ",
+        );
+
+        assert_eq!(formatted, "#[First]\n    #[Second]\n    #[Third]\n    ");
+    }
+
+    #[test]
+    fn test_overlong_arguments_wrap_like_call_arguments() {
+        let formatted = format_declaration_attributes(
+            "#[Assert\\Length(min: 10, max: 250, minMessage: 'too short', maxMessage: 'too long')]",
+            &settings(AttributeStyle::OnePerLine, 40),
+            "",
+        );
+
+        assert_eq!(
+            formatted,
+            "#[Assert\\Length(\n    min: 10,\n    max: 250,\n    minMessage: 'too short',\n    maxMessage: 'too long',\n)]\n",
+        );
+    }
+
+    #[test]
+    fn test_inline_attributes_stay_on_the_same_line() {
+        // Parameter and anonymous-class positions: the attribute must not be
+        // pushed onto its own line.
+        let formatted = format_inline_attributes("#[SensitiveParameter]", &settings(AttributeStyle::OnePerLine, 120), 30);
+
+        assert_eq!(formatted, "#[SensitiveParameter]");
+    }
+
+    #[test]
+    fn test_inline_attributes_wrap_arguments_only_when_too_long() {
+        let formatted = format_inline_attributes(
+            "#[Autowire(service: 'app.very.long.service.identifier')]",
+            &settings(AttributeStyle::OnePerLine, 40),
+            8,
+        );
+
+        assert_eq!(
+            formatted,
+            "#[Autowire(\n            service: 'app.very.long.service.identifier',\n        )]",
+        );
+    }
+
+    #[test]
+    fn test_nested_brackets_and_strings_do_not_confuse_splitting() {
+        let attributes = split_attributes(r#"#[Map(['a, b' => [1, 2]]), Other("x]y")]"#);
+
+        assert_eq!(attributes, vec![r#"Map(['a, b' => [1, 2]])"#.to_owned(), r#"Other("x]y")"#.to_owned()]);
+    }
+}
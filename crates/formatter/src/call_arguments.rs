@@ -0,0 +1,193 @@
+//! Call argument layout, including "last argument expansion".
+//!
+//! A multi-line closure, anonymous class, array literal, or `match`
+//! passed as the sole or final argument is *hugged*: it keeps the call's
+//! parentheses on the same lines as its own delimiters
+//! (`->map(function ($i) { ... })`) instead of forcing every argument
+//! onto its own line. Arrow functions are deliberately not huggable —
+//! they have no braced body to hug, so an over-long `fn` argument falls
+//! back to the one-argument-per-line layout.
+
+use mago_ast::*;
+
+use crate::FormatSettings;
+
+/// A pre-rendered argument: its formatted text (possibly multi-line) and
+/// whether it may be hugged in final position.
+#[derive(Debug, Clone)]
+pub struct FormattedArgument {
+    pub text: String,
+    pub huggable: bool,
+}
+
+/// Whether an argument expression is a candidate for last-argument
+/// expansion.
+pub fn argument_is_huggable(expression: &Expression) -> bool {
+    match expression {
+        Expression::Closure(_) | Expression::AnonymousClass(_) | Expression::Array(_) | Expression::Match(_) => true,
+        Expression::Parenthesized(inner) => argument_is_huggable(&inner.expression),
+        _ => false,
+    }
+}
+
+/// Lay out a call's argument list starting at `column`, producing the text
+/// between and including the parentheses.
+///
+/// Layout is chosen in order of preference: everything inline if it fits;
+/// hugging the final argument if it is huggable, the preceding arguments
+/// are single-line, and the head fits; otherwise one argument per line.
+pub fn layout_call_arguments(
+    arguments: &[FormattedArgument],
+    settings: &FormatSettings,
+    indent: &str,
+    column: usize,
+) -> String {
+    if arguments.is_empty() {
+        return "()".to_owned();
+    }
+
+    let inline = format!("({})", arguments.iter().map(|argument| argument.text.as_str()).collect::<Vec<_>>().join(", "));
+    if !inline.contains('\n') && column + inline.len() <= settings.line_width {
+        return inline;
+    }
+
+    if settings.hug_last_argument {
+        if let Some(hugged) = try_hug_last(arguments, settings, column) {
+            return hugged;
+        }
+    }
+
+    // Fall back: every argument on its own line, trailing comma.
+    let inner = format!("{indent}    ");
+    let mut out = String::from("(\n");
+    for argument in arguments {
+        out.push_str(&inner);
+        out.push_str(&reindent_tail(&argument.text, &inner));
+        out.push_str(",\n");
+    }
+    out.push_str(indent);
+    out.push(')');
+
+    out
+}
+
+/// Attempt last-argument expansion: `(a, b, <multi-line argument>)`.
+fn try_hug_last(arguments: &[FormattedArgument], settings: &FormatSettings, column: usize) -> Option<String> {
+    let (last, head) = arguments.split_last()?;
+    if !last.huggable {
+        return None;
+    }
+
+    // Every leading argument must be simple enough to stay inline; a
+    // multi-line argument before the hugged one would produce an unreadable
+    // mixed layout.
+    if head.iter().any(|argument| argument.text.contains('\n')) {
+        return None;
+    }
+
+    let mut prefix = String::from("(");
+    for argument in head {
+        prefix.push_str(&argument.text);
+        prefix.push_str(", ");
+    }
+
+    // The head plus the hugged argument's first line must fit; its
+    // remaining lines are governed by its own indentation.
+    let first_line = last.text.lines().next().unwrap_or("");
+    if column + prefix.len() + first_line.len() + 1 > settings.line_width {
+        return None;
+    }
+
+    Some(format!("{prefix}{})", last.text))
+}
+
+/// Shift the continuation lines of a multi-line argument to `indent`,
+/// keeping its first line untouched (it follows the opening position).
+fn reindent_tail(text: &str, indent: &str) -> String {
+    let mut lines = text.lines();
+    let Some(first) = lines.next() else {
+        return String::new();
+    };
+
+    let mut out = first.to_owned();
+    for line in lines {
+        out.push('\n');
+        if line.is_empty() {
+            continue;
+        }
+        out.push_str(indent);
+        out.push_str(line.trim_start());
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(width: usize, hug: bool) -> FormatSettings {
+        FormatSettings { line_width: width, hug_last_argument: hug, ..FormatSettings::default() }
+    }
+
+    fn simple(text: &str) -> FormattedArgument {
+        FormattedArgument { text: text.to_owned(), huggable: false }
+    }
+
+    fn huggable(text: &str) -> FormattedArgument {
+        FormattedArgument { text: text.to_owned(), huggable: true }
+    }
+
+    #[test]
+    fn test_final_closure_is_hugged() {
+        let closure = "function ($i) use ($x) {\n    return $i + $x;\n}";
+        let laid_out = layout_call_arguments(&[simple("$items"), huggable(closure)], &settings(80, true), "", 10);
+
+        assert_eq!(laid_out, "($items, function ($i) use ($x) {\n    return $i + $x;\n})");
+    }
+
+    #[test]
+    fn test_match_expression_hugs_like_a_closure() {
+        let r#match = "match ($status) {\n    200 => 'ok',\n    default => 'error',\n}";
+        let laid_out = layout_call_arguments(&[huggable(r#match)], &settings(80, true), "", 8);
+
+        assert_eq!(laid_out, "(match ($status) {\n    200 => 'ok',\n    default => 'error',\n})");
+    }
+
+    #[test]
+    fn test_hugging_disabled_breaks_all_arguments() {
+        let closure = "function ($i) {\n    return $i;\n}";
+        let laid_out = layout_call_arguments(&[simple("$items"), huggable(closure)], &settings(80, false), "", 10);
+
+        assert_eq!(laid_out, "(\n    $items,\n    function ($i) {\n    return $i;\n    },\n)");
+    }
+
+    #[test]
+    fn test_non_huggable_overflow_breaks_arguments() {
+        // An arrow function has no braced body; exceeding the width must
+        // fall through to one-per-line rather than hugging.
+        let arrow = "fn ($item) => $item->veryLongMethodName($with, $many, $arguments)";
+        let laid_out = layout_call_arguments(&[simple("$first"), simple(arrow)], &settings(40, true), "", 0);
+
+        assert_eq!(
+            laid_out,
+            format!("(\n    $first,\n    {arrow},\n)"),
+        );
+    }
+
+    #[test]
+    fn test_multiline_leading_argument_prevents_hugging() {
+        let array = "[\n    1,\n    2,\n]";
+        let closure = "function () {\n    work();\n}";
+        let laid_out = layout_call_arguments(&[huggable(array), huggable(closure)], &settings(80, true), "", 0);
+
+        assert!(laid_out.starts_with("(\n"), "mixed multi-line heads must break, got: {laid_out}");
+    }
+
+    #[test]
+    fn test_short_calls_stay_inline() {
+        let laid_out = layout_call_arguments(&[simple("$a"), simple("$b")], &settings(80, true), "", 0);
+
+        assert_eq!(laid_out, "($a, $b)");
+    }
+}
@@ -0,0 +1,173 @@
+//! Control structure style: brace insertion, `elseif` normalization, and
+//! PSR-12 keyword spacing.
+//!
+//! The transformations are expressed as `(Span, String)` replacement
+//! pairs against the original source, the common currency between the
+//! formatter (which splices them via [`crate::print_modified`]) and the
+//! `control-structure-braces` lint rule (which wraps them in a `FixPlan`).
+//! Keeping one implementation here is what lets lint-only users get the
+//! same output as the formatter.
+
+use mago_ast::*;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+/// Whether single-statement bodies are wrapped in braces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ControlBraces {
+    #[default]
+    Always,
+    Preserve,
+}
+
+/// Which spelling of the else-if construct is enforced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ElseIfStyle {
+    /// Normalize `else if` to `elseif` (PSR-12).
+    #[default]
+    Elseif,
+    /// Normalize `elseif` to `else if`.
+    ElseIf,
+    Preserve,
+}
+
+/// Settings for the control-structure pass.
+#[derive(Debug, Clone, Default)]
+pub struct ControlStructureSettings {
+    pub control_braces: ControlBraces,
+    pub elseif_style: ElseIfStyle,
+    /// Enforce exactly one space between the keyword and `(` and between
+    /// `)` and `{`.
+    pub normalize_spacing: bool,
+}
+
+/// All edits for one `if` statement (braces, `elseif` spelling, spacing).
+///
+/// Alternative (colon) syntax bodies are skipped entirely: `if (...):`
+/// has no braces to normalize and its own spacing conventions.
+pub fn edits_for_if(r#if: &If, source: &str, settings: &ControlStructureSettings) -> Vec<(Span, String)> {
+    let IfBody::Statement(body) = &r#if.body else {
+        return Vec::new();
+    };
+
+    let mut edits = Vec::new();
+
+    if settings.normalize_spacing {
+        push_spacing_edits(r#if.r#if, &r#if.condition, source, &mut edits);
+    }
+
+    if settings.control_braces == ControlBraces::Always {
+        push_brace_edits(&body.statement, &mut edits);
+
+        for clause in body.else_if_clauses.iter() {
+            push_brace_edits(&clause.statement, &mut edits);
+        }
+    }
+
+    if let Some(r#else) = &body.else_clause {
+        match (&settings.elseif_style, r#else.statement.as_ref()) {
+            // `else if (...) ...` — a dangling `if` as the sole else body.
+            // Fusing it into `elseif` is only sound when that `if` is
+            // unbraced; `else { if ... }` changes meaning if fused.
+            (ElseIfStyle::Elseif, Statement::If(nested)) => {
+                edits.push((r#else.r#else.join(nested.r#if), "elseif".to_owned()));
+
+                // The nested if's own body still needs its edits.
+                edits.extend(edits_for_if(nested, source, settings));
+            }
+            _ => {
+                if settings.control_braces == ControlBraces::Always
+                    && !matches!(r#else.statement.as_ref(), Statement::If(_))
+                {
+                    push_brace_edits(&r#else.statement, &mut edits);
+                }
+            }
+        }
+    }
+
+    edits
+}
+
+/// All edits for a loop (`for`, `foreach`, `while`) given its keyword span
+/// and unbraced body statement; colon-syntax bodies must not be passed.
+pub fn edits_for_loop(
+    keyword: Span,
+    condition_span: Span,
+    body: &Statement,
+    source: &str,
+    settings: &ControlStructureSettings,
+) -> Vec<(Span, String)> {
+    let mut edits = Vec::new();
+
+    if settings.normalize_spacing {
+        push_spacing_edits(keyword, &condition_span, source, &mut edits);
+    }
+
+    if settings.control_braces == ControlBraces::Always {
+        push_brace_edits(body, &mut edits);
+    }
+
+    edits
+}
+
+/// Replace `elseif` with `else if` when the reverse style is configured.
+pub fn edits_for_elseif_keyword(keyword: Span, settings: &ControlStructureSettings) -> Vec<(Span, String)> {
+    match settings.elseif_style {
+        ElseIfStyle::ElseIf => vec![(keyword, "else if".to_owned())],
+        _ => Vec::new(),
+    }
+}
+
+/// Wrap a single-statement body in braces.
+///
+/// The statement's span includes any `else` attached to a nested `if`, so
+/// the dangling-else case is wrapped as a unit and its binding cannot
+/// change. A trailing same-line comment sits *after* the statement's span
+/// and therefore ends up after the closing brace — consistently outside.
+fn push_brace_edits(statement: &Statement, edits: &mut Vec<(Span, String)>) {
+    if matches!(statement, Statement::Block(_)) {
+        return;
+    }
+
+    let span = statement.span();
+    let start = span.start;
+    let end = span.end;
+
+    // `{ ` before the statement, ` }` after it; surrounding whitespace in
+    // the source stays untouched so indentation survives.
+    edits.push((Span::new(start, start), "{ ".to_owned()));
+    edits.push((Span::new(end, end), " }".to_owned()));
+}
+
+/// Normalize the gaps `keyword<here>(` and `)<here>{` to one space each.
+fn push_spacing_edits(keyword: Span, condition: &impl HasSpan, source: &str, edits: &mut Vec<(Span, String)>) {
+    // The `(` is the first parenthesis between the keyword and the
+    // condition.
+    let between = &source[keyword.end.offset..condition.span().start.offset];
+    if let Some(paren) = between.find('(') {
+        let gap = &between[..paren];
+        if gap != " " && gap.chars().all(char::is_whitespace) {
+            let start = keyword.end;
+            let mut end = start;
+            end.offset += paren;
+            edits.push((Span::new(start, end), " ".to_owned()));
+        }
+    }
+
+    // The `{`, if present, is the first brace after the condition's
+    // closing parenthesis.
+    let after = &source[condition.span().end.offset..];
+    if let Some(close) = after.find(')') {
+        let rest = &after[close + 1..];
+        if let Some(brace) = rest.find('{') {
+            let gap = &rest[..brace];
+            if gap != " " && gap.chars().all(char::is_whitespace) {
+                let mut start = condition.span().end;
+                start.offset += close + 1;
+                let mut end = start;
+                end.offset += brace;
+                edits.push((Span::new(start, end), " ".to_owned()));
+            }
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Heredoc/nowdoc handling: bodies are opaque, only indentation moves.
+//!
+//! The formatter never reflows a heredoc or nowdoc body — not trimming,
+//! not rewrapping, and interpolated expressions keep their exact text.
+//! The one normalization offered (gated by
+//! [`FormatSettings::normalize_heredoc_indentation`](crate::FormatSettings))
+//! re-aligns the closing label with the enclosing statement's indentation
+//! using PHP 7.3 flexible syntax, shifting every body line's leading
+//! whitespace by the same delta so the *dedented* content PHP sees is
+//! byte-identical. When the lines do not share the prefix the shift needs
+//! — mixed tabs and spaces, or a line flusher-left than the closing label
+//! — the construct is left untouched rather than half-moved.
+
+/// Re-indent a heredoc/nowdoc so its closing label starts with
+/// `target_indentation`.
+///
+/// `text` is the construct's exact source text, `<<<` through the end of
+/// the closing label (the lexer's span captures the closing indentation
+/// as part of the literal). Returns the rewritten text, or `None` when
+/// the construct must be preserved:
+///
+/// - the closing line's indentation mixes tabs and spaces, or differs in
+///   character from `target_indentation` while non-empty;
+/// - some non-empty body line does not start with the closing label's
+///   current indentation (the common prefix the shift relies on);
+/// - the result would be identical to the input.
+///
+/// Whitespace-only body lines are normalized to empty lines, which PHP
+/// permits regardless of the closing indentation.
+pub fn reindent_document(text: &str, target_indentation: &str) -> Option<String> {
+    // Split into the opener line, body lines, and the closing line. The
+    // body may be empty (`<<<X` directly followed by the label line).
+    let mut lines: Vec<&str> = text.split('\n').collect();
+    if lines.len() < 2 {
+        return None;
+    }
+
+    let closing_line = lines.pop()?;
+    let opener = lines.remove(0);
+
+    let label_start = closing_line.len() - closing_line.trim_start_matches([' ', '\t']).len();
+    let current_indentation = &closing_line[..label_start];
+    let label = &closing_line[label_start..];
+
+    if !is_uniform_whitespace(current_indentation) || !is_uniform_whitespace(target_indentation) {
+        return None;
+    }
+
+    let mut rewritten = String::with_capacity(text.len());
+    rewritten.push_str(opener);
+    rewritten.push('\n');
+
+    for line in &lines {
+        if line.trim_matches([' ', '\t', '\r']).is_empty() {
+            // Empty (or whitespace-only) lines need no indentation.
+            rewritten.push('\n');
+            continue;
+        }
+
+        // The shift is prefix surgery only: strip the old closing
+        // indentation, reattach the new one, and the content — including
+        // any interpolated expression — stays byte-identical.
+        let remainder = line.strip_prefix(current_indentation)?;
+        rewritten.push_str(target_indentation);
+        rewritten.push_str(remainder);
+        rewritten.push('\n');
+    }
+
+    rewritten.push_str(target_indentation);
+    rewritten.push_str(label);
+
+    if rewritten == text {
+        return None;
+    }
+
+    Some(rewritten)
+}
+
+/// Whether `indentation` is all spaces or all tabs (or empty). PHP 7.3
+/// rejects closing labels indented with a mix, and a mixed prefix would
+/// make the common-prefix check meaningless.
+fn is_uniform_whitespace(indentation: &str) -> bool {
+    indentation.bytes().all(|byte| byte == b' ') || indentation.bytes().all(|byte| byte == b'\t')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indents_flush_left_heredoc_to_target() {
+        let text = "<<<SQL\nSELECT *\n  FROM t\nSQL";
+        assert_eq!(
+            reindent_document(text, "    "),
+            Some("<<<SQL\n    SELECT *\n      FROM t\n    SQL".to_owned()),
+        );
+    }
+
+    #[test]
+    fn test_dedents_when_target_is_shallower() {
+        let text = "<<<TXT\n        a\n        b\n    TXT";
+        assert_eq!(reindent_document(text, ""), Some("<<<TXT\n    a\n    b\nTXT".to_owned()));
+    }
+
+    #[test]
+    fn test_tab_indented_bodies_shift_by_tabs() {
+        let text = "<<<TXT\n\t\ta\n\tTXT";
+        assert_eq!(reindent_document(text, "\t\t"), Some("<<<TXT\n\t\t\ta\n\t\tTXT".to_owned()));
+    }
+
+    #[test]
+    fn test_empty_heredoc_normalizes_label_only() {
+        assert_eq!(reindent_document("<<<TXT\nTXT", "  "), Some("<<<TXT\n  TXT".to_owned()));
+    }
+
+    #[test]
+    fn test_interpolation_text_is_untouched() {
+        let text = "<<<MSG\nHello {$user->name}!\nMSG";
+        assert_eq!(
+            reindent_document(text, "    "),
+            Some("<<<MSG\n    Hello {$user->name}!\n    MSG".to_owned()),
+        );
+    }
+
+    #[test]
+    fn test_line_without_the_common_prefix_blocks_the_shift() {
+        // The second line sits left of the closing label's indentation;
+        // shifting the rest would change the dedented content.
+        let text = "<<<TXT\n    a\nb\n    TXT";
+        assert_eq!(reindent_document(text, ""), None);
+    }
+
+    #[test]
+    fn test_mixed_indentation_is_preserved() {
+        let text = "<<<TXT\n \ta\n \tTXT";
+        assert_eq!(reindent_document(text, ""), None);
+    }
+
+    #[test]
+    fn test_already_aligned_heredoc_is_reported_as_unchanged() {
+        let text = "<<<TXT\n    a\n    TXT";
+        assert_eq!(reindent_document(text, "    "), None);
+    }
+
+    #[test]
+    fn test_whitespace_only_body_lines_become_empty() {
+        let text = "<<<TXT\n    a\n   \n    b\n    TXT";
+        assert_eq!(
+            reindent_document(text, "  "),
+            Some("<<<TXT\n  a\n\n  b\n  TXT".to_owned()),
+        );
+    }
+
+    #[test]
+    fn test_nowdoc_argument_label_moves_off_column_zero() {
+        // Pre-7.3 style: heredoc passed as an argument, label forced to
+        // column 0. Normalizing to the statement indentation is exactly
+        // the flexible-syntax upgrade.
+        let text = "<<<'TXT'\nline\nTXT";
+        assert_eq!(reindent_document(text, "    "), Some("<<<'TXT'\n    line\n    TXT".to_owned()));
+    }
+}
@@ -0,0 +1,38 @@
+pub use crate::attribute::AttributeStyle;
+pub use crate::node_printer::print_modified;
+pub use crate::node_printer::print_node;
+pub use crate::node_printer::PrintError;
+
+pub mod array_syntax;
+pub mod attribute;
+pub mod call_arguments;
+pub mod control_structure;
+pub mod heredoc;
+pub mod node_printer;
+
+/// Settings shared by the formatting passes.
+#[derive(Debug, Clone)]
+pub struct FormatSettings {
+    /// The target line width; constructs exceeding it are wrapped.
+    pub line_width: usize,
+    /// How attributes on declarations are laid out.
+    pub attribute_style: AttributeStyle,
+    /// Whether a sole or final closure/anonymous-class/array/`match`
+    /// argument hugs the call parentheses instead of breaking the list.
+    pub hug_last_argument: bool,
+    /// Align heredoc/nowdoc closing labels with the statement indentation
+    /// (PHP 7.3 flexible syntax); see [`heredoc::reindent_document`].
+    /// Bodies are never reflowed either way.
+    pub normalize_heredoc_indentation: bool,
+}
+
+impl Default for FormatSettings {
+    fn default() -> Self {
+        Self {
+            line_width: 120,
+            attribute_style: AttributeStyle::OnePerLine,
+            hug_last_argument: true,
+            normalize_heredoc_indentation: true,
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! Lossless node-to-source printing.
+//!
+//! Rules building fixes need "the source text of this expression", possibly
+//! with a few nested parts rewritten. Slicing the file by span handles the
+//! unmodified case; [`print_modified`] handles the rest by splicing
+//! replacement text into the original slice while keeping every other byte
+//! identical, so synthesized replacements never fight the formatter.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use mago_span::HasSpan;
+use mago_span::Span;
+
+/// An invalid replacement set passed to [`print_modified`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrintError {
+    /// A replacement span is not contained in the printed node's span.
+    OutOfBounds { replacement: Span, node: Span },
+    /// Two replacement spans overlap.
+    Overlapping { first: Span, second: Span },
+}
+
+impl fmt::Display for PrintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PrintError::OutOfBounds { replacement, node } => write!(
+                f,
+                "replacement span {}..{} is outside the printed node span {}..{}",
+                replacement.start.offset, replacement.end.offset, node.start.offset, node.end.offset,
+            ),
+            PrintError::Overlapping { first, second } => write!(
+                f,
+                "replacement spans {}..{} and {}..{} overlap",
+                first.start.offset, first.end.offset, second.start.offset, second.end.offset,
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PrintError {}
+
+/// The source text of `node`, borrowed from `source`.
+///
+/// This is lossless by construction: the returned slice is exactly what the
+/// author wrote, comments and formatting included.
+pub fn print_node<'a>(node: &impl HasSpan, source: &'a str) -> Cow<'a, str> {
+    Cow::Borrowed(&source[node.span().to_range()])
+}
+
+/// The source text of `node` with `replacements` spliced in.
+///
+/// Each `(span, text)` pair replaces the bytes of `span` with `text`;
+/// everything outside the replacement spans is byte-identical to the
+/// original. Replacements may be given in any order but must fall inside
+/// the node's span and must not overlap — violations are reported rather
+/// than silently misapplied, since a bad splice would corrupt the fix.
+pub fn print_modified(
+    node: &impl HasSpan,
+    source: &str,
+    replacements: &[(Span, String)],
+) -> Result<String, PrintError> {
+    let node_span = node.span();
+
+    let mut ordered: Vec<&(Span, String)> = replacements.iter().collect();
+    ordered.sort_by_key(|(span, _)| (span.start.offset, span.end.offset));
+
+    for (span, _) in &ordered {
+        if !node_span.contains(span) {
+            return Err(PrintError::OutOfBounds { replacement: *span, node: node_span });
+        }
+    }
+
+    for pair in ordered.windows(2) {
+        let (first, _) = pair[0];
+        let (second, _) = pair[1];
+        if second.start.offset < first.end.offset {
+            return Err(PrintError::Overlapping { first: *first, second: *second });
+        }
+    }
+
+    let mut printed = String::with_capacity(node_span.length());
+    let mut cursor = node_span.start.offset;
+    for (span, text) in ordered {
+        printed.push_str(&source[cursor..span.start.offset]);
+        printed.push_str(text);
+        cursor = span.end.offset;
+    }
+
+    printed.push_str(&source[cursor..node_span.end.offset]);
+    Ok(printed)
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_span::FileId;
+    use mago_span::Position;
+
+    use super::*;
+
+    fn span(start: usize, end: usize) -> Span {
+        Span::new(Position::new(FileId(0), start, 1), Position::new(FileId(0), end, 1))
+    }
+
+    #[test]
+    fn test_print_node_borrows_the_original_slice() {
+        let source = "<?php foo(1 + 2);";
+        assert!(matches!(print_node(&span(6, 16), source), Cow::Borrowed("foo(1 + 2)")));
+    }
+
+    #[test]
+    fn test_replacement_nested_in_argument_list_and_interpolation() {
+        //             0123456789012345678901234567
+        let source = r#"<?php foo("a $bar b", $baz);"#;
+        let node = span(6, 27);
+
+        // Rewrite the interpolated `$bar` and the second argument `$baz`.
+        let printed = print_modified(
+            &node,
+            source,
+            &[(span(22, 26), "$qux".to_owned()), (span(14, 18), "$new".to_owned())],
+        )
+        .unwrap();
+
+        assert_eq!(printed, r#"foo("a $new b", $qux)"#);
+    }
+
+    #[test]
+    fn test_rejects_out_of_bounds_and_overlapping_replacements() {
+        let source = "<?php foo(1 + 2);";
+        let node = span(6, 16);
+
+        assert!(matches!(
+            print_modified(&node, source, &[(span(0, 5), String::new())]),
+            Err(PrintError::OutOfBounds { .. }),
+        ));
+
+        assert!(matches!(
+            print_modified(&node, source, &[(span(10, 13), String::new()), (span(12, 15), String::new())]),
+            Err(PrintError::Overlapping { .. }),
+        ));
+    }
+}
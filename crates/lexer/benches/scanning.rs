@@ -0,0 +1,60 @@
+use criterion::Criterion;
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+
+use mago_lexer::scan::identifier_run;
+use mago_lexer::scan::whitespace_run;
+
+/// A representative chunk of PHP-looking text: indentation-heavy code with
+/// typical identifier lengths, repeated to a few hundred kilobytes.
+fn representative_input() -> Vec<u8> {
+    let unit = b"        public function handleIncomingRequest(ServerRequestInterface $request): ResponseInterface\n        {\n            $normalizedHeaders = $this->headerNormalizer->normalize($request->getHeaders());\n        }\n\n";
+
+    let mut input = Vec::with_capacity(unit.len() * 2_000);
+    for _ in 0..2_000 {
+        input.extend_from_slice(unit);
+    }
+
+    input
+}
+
+fn naive_whitespace(bytes: &[u8], offset: usize) -> usize {
+    bytes[offset..].iter().take_while(|b| matches!(b, b' ' | b'\t' | b'\n' | b'\r')).count()
+}
+
+fn naive_identifier(bytes: &[u8], offset: usize) -> usize {
+    bytes[offset..].iter().take_while(|&&b| b.is_ascii_alphanumeric() || b == b'_' || b >= 0x80).count()
+}
+
+fn scan_all(bytes: &[u8], whitespace: impl Fn(&[u8], usize) -> usize, identifier: impl Fn(&[u8], usize) -> usize) -> usize {
+    let mut offset = 0;
+    let mut total = 0;
+    while offset < bytes.len() {
+        let run = whitespace(bytes, offset).max(identifier(bytes, offset));
+        if run == 0 {
+            offset += 1;
+        } else {
+            offset += run;
+            total += run;
+        }
+    }
+
+    total
+}
+
+fn bench_scanners(c: &mut Criterion) {
+    let input = representative_input();
+
+    let mut group = c.benchmark_group("scanning");
+    group.bench_function("bulk", |b| {
+        b.iter(|| scan_all(black_box(&input), whitespace_run, identifier_run));
+    });
+    group.bench_function("per-byte", |b| {
+        b.iter(|| scan_all(black_box(&input), naive_whitespace, naive_identifier));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_scanners);
+criterion_main!(benches);
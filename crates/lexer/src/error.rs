@@ -0,0 +1,52 @@
+use std::fmt;
+
+/// An error produced while tokenizing source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxError {
+    /// A byte that cannot start any token, at the given absolute offset.
+    UnexpectedCharacter { position: usize },
+    /// A string or heredoc that reaches end of input before its terminator.
+    UnterminatedString { position: usize },
+    /// An invalid UTF-8 sequence in strict validation mode; see
+    /// [`crate::utf8::Utf8Policy`].
+    InvalidUtf8 { position: usize },
+    /// The configured input-size or token-count cap was exceeded; see
+    /// [`crate::limits::Limits`].
+    InputTooLarge { limit: usize, kind: crate::limits::LimitKind },
+    /// A structural limit (interpolation nesting, heredoc label length,
+    /// expression depth, statement count) tripped at `position`; see
+    /// [`crate::limits::Limits`] and [`crate::limits::ParserLimits`].
+    LimitExceeded { position: usize, limit: usize, kind: crate::limits::LimitKind },
+    /// Tabs and spaces mixed in heredoc/nowdoc indentation — within the
+    /// closing marker's own indentation, or between a body line's stripped
+    /// prefix and the marker. A fatal error in PHP 7.3+; see
+    /// [`crate::heredoc::check_heredoc_indentation`].
+    InconsistentIndentation { position: usize },
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SyntaxError::UnexpectedCharacter { position } => {
+                write!(f, "unexpected character at offset {position}")
+            }
+            SyntaxError::UnterminatedString { position } => {
+                write!(f, "unterminated string starting at offset {position}")
+            }
+            SyntaxError::InvalidUtf8 { position } => {
+                write!(f, "invalid UTF-8 sequence at offset {position}")
+            }
+            SyntaxError::InputTooLarge { limit, kind } => {
+                write!(f, "input exceeds the configured limit of {limit} {kind}")
+            }
+            SyntaxError::LimitExceeded { position, limit, kind } => {
+                write!(f, "limit of {limit} {kind} exceeded at offset {position}")
+            }
+            SyntaxError::InconsistentIndentation { position } => {
+                write!(f, "invalid indentation - tabs and spaces cannot be mixed, at offset {position}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SyntaxError {}
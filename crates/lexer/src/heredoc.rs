@@ -0,0 +1,124 @@
+use crate::error::SyntaxError;
+
+/// Validate heredoc/nowdoc indentation against PHP 7.3's flexible-heredoc
+/// rules, which forbid mixing tabs and spaces.
+///
+/// `closing_indentation` is the whitespace before the closing marker
+/// (starting at absolute offset `closing_indentation_offset`); `body` is
+/// everything between the opener's newline (absolute offset `body_offset`)
+/// and the closing line. Two things are fatal in PHP:
+///
+/// - the closing marker's own indentation mixes tabs and spaces;
+/// - a body line's stripped prefix — its first
+///   `closing_indentation.len()` bytes — disagrees byte-for-byte with the
+///   marker's indentation, e.g. a tab-indented marker with space-indented
+///   body lines or vice versa.
+///
+/// Whitespace *beyond* the stripped prefix is body content and may mix
+/// freely, and lines that are entirely whitespace are exempt, exactly as
+/// in PHP. The returned [`SyntaxError::InconsistentIndentation`] carries
+/// the offset of the first offending byte.
+///
+/// Insufficient indentation (a body line shallower than the marker) is a
+/// different PHP error and is not this function's concern; the caller
+/// checks it when computing the strip width.
+pub fn check_heredoc_indentation(
+    body: &str,
+    body_offset: usize,
+    closing_indentation: &str,
+    closing_indentation_offset: usize,
+) -> Result<(), SyntaxError> {
+    let indentation = closing_indentation.as_bytes();
+
+    // The marker's own indentation must be all-tabs or all-spaces.
+    if let Some(first) = indentation.first() {
+        if let Some(mixed) = indentation.iter().position(|byte| byte != first) {
+            return Err(SyntaxError::InconsistentIndentation { position: closing_indentation_offset + mixed });
+        }
+    }
+
+    if indentation.is_empty() {
+        return Ok(());
+    }
+
+    let mut line_offset = 0usize;
+    for line in body.split_inclusive('\n') {
+        let bytes = line.as_bytes();
+        let content = line.trim_end_matches(['\n', '\r']);
+
+        if !content.bytes().all(|byte| byte == b' ' || byte == b'\t') {
+            for (index, &byte) in bytes.iter().take(indentation.len()).enumerate() {
+                if !matches!(byte, b' ' | b'\t') {
+                    // Shallow indentation; not a mixing problem.
+                    break;
+                }
+
+                if byte != indentation[index] {
+                    return Err(SyntaxError::InconsistentIndentation { position: body_offset + line_offset + index });
+                }
+            }
+        }
+
+        line_offset += line.len();
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run the check the way the heredoc scanner does: `document` is the
+    /// full text after the `<<<LABEL` opener's newline, with the closing
+    /// line last.
+    fn check(document: &str) -> Result<(), SyntaxError> {
+        let closing_start = document.rfind('\n').map_or(0, |index| index + 1);
+        let (body, closing_line) = document.split_at(closing_start);
+        let indentation_length = closing_line.len() - closing_line.trim_start_matches([' ', '\t']).len();
+
+        check_heredoc_indentation(body, 0, &closing_line[..indentation_length], closing_start)
+    }
+
+    #[test]
+    fn test_consistent_indentation_passes() {
+        assert_eq!(check("    line one\n    line two\n    LABEL"), Ok(()));
+        assert_eq!(check("\t\tline one\n\t\tline two\n\tLABEL"), Ok(()));
+        assert_eq!(check("line\nLABEL"), Ok(()));
+    }
+
+    #[test]
+    fn test_tab_marker_with_space_indented_body_is_rejected() {
+        let result = check("  line\n\tLABEL");
+
+        assert_eq!(result, Err(SyntaxError::InconsistentIndentation { position: 0 }));
+    }
+
+    #[test]
+    fn test_space_marker_with_tab_indented_body_is_rejected() {
+        let result = check("  fine\n\t\tbad\n  LABEL");
+
+        assert_eq!(result, Err(SyntaxError::InconsistentIndentation { position: 7 }));
+    }
+
+    #[test]
+    fn test_mixed_closing_marker_indentation_is_rejected() {
+        // The error points into the marker's indentation itself: the body
+        // starts at 0, the closing line at 5, and the tab after the space
+        // is the second byte of that line.
+        let result = check("line\n \tLABEL");
+
+        assert_eq!(result, Err(SyntaxError::InconsistentIndentation { position: 6 }));
+    }
+
+    #[test]
+    fn test_whitespace_beyond_the_stripped_prefix_may_mix() {
+        // Two spaces are stripped; the tab after them is body content.
+        assert_eq!(check("  \tdata\n  LABEL"), Ok(()));
+    }
+
+    #[test]
+    fn test_blank_and_whitespace_only_lines_are_exempt() {
+        assert_eq!(check("  line\n\n\t \n  LABEL"), Ok(()));
+    }
+}
@@ -0,0 +1,76 @@
+use mago_php_version::PHPVersion;
+use mago_token::TokenKind;
+
+/// Recognize the PHP 8.4 asymmetric-visibility `(set)` suffix after a
+/// visibility keyword, e.g. `public(set)` in `public(set) int $x`.
+///
+/// The scripting-mode scanner calls this right after lexing `public`,
+/// `protected`, or `private`. If the following bytes spell `(set)` — with no
+/// intervening whitespace, and with `set` matched case-insensitively like
+/// every other keyword — the visibility token is widened into the combined
+/// `PublicSet` / `ProtectedSet` / `PrivateSet` kind and the scanner consumes
+/// the five suffix bytes.
+///
+/// Returns the combined kind and the suffix length, or `None` when the
+/// suffix is absent, the keyword is not a visibility keyword, or the target
+/// `version` predates asymmetric visibility. On older targets the bytes lex
+/// as before (`public`, `(`, `set`, `)`), so existing code is unaffected.
+pub fn asymmetric_visibility_suffix(
+    keyword: TokenKind,
+    rest: &[u8],
+    version: PHPVersion,
+) -> Option<(TokenKind, usize)> {
+    if version < PHPVersion::PHP84 {
+        return None;
+    }
+
+    let combined = match keyword {
+        TokenKind::Public => TokenKind::PublicSet,
+        TokenKind::Protected => TokenKind::ProtectedSet,
+        TokenKind::Private => TokenKind::PrivateSet,
+        _ => return None,
+    };
+
+    let suffix = rest.get(..5)?;
+    if suffix[0] == b'('
+        && suffix[1].eq_ignore_ascii_case(&b's')
+        && suffix[2].eq_ignore_ascii_case(&b'e')
+        && suffix[3].eq_ignore_ascii_case(&b't')
+        && suffix[4] == b')'
+    {
+        Some((combined, 5))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recognizes_set_suffix_on_php_84() {
+        assert_eq!(
+            asymmetric_visibility_suffix(TokenKind::Public, b"(set) int $x", PHPVersion::PHP84),
+            Some((TokenKind::PublicSet, 5)),
+        );
+
+        assert_eq!(
+            asymmetric_visibility_suffix(TokenKind::Protected, b"(SET)", PHPVersion::PHP84),
+            Some((TokenKind::ProtectedSet, 5)),
+        );
+    }
+
+    #[test]
+    fn test_inert_on_older_targets_and_non_visibility_keywords() {
+        assert_eq!(asymmetric_visibility_suffix(TokenKind::Public, b"(set)", PHPVersion::PHP83), None);
+        assert_eq!(asymmetric_visibility_suffix(TokenKind::Static, b"(set)", PHPVersion::PHP84), None);
+    }
+
+    #[test]
+    fn test_requires_exact_suffix() {
+        assert_eq!(asymmetric_visibility_suffix(TokenKind::Public, b" (set)", PHPVersion::PHP84), None);
+        assert_eq!(asymmetric_visibility_suffix(TokenKind::Public, b"(get)", PHPVersion::PHP84), None);
+        assert_eq!(asymmetric_visibility_suffix(TokenKind::Public, b"(se", PHPVersion::PHP84), None);
+    }
+}
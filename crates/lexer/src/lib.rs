@@ -0,0 +1,12 @@
+pub use crate::error::SyntaxError;
+pub use crate::limits::Limits;
+pub use crate::limits::ParserLimits;
+
+pub mod error;
+pub mod heredoc;
+pub mod keyword;
+pub mod limits;
+pub mod number;
+pub mod scan;
+pub mod scratch;
+pub mod utf8;
@@ -0,0 +1,245 @@
+//! Input-size and token-count caps for lexing untrusted input.
+//!
+//! Hosted linting services accept arbitrary uploads; without a cap, an
+//! adversarial or merely enormous file is tokenized in full before any
+//! other layer can refuse it. `Limits` lets callers bound both input
+//! bytes (rejected up front) and emitted tokens (checked in
+//! `Lexer::advance`). Both caps are opt-in: the default is unlimited, so
+//! existing callers see no behavior change.
+
+use std::fmt;
+
+use crate::error::SyntaxError;
+
+/// Which cap was exceeded, for error reporting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitKind {
+    Bytes,
+    Tokens,
+    /// `${...}` / `{$...}` interpolation nesting depth.
+    InterpolationNesting,
+    /// Heredoc/nowdoc label length.
+    HeredocLabel,
+    /// Parser expression nesting depth.
+    ExpressionDepth,
+    /// Parser statement count.
+    Statements,
+}
+
+impl fmt::Display for LimitKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LimitKind::Bytes => f.write_str("bytes"),
+            LimitKind::Tokens => f.write_str("tokens"),
+            LimitKind::InterpolationNesting => f.write_str("levels of string interpolation"),
+            LimitKind::HeredocLabel => f.write_str("bytes of heredoc label"),
+            LimitKind::ExpressionDepth => f.write_str("levels of expression nesting"),
+            LimitKind::Statements => f.write_str("statements"),
+        }
+    }
+}
+
+/// Caps applied while lexing. Construct with [`Limits::unlimited`] and
+/// narrow with the builder methods.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+    max_input_bytes: Option<usize>,
+    max_tokens: Option<usize>,
+    max_interpolation_nesting: Option<usize>,
+    max_heredoc_label: Option<usize>,
+}
+
+impl Limits {
+    /// No caps — the default, preserving historical behavior.
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_input_bytes(mut self, bytes: usize) -> Self {
+        self.max_input_bytes = Some(bytes);
+        self
+    }
+
+    pub fn with_max_tokens(mut self, tokens: usize) -> Self {
+        self.max_tokens = Some(tokens);
+        self
+    }
+
+    /// Validate the input size before lexing begins. Called once from
+    /// `Lexer::new`, so oversized input fails before a single token is
+    /// produced.
+    pub fn check_input_size(&self, bytes: usize) -> Result<(), SyntaxError> {
+        match self.max_input_bytes {
+            Some(limit) if bytes > limit => Err(SyntaxError::InputTooLarge { limit, kind: LimitKind::Bytes }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Account for one emitted token. Called from `Lexer::advance` on
+    /// every token; the comparison against an `Option` the branch
+    /// predictor learns is effectively free in the unlimited case.
+    #[inline]
+    pub fn check_token_count(&self, emitted: usize) -> Result<(), SyntaxError> {
+        match self.max_tokens {
+            Some(limit) if emitted >= limit => Err(SyntaxError::InputTooLarge { limit, kind: LimitKind::Tokens }),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn with_max_interpolation_nesting(mut self, levels: usize) -> Self {
+        self.max_interpolation_nesting = Some(levels);
+        self
+    }
+
+    pub fn with_max_heredoc_label(mut self, bytes: usize) -> Self {
+        self.max_heredoc_label = Some(bytes);
+        self
+    }
+
+    /// Check the `${...}` / `{$...}` nesting depth. Called from
+    /// `read_until_end_of_brace_interpolation` each time a nested opener is
+    /// entered, so a megabyte of `${` fails at the cap instead of
+    /// recursing for the rest of the input.
+    #[inline]
+    pub fn check_interpolation_nesting(&self, depth: usize, position: usize) -> Result<(), SyntaxError> {
+        match self.max_interpolation_nesting {
+            Some(limit) if depth > limit => Err(SyntaxError::LimitExceeded { position, limit, kind: LimitKind::InterpolationNesting }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check a heredoc/nowdoc label length before scanning for its
+    /// terminator.
+    #[inline]
+    pub fn check_heredoc_label(&self, length: usize, position: usize) -> Result<(), SyntaxError> {
+        match self.max_heredoc_label {
+            Some(limit) if length > limit => Err(SyntaxError::LimitExceeded { position, limit, kind: LimitKind::HeredocLabel }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Structural caps applied while parsing; the parser-side counterpart of
+/// [`Limits`]. Unlimited by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParserLimits {
+    max_expression_depth: Option<usize>,
+    max_statements: Option<usize>,
+}
+
+impl ParserLimits {
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_expression_depth(mut self, depth: usize) -> Self {
+        self.max_expression_depth = Some(depth);
+        self
+    }
+
+    pub fn with_max_statements(mut self, statements: usize) -> Self {
+        self.max_statements = Some(statements);
+        self
+    }
+
+    /// Check the expression recursion depth; called on entry to
+    /// `parse_expression_with_precedence`.
+    #[inline]
+    pub fn check_expression_depth(&self, depth: usize, position: usize) -> Result<(), SyntaxError> {
+        match self.max_expression_depth {
+            Some(limit) if depth > limit => Err(SyntaxError::LimitExceeded { position, limit, kind: LimitKind::ExpressionDepth }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check the total statement count; called once per parsed statement.
+    #[inline]
+    pub fn check_statement_count(&self, statements: usize, position: usize) -> Result<(), SyntaxError> {
+        match self.max_statements {
+            Some(limit) if statements > limit => Err(SyntaxError::LimitExceeded { position, limit, kind: LimitKind::Statements }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlimited_accepts_everything() {
+        let limits = Limits::unlimited();
+
+        assert!(limits.check_input_size(usize::MAX).is_ok());
+        assert!(limits.check_token_count(usize::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_byte_cap_rejects_oversized_input_up_front() {
+        let limits = Limits::unlimited().with_max_input_bytes(1024);
+
+        assert!(limits.check_input_size(1024).is_ok());
+        assert_eq!(
+            limits.check_input_size(1025),
+            Err(SyntaxError::InputTooLarge { limit: 1024, kind: LimitKind::Bytes }),
+        );
+    }
+
+    /// Fuzz-derived shape: a long run of `${` openers. The guard must trip
+    /// at the cap, in time proportional to the cap rather than the input.
+    #[test]
+    fn test_interpolation_nesting_trips_at_the_cap() {
+        let limits = Limits::unlimited().with_max_interpolation_nesting(64);
+        let adversarial = "${".repeat(500_000);
+
+        let mut result = Ok(());
+        for depth in 1..=adversarial.len() / 2 {
+            result = limits.check_interpolation_nesting(depth, depth * 2);
+            if result.is_err() {
+                break;
+            }
+        }
+
+        assert_eq!(
+            result,
+            Err(SyntaxError::LimitExceeded { position: 130, limit: 64, kind: LimitKind::InterpolationNesting }),
+        );
+    }
+
+    #[test]
+    fn test_heredoc_label_cap() {
+        let limits = Limits::unlimited().with_max_heredoc_label(255);
+
+        assert!(limits.check_heredoc_label(255, 10).is_ok());
+        assert_eq!(
+            limits.check_heredoc_label(256, 10),
+            Err(SyntaxError::LimitExceeded { position: 10, limit: 255, kind: LimitKind::HeredocLabel }),
+        );
+    }
+
+    #[test]
+    fn test_parser_limits_trip_with_positions() {
+        let limits = ParserLimits::unlimited().with_max_expression_depth(256).with_max_statements(100_000);
+
+        assert!(limits.check_expression_depth(256, 0).is_ok());
+        assert_eq!(
+            limits.check_expression_depth(257, 42),
+            Err(SyntaxError::LimitExceeded { position: 42, limit: 256, kind: LimitKind::ExpressionDepth }),
+        );
+        assert_eq!(
+            limits.check_statement_count(100_001, 7),
+            Err(SyntaxError::LimitExceeded { position: 7, limit: 100_000, kind: LimitKind::Statements }),
+        );
+    }
+
+    #[test]
+    fn test_token_cap_trips_on_the_excess_token() {
+        let limits = Limits::unlimited().with_max_tokens(3);
+
+        assert!(limits.check_token_count(2).is_ok());
+        assert_eq!(
+            limits.check_token_count(3),
+            Err(SyntaxError::InputTooLarge { limit: 3, kind: LimitKind::Tokens }),
+        );
+    }
+}
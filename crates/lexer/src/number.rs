@@ -0,0 +1,176 @@
+//! Numeric literal runs with `_` separator handling.
+//!
+//! PHP allows `_` in numeric literals only *between* digits, and applies
+//! that rule independently to the integer part, the fractional part, and
+//! the exponent: `1_000.5_0e1_0` is one float, while `1_.5`, `1._5`,
+//! `1e_5`, and a trailing `_` are all rejected by the engine. The
+//! scanners here stop a run at the first byte that would make the literal
+//! illegal, so such inputs split into multiple tokens (`1_` never lexes
+//! as a number; `1` does, and the stray `_` falls to the identifier path,
+//! which fails loudly) instead of being silently accepted with the wrong
+//! length.
+
+/// The length of a digit run in `base` starting at `offset`, where `_`
+/// separators are consumed only when flanked by digits on both sides.
+///
+/// `1_000` in base 10 yields 5; `1_` yields 1 (the trailing separator is
+/// not part of the literal); `_1` yields 0 (a leading separator never
+/// starts a literal). Bases 2, 8, 10, and 16 cover PHP's literal forms.
+#[inline]
+pub fn digits_run(bytes: &[u8], offset: usize, base: u32) -> usize {
+    let mut index = offset;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        if is_digit_of_base(byte, base) {
+            index += 1;
+        } else if byte == b'_'
+            && index > offset
+            && bytes.get(index + 1).copied().is_some_and(|next| is_digit_of_base(next, base))
+        {
+            // A separator counts only between two digits; the previous
+            // byte is a digit by construction (a separator cannot follow
+            // a separator, since the lookahead here demands a digit).
+            index += 2;
+        } else {
+            break;
+        }
+    }
+
+    index - offset
+}
+
+/// What a decimal number run turned out to be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberKind {
+    Integer,
+    Float,
+}
+
+/// A scanned decimal literal: its byte length and integer/float kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberScan {
+    pub length: usize,
+    pub kind: NumberKind,
+}
+
+/// Scan a decimal literal starting at `offset`, covering the full
+/// `{digits}[.{digits}][eE[+-]{digits}]` shape with per-part separator
+/// rules.
+///
+/// The first byte must be an ASCII digit or a `.` followed by a digit
+/// (`.5`); otherwise the length is zero. A bare trailing dot (`1.`) is a
+/// valid float, matching the engine's `DNUM` rule. An exponent is only
+/// consumed when a digit follows the `e`/`E` (and optional sign) — `1e_5`
+/// and `1e+` leave the `e` outside the literal, so `1` lexes as an
+/// integer and the rest splits off.
+pub fn decimal_number_run(bytes: &[u8], offset: usize) -> NumberScan {
+    let mut index = offset;
+    let mut kind = NumberKind::Integer;
+
+    index += digits_run(bytes, index, 10);
+
+    // Fractional part: a dot is part of the literal when digits precede
+    // it (`1.`, `1.5`) or directly follow it (`.5`).
+    if bytes.get(index) == Some(&b'.') {
+        let fraction = digits_run(bytes, index + 1, 10);
+        if index > offset || fraction > 0 {
+            index += 1 + fraction;
+            kind = NumberKind::Float;
+        }
+    }
+
+    if index == offset {
+        return NumberScan { length: 0, kind };
+    }
+
+    // Exponent part: committed only when it is complete, so an illegal
+    // exponent leaves the mantissa's kind and length untouched.
+    if matches!(bytes.get(index), Some(b'e' | b'E')) {
+        let mut exponent = index + 1;
+        if matches!(bytes.get(exponent), Some(b'+' | b'-')) {
+            exponent += 1;
+        }
+
+        let digits = digits_run(bytes, exponent, 10);
+        if digits > 0 {
+            index = exponent + digits;
+            kind = NumberKind::Float;
+        }
+    }
+
+    NumberScan { length: index - offset, kind }
+}
+
+#[inline]
+fn is_digit_of_base(byte: u8, base: u32) -> bool {
+    match base {
+        2 => matches!(byte, b'0' | b'1'),
+        8 => matches!(byte, b'0'..=b'7'),
+        16 => byte.is_ascii_hexdigit(),
+        _ => byte.is_ascii_digit(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan(source: &str) -> NumberScan {
+        decimal_number_run(source.as_bytes(), 0)
+    }
+
+    #[test]
+    fn test_separators_in_every_part_lex_as_one_float() {
+        assert_eq!(scan("1_000.000_1"), NumberScan { length: 11, kind: NumberKind::Float });
+        assert_eq!(scan("1e1_0"), NumberScan { length: 5, kind: NumberKind::Float });
+        assert_eq!(scan("1_000.5_0e1_0"), NumberScan { length: 13, kind: NumberKind::Float });
+        assert_eq!(scan("1_000"), NumberScan { length: 5, kind: NumberKind::Integer });
+    }
+
+    #[test]
+    fn test_plain_floats_still_scan() {
+        assert_eq!(scan("1.5e-3;"), NumberScan { length: 6, kind: NumberKind::Float });
+        assert_eq!(scan(".5"), NumberScan { length: 2, kind: NumberKind::Float });
+        assert_eq!(scan("1."), NumberScan { length: 2, kind: NumberKind::Float });
+    }
+
+    #[test]
+    fn test_separator_before_the_dot_splits() {
+        // `1_.5`: the separator has no digit after it, so the literal is
+        // just `1` and the rest is not a number.
+        assert_eq!(scan("1_.5"), NumberScan { length: 1, kind: NumberKind::Integer });
+    }
+
+    #[test]
+    fn test_separator_after_the_dot_splits() {
+        // `1._5`: the fraction cannot start with `_`; `1.` is a complete
+        // float and `_5` falls outside the literal.
+        assert_eq!(scan("1._5"), NumberScan { length: 2, kind: NumberKind::Float });
+    }
+
+    #[test]
+    fn test_separator_leading_the_exponent_splits() {
+        // `1e_5`: no digit after `e`, so the exponent is not consumed at
+        // all and `1` stays an integer.
+        assert_eq!(scan("1e_5"), NumberScan { length: 1, kind: NumberKind::Integer });
+
+        // Same with a sign: `1e+_5`.
+        assert_eq!(scan("1e+_5"), NumberScan { length: 1, kind: NumberKind::Integer });
+    }
+
+    #[test]
+    fn test_trailing_separator_is_excluded() {
+        assert_eq!(scan("1_000_"), NumberScan { length: 5, kind: NumberKind::Integer });
+        assert_eq!(scan("1e5_"), NumberScan { length: 3, kind: NumberKind::Float });
+        assert_eq!(scan("1.5_"), NumberScan { length: 3, kind: NumberKind::Float });
+    }
+
+    #[test]
+    fn test_digits_run_respects_bases() {
+        assert_eq!(digits_run(b"1010_1010z", 0, 2), 9);
+        assert_eq!(digits_run(b"7_7_78", 0, 8), 6);
+        assert_eq!(digits_run(b"778", 0, 8), 2);
+        assert_eq!(digits_run(b"dead_beefg", 0, 16), 9);
+        assert_eq!(digits_run(b"_1", 0, 10), 0);
+    }
+}
@@ -0,0 +1,104 @@
+//! UTF-8 validation of token values.
+//!
+//! Interning token bytes through `String::from_utf8_lossy` silently
+//! replaces invalid sequences with U+FFFD, which corrupts the interned
+//! value relative to the original bytes: round-tripping tokens back to
+//! source becomes impossible and real encoding problems in legacy
+//! codebases go unnoticed. The lexer instead validates here and either
+//! fails (strict mode) or records a warning while interning losslessly.
+
+use mago_interner::StringIdentifier;
+use mago_interner::ThreadedInterner;
+
+/// How the lexer treats invalid UTF-8 in string and identifier tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Utf8Policy {
+    /// Fail lexing with `SyntaxError::InvalidUtf8` at the offending byte.
+    Strict,
+    /// Record a warning and preserve the original bytes unchanged, so
+    /// spans and round-tripping stay accurate.
+    #[default]
+    Warn,
+}
+
+/// An invalid UTF-8 sequence found in a token value.
+///
+/// `position` is the absolute byte offset of the first invalid byte, ready
+/// to be surfaced as `SyntaxError::InvalidUtf8 { position }` in strict
+/// mode or as a diagnostic note otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidUtf8 {
+    pub position: usize,
+}
+
+/// Validate `bytes` (a token value starting at absolute offset
+/// `token_offset`) and intern it.
+///
+/// On valid input, the value is interned as-is. On invalid input the
+/// outcome depends on `policy`: strict mode returns the error without
+/// interning; warn mode interns the *original bytes* — escaped via
+/// lossless latin-1-style byte mapping so no information is dropped — and
+/// returns the error alongside the identifier for reporting.
+pub fn intern_validated(
+    interner: &ThreadedInterner,
+    bytes: &[u8],
+    token_offset: usize,
+    policy: Utf8Policy,
+) -> (Option<StringIdentifier>, Option<InvalidUtf8>) {
+    match std::str::from_utf8(bytes) {
+        Ok(value) => (Some(interner.intern(value)), None),
+        Err(error) => {
+            let invalid = InvalidUtf8 { position: token_offset + error.valid_up_to() };
+            match policy {
+                Utf8Policy::Strict => (None, Some(invalid)),
+                Utf8Policy::Warn => {
+                    // Map each raw byte to the code point of the same value:
+                    // lossless and reversible, unlike U+FFFD substitution.
+                    let preserved: String = bytes.iter().map(|&byte| char::from(byte)).collect();
+
+                    (Some(interner.intern(&preserved)), Some(invalid))
+                }
+            }
+        }
+    }
+}
+
+/// Recover the original bytes of a value interned by [`intern_validated`]
+/// under the warn policy.
+pub fn recover_original_bytes(value: &str) -> Vec<u8> {
+    value.chars().map(|character| character as u32 as u8).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_utf8_interns_directly() {
+        let interner = ThreadedInterner::new();
+        let (id, error) = intern_validated(&interner, "café".as_bytes(), 10, Utf8Policy::Strict);
+        assert!(error.is_none());
+        assert_eq!(interner.lookup(&id.unwrap()), "café");
+    }
+
+    #[test]
+    fn test_strict_mode_reports_exact_position() {
+        let interner = ThreadedInterner::new();
+        // `b"ab\xFFcd"`: the invalid byte is at index 2 of the token, which
+        // starts at absolute offset 100.
+        let (id, error) = intern_validated(&interner, b"ab\xFFcd", 100, Utf8Policy::Strict);
+        assert!(id.is_none());
+        assert_eq!(error, Some(InvalidUtf8 { position: 102 }));
+    }
+
+    #[test]
+    fn test_warn_mode_round_trips_original_bytes() {
+        let interner = ThreadedInterner::new();
+        let original: &[u8] = b"caf\xE9"; // latin-1 encoded "café"
+        let (id, error) = intern_validated(&interner, original, 0, Utf8Policy::Warn);
+        assert!(error.is_some());
+
+        let preserved = interner.lookup(&id.unwrap()).to_owned();
+        assert_eq!(recover_original_bytes(&preserved), original);
+    }
+}
@@ -0,0 +1,70 @@
+use mago_interner::ThreadedInterner;
+use mago_lexer::input::Input;
+use mago_lexer::Lexer;
+use mago_span::FileId;
+use mago_token::TokenKind;
+use mago_token::OPERATORS;
+
+/// The non-trivia token kinds `source` lexes to after the open tag.
+fn kinds_of(source: &str) -> Vec<TokenKind> {
+    let interner = ThreadedInterner::new();
+    let source = format!("<?php {source}");
+    let mut lexer = Lexer::new(&interner, Input::new(FileId(0), source.as_bytes()));
+
+    std::iter::from_fn(|| lexer.advance())
+        .map(|token| token.expect("test sources must lex without errors"))
+        .filter(|token| !token.kind.is_trivia() && !token.kind.is_open_tag())
+        .map(|token| token.kind)
+        .collect()
+}
+
+/// Maximal munch for the `?`-prefixed family: the lexer's three-byte
+/// lookahead must prefer the longest operator, and a space anywhere in
+/// the sequence must split it. These sequences are the ones a wrong
+/// ordering in the `read(3)` / `read(2)` match would mis-lex.
+#[test]
+fn test_question_prefixed_operators_munch_maximally() {
+    assert_eq!(kinds_of("$a ??= 1;").get(1), Some(&TokenKind::QuestionQuestionEqual));
+    assert_eq!(kinds_of("$a ?-> b;").get(1), Some(&TokenKind::QuestionMinusGreaterThan));
+    assert_eq!(kinds_of("$a ?: $b;").get(1), Some(&TokenKind::QuestionColon));
+    assert_eq!(kinds_of("$a ?? $b;").get(1), Some(&TokenKind::QuestionQuestion));
+}
+
+/// `?? =` with a space is coalesce followed by assignment, never `??=`.
+#[test]
+fn test_space_splits_coalesce_from_assignment() {
+    let kinds = kinds_of("$a ?? = 1;");
+    assert_eq!(kinds.get(1), Some(&TokenKind::QuestionQuestion));
+    assert_eq!(kinds.get(2), Some(&TokenKind::Equal));
+}
+
+/// `?>` closes PHP; it must win over lexing a bare `?` even though `?` is
+/// a prefix of both `??=` and `?:`.
+#[test]
+fn test_close_tag_wins_over_bare_question() {
+    let kinds = kinds_of("$a ?>");
+    assert_eq!(kinds.get(1), Some(&TokenKind::CloseTag));
+}
+
+/// Consolidated matrix: for every operator in the canonical table that is
+/// a strict prefix of a longer one (`?` of `??`, `??` of `??=`, `<` of
+/// `<<=`, …), the longer lexeme must lex as one token — one mis-ordered
+/// match arm and the prefix would win instead.
+#[test]
+fn test_every_prefixed_operator_prefers_the_longest_lexeme() {
+    for (kind, lexeme, _, _) in OPERATORS {
+        let has_strict_prefix_in_table =
+            OPERATORS.iter().any(|(_, other, _, _)| other.len() < lexeme.len() && lexeme.starts_with(other));
+        if !has_strict_prefix_in_table {
+            continue;
+        }
+
+        let kinds = kinds_of(&format!("$a {lexeme} $b;"));
+        assert_eq!(
+            kinds.get(1),
+            Some(kind),
+            "`{lexeme}` must lex as a single {kind:?}, not split into its prefix"
+        );
+        assert_eq!(kinds.len(), 4, "`$a {lexeme} $b;` must produce exactly four tokens");
+    }
+}
@@ -0,0 +1,25 @@
+use mago_interner::ThreadedInterner;
+use mago_lexer::input::Input;
+use mago_lexer::Lexer;
+use mago_span::FileId;
+use mago_token::OPERATORS;
+
+/// Every lexeme in the canonical operator table must lex back to the
+/// table's own `TokenKind`; otherwise documentation generated from the
+/// table would disagree with the lexer.
+#[test]
+fn test_operator_table_round_trips_through_the_lexer() {
+    let interner = ThreadedInterner::new();
+
+    for (kind, lexeme, _, _) in OPERATORS {
+        let source = format!("<?php {lexeme}");
+        let mut lexer = Lexer::new(&interner, Input::new(FileId(0), source.as_bytes()));
+
+        let token = std::iter::from_fn(|| lexer.advance())
+            .map(|token| token.expect("operator lexemes must lex without errors"))
+            .find(|token| !token.kind.is_trivia() && !token.kind.is_open_tag())
+            .unwrap_or_else(|| panic!("no token produced for `{lexeme}`"));
+
+        assert_eq!(token.kind, *kind, "`{lexeme}` lexed to {:?}", token.kind);
+    }
+}
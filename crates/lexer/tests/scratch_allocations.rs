@@ -0,0 +1,63 @@
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use mago_interner::ThreadedInterner;
+use mago_lexer::scratch::LexerScratch;
+use mago_span::FileId;
+use mago_span::Position;
+use mago_span::Span;
+use mago_token::Token;
+use mago_token::TokenKind;
+
+/// Counts allocations so the test can assert the steady state performs
+/// none.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn fill(scratch: &mut LexerScratch, interner: &ThreadedInterner, tokens: usize) {
+    let value = interner.intern("$x");
+    for index in 0..tokens {
+        let position = Position::new(FileId(0), index, 1);
+        scratch.tokens.push(Token { kind: TokenKind::Variable, value, span: Span::new(position, position) });
+        scratch.string_buffer.push_str("scratch");
+    }
+}
+
+#[test]
+fn test_steady_state_reuse_performs_no_allocations() {
+    let interner = ThreadedInterner::new();
+    let mut scratch = LexerScratch::new();
+
+    // Warm up: the first file grows the buffers.
+    fill(&mut scratch, &interner, 512);
+    scratch.clear();
+
+    // Steady state: the same workload must fit in retained capacity.
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..16 {
+        fill(&mut scratch, &interner, 512);
+        scratch.clear();
+    }
+    let after = ALLOCATIONS.load(Ordering::Relaxed);
+
+    assert_eq!(after - before, 0, "steady-state lexing over a reused scratch must not allocate");
+    assert!(scratch.retained_capacity() > 0);
+}
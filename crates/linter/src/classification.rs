@@ -0,0 +1,66 @@
+use mago_ast::*;
+use mago_span::HasSpan;
+
+/// How a source file is treated by rules and the formatter.
+///
+/// Template files (`.phtml` and friends) interleave HTML with short PHP
+/// segments; rules written for scripts — side-effect checks, the
+/// strict-types rule, blank-line formatting — misfire there, while
+/// template-specific rules (escaped `<?=` output) only make sense there.
+/// The classification is computed once per file and exposed on the lint
+/// context so rules can branch on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SourceClassification {
+    #[default]
+    Script,
+    Template,
+}
+
+/// File extensions always classified as templates.
+const TEMPLATE_EXTENSIONS: &[&str] = &["phtml", "tpl", "view.php", "blade.php"];
+
+/// Classify a file from its path and parsed contents.
+///
+/// `forced` is the per-path override from configuration and wins
+/// outright. Otherwise template extensions classify by name, and anything
+/// else falls back to a byte-ratio heuristic: when at least half the file
+/// is inline (non-PHP) output, it is a template regardless of extension —
+/// catching `.php` view files — while a `.phtml` that is pure PHP still
+/// counts as a template by extension, matching author intent.
+pub fn classify_source(
+    path: &str,
+    program: &Program,
+    source_length: usize,
+    forced: Option<SourceClassification>,
+) -> SourceClassification {
+    if let Some(classification) = forced {
+        return classification;
+    }
+
+    let lowered = path.to_ascii_lowercase();
+    if TEMPLATE_EXTENSIONS.iter().any(|extension| lowered.ends_with(&format!(".{extension}"))) {
+        return SourceClassification::Template;
+    }
+
+    if source_length > 0 && inline_bytes(program) * 2 >= source_length {
+        return SourceClassification::Template;
+    }
+
+    SourceClassification::Script
+}
+
+/// Total bytes covered by inline (HTML) segments.
+fn inline_bytes(program: &Program) -> usize {
+    let mut bytes = 0usize;
+    let mut stack = vec![Node::Program(program)];
+    while let Some(node) = stack.pop() {
+        if let Node::Statement(Statement::Inline(inline)) = node {
+            bytes += inline.span().length();
+            continue;
+        }
+
+        stack.extend(node.children());
+    }
+
+    bytes
+}
@@ -0,0 +1,331 @@
+use mago_fixer::SafetyClassification;
+use mago_reporting::Level;
+
+use crate::rule::Rule;
+
+/// What a documentation example claims about the rule.
+///
+/// The claim is checked, not decorative: `validate_examples` parses every
+/// example, and the registry test harness runs each `Triggers` /
+/// `DoesNotTrigger` example through the rule and fails when the claim and
+/// the rule disagree. Docs that drift from behavior break the build.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExampleAssertion {
+    /// The rule reports at least one issue on this code.
+    Triggers,
+    /// The rule reports nothing on this code.
+    DoesNotTrigger,
+    /// Shown for context only; no lint assertion, parse-checked only.
+    Illustrative,
+}
+
+/// One code example in a rule's documentation.
+#[derive(Debug, Clone)]
+pub struct CodeExample {
+    pub title: &'static str,
+    /// Complete PHP source, including the `<?php` tag.
+    pub code: &'static str,
+    pub assertion: ExampleAssertion,
+}
+
+/// Structured documentation for one rule, rendered by
+/// `mago lint --explain <rule>` and `mago docs generate`.
+///
+/// Built fluently, like [`OptionsSchema`](crate::options::OptionsSchema),
+/// so rule declarations read as a table. The name, default level, and
+/// option schema are not repeated here — the renderers take them from the
+/// [`Rule`] itself.
+#[derive(Debug, Clone)]
+pub struct RuleDocumentation {
+    /// One sentence: what the rule reports.
+    pub summary: &'static str,
+    /// Why the pattern is worth reporting; a short paragraph.
+    pub rationale: &'static str,
+    pub examples: Vec<CodeExample>,
+    /// The strongest safety the rule's autofix carries, or `None` when
+    /// the rule has no autofix.
+    pub fix: Option<SafetyClassification>,
+    /// Version requirement rendered as written (e.g. `"7.4"`); `None`
+    /// when the rule applies to every supported version.
+    pub minimum_php_version: Option<&'static str>,
+}
+
+impl RuleDocumentation {
+    pub fn new(summary: &'static str, rationale: &'static str) -> Self {
+        Self { summary, rationale, examples: Vec::new(), fix: None, minimum_php_version: None }
+    }
+
+    /// Placeholder for rules that predate structured documentation.
+    ///
+    /// New rules must not use this; the documentation generator marks the
+    /// rule's page as a stub and `--explain` says so outright.
+    pub fn undocumented() -> Self {
+        Self::new("", "")
+    }
+
+    pub fn is_stub(&self) -> bool {
+        self.summary.is_empty()
+    }
+
+    /// An example the rule reports on.
+    pub fn bad_example(mut self, title: &'static str, code: &'static str) -> Self {
+        self.examples.push(CodeExample { title, code, assertion: ExampleAssertion::Triggers });
+        self
+    }
+
+    /// An example the rule accepts.
+    pub fn good_example(mut self, title: &'static str, code: &'static str) -> Self {
+        self.examples.push(CodeExample { title, code, assertion: ExampleAssertion::DoesNotTrigger });
+        self
+    }
+
+    /// Context-only code with no lint assertion.
+    pub fn illustrative_example(mut self, title: &'static str, code: &'static str) -> Self {
+        self.examples.push(CodeExample { title, code, assertion: ExampleAssertion::Illustrative });
+        self
+    }
+
+    pub fn fixable(mut self, safety: SafetyClassification) -> Self {
+        self.fix = Some(safety);
+        self
+    }
+
+    pub fn requires_php(mut self, version: &'static str) -> Self {
+        self.minimum_php_version = Some(version);
+        self
+    }
+}
+
+/// Parse every example of `rule` and return one message per failure.
+///
+/// This is the cheap half of keeping docs honest — examples must at least
+/// be real PHP. The expensive half (asserting `Triggers` examples
+/// actually trigger) needs a full lint context and lives in the registry
+/// test harness next to the rule configuration plumbing.
+pub fn validate_examples(rule: &dyn Rule) -> Vec<String> {
+    let interner = mago_interner::ThreadedInterner::new();
+    let mut problems = Vec::new();
+
+    for example in &rule.get_documentation().examples {
+        let (_, error) = mago_parser::parse_source_text(&interner, example.code);
+        if let Some(error) = error {
+            problems.push(format!(
+                "example `{}` of rule `{}` does not parse: {error}",
+                example.title,
+                rule.get_name(),
+            ));
+        }
+    }
+
+    problems
+}
+
+/// Render a rule's full documentation for `mago lint --explain <rule>`.
+///
+/// Plain text with the layout the terminal reporter expects; the CLI
+/// applies syntax highlighting to the indented code blocks.
+pub fn render_explain(rule: &dyn Rule) -> String {
+    let documentation = rule.get_documentation();
+    let mut out = String::new();
+
+    out.push_str(&format!("{} ({})\n", rule.get_name(), level_name(rule.get_default_level())));
+
+    if documentation.is_stub() {
+        out.push_str("\nThis rule has no documentation yet.\n");
+        return out;
+    }
+
+    out.push_str(&format!("\n{}\n\n{}\n", documentation.summary, documentation.rationale));
+
+    if let Some(version) = documentation.minimum_php_version {
+        out.push_str(&format!("\nRequires PHP {version} or later.\n"));
+    }
+
+    out.push_str(&format!("\n{}", fix_line(documentation.fix)));
+
+    for example in &documentation.examples {
+        out.push_str(&format!("\n{} ({}):\n", example.title, assertion_name(example.assertion)));
+        for line in example.code.lines() {
+            out.push_str(&format!("    {line}\n"));
+        }
+    }
+
+    out.push('\n');
+    out.push_str(&rule.get_options().render());
+    out
+}
+
+/// Render a rule's documentation page for `mago docs generate`, which
+/// writes the result to `<target>/<rule-name>.md`.
+pub fn render_markdown(rule: &dyn Rule) -> String {
+    let documentation = rule.get_documentation();
+    let mut out = String::new();
+
+    out.push_str(&format!("# `{}`\n\n", rule.get_name()));
+    out.push_str(&format!("Default level: {}.\n\n", level_name(rule.get_default_level())));
+
+    if documentation.is_stub() {
+        out.push_str("This rule has no documentation yet.\n");
+        return out;
+    }
+
+    out.push_str(&format!("{}\n\n{}\n\n", documentation.summary, documentation.rationale));
+
+    if let Some(version) = documentation.minimum_php_version {
+        out.push_str(&format!("Requires PHP {version} or later.\n\n"));
+    }
+
+    out.push_str(&fix_line(documentation.fix));
+
+    for example in &documentation.examples {
+        out.push_str(&format!("\n## {} ({})\n\n```php\n{}\n```\n", example.title, assertion_name(example.assertion), example.code.trim_end()));
+    }
+
+    let options = rule.get_options();
+    if !options.options().is_empty() {
+        out.push_str("\n## Options\n\n");
+        for option in options.options() {
+            out.push_str(&format!(
+                "- `{}` ({}, default: `{}`) — {}\n",
+                option.key, option.r#type, option.default, option.description,
+            ));
+        }
+    }
+
+    out
+}
+
+fn level_name(level: Option<Level>) -> &'static str {
+    match level {
+        Some(Level::Error) => "error",
+        Some(Level::Warning) => "warning",
+        Some(Level::Note) => "note",
+        Some(Level::Help) => "help",
+        None => "disabled by default",
+    }
+}
+
+fn fix_line(fix: Option<SafetyClassification>) -> String {
+    match fix {
+        None => "This rule has no autofix.\n".to_owned(),
+        Some(SafetyClassification::Safe) => "This rule has a safe autofix.\n".to_owned(),
+        Some(SafetyClassification::PotentiallyUnsafe) => {
+            "This rule has an autofix that is applied only with `--potentially-unsafe`.\n".to_owned()
+        }
+        Some(SafetyClassification::Unsafe) => {
+            "This rule has an autofix that is applied only with `--unsafe`.\n".to_owned()
+        }
+    }
+}
+
+fn assertion_name(assertion: ExampleAssertion) -> &'static str {
+    match assertion {
+        ExampleAssertion::Triggers => "reported",
+        ExampleAssertion::DoesNotTrigger => "accepted",
+        ExampleAssertion::Illustrative => "illustration",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::OptionsSchema;
+
+    struct DocumentedRule;
+
+    impl Rule for DocumentedRule {
+        fn get_name(&self) -> &'static str {
+            "documented-rule"
+        }
+
+        fn get_default_level(&self) -> Option<Level> {
+            Some(Level::Warning)
+        }
+
+        fn get_options(&self) -> OptionsSchema {
+            OptionsSchema::new().bool("strict", "false", "Whether to be strict.")
+        }
+
+        fn get_documentation(&self) -> RuleDocumentation {
+            RuleDocumentation::new(
+                "Reports the thing.",
+                "The thing is bad because reasons.",
+            )
+            .bad_example("The thing", "<?php the_thing();")
+            .good_example("Not the thing", "<?php not_the_thing();")
+            .fixable(SafetyClassification::Safe)
+            .requires_php("7.4")
+        }
+    }
+
+    struct UndocumentedRule;
+
+    impl Rule for UndocumentedRule {
+        fn get_name(&self) -> &'static str {
+            "undocumented-rule"
+        }
+
+        fn get_default_level(&self) -> Option<Level> {
+            None
+        }
+    }
+
+    struct BrokenExampleRule;
+
+    impl Rule for BrokenExampleRule {
+        fn get_name(&self) -> &'static str {
+            "broken-example-rule"
+        }
+
+        fn get_default_level(&self) -> Option<Level> {
+            Some(Level::Error)
+        }
+
+        fn get_documentation(&self) -> RuleDocumentation {
+            RuleDocumentation::new("Reports.", "Because.").bad_example("Broken", "<?php if (")
+        }
+    }
+
+    #[test]
+    fn test_examples_that_parse_validate_cleanly() {
+        assert!(validate_examples(&DocumentedRule).is_empty());
+    }
+
+    #[test]
+    fn test_broken_example_is_reported_with_rule_and_title() {
+        let problems = validate_examples(&BrokenExampleRule);
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("`Broken`"));
+        assert!(problems[0].contains("`broken-example-rule`"));
+    }
+
+    #[test]
+    fn test_explain_renders_every_section() {
+        let rendered = render_explain(&DocumentedRule);
+
+        assert!(rendered.starts_with("documented-rule (warning)\n"));
+        assert!(rendered.contains("Reports the thing."));
+        assert!(rendered.contains("Requires PHP 7.4 or later."));
+        assert!(rendered.contains("safe autofix"));
+        assert!(rendered.contains("The thing (reported):\n    <?php the_thing();"));
+        assert!(rendered.contains("strict (boolean, default: false)"));
+    }
+
+    #[test]
+    fn test_explain_marks_stubs() {
+        let rendered = render_explain(&UndocumentedRule);
+
+        assert!(rendered.starts_with("undocumented-rule (disabled by default)\n"));
+        assert!(rendered.contains("no documentation yet"));
+    }
+
+    #[test]
+    fn test_markdown_page_layout() {
+        let rendered = render_markdown(&DocumentedRule);
+
+        assert!(rendered.starts_with("# `documented-rule`\n\nDefault level: warning.\n"));
+        assert!(rendered.contains("## The thing (reported)\n\n```php\n<?php the_thing();\n```"));
+        assert!(rendered.contains("- `strict` (boolean, default: `false`) — Whether to be strict."));
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt;
+
+/// The type of a rule option value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionType {
+    Bool,
+    Integer,
+    String,
+    StringList,
+}
+
+impl fmt::Display for OptionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptionType::Bool => f.write_str("boolean"),
+            OptionType::Integer => f.write_str("integer"),
+            OptionType::String => f.write_str("string"),
+            OptionType::StringList => f.write_str("list of strings"),
+        }
+    }
+}
+
+/// One declared option: key, type, default (rendered as TOML), and a
+/// one-line description.
+///
+/// The same metadata drives three consumers: configuration validation,
+/// `mago lint --explain <rule>`, and the generated documentation — so an
+/// option cannot exist without being documented.
+#[derive(Debug, Clone)]
+pub struct OptionSchema {
+    pub key: &'static str,
+    pub r#type: OptionType,
+    pub default: &'static str,
+    pub description: &'static str,
+}
+
+/// The full option schema of one rule. Built with the fluent methods so
+/// rule declarations read as a table.
+#[derive(Debug, Clone, Default)]
+pub struct OptionsSchema {
+    options: Vec<OptionSchema>,
+}
+
+impl OptionsSchema {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn bool(mut self, key: &'static str, default: &'static str, description: &'static str) -> Self {
+        self.options.push(OptionSchema { key, r#type: OptionType::Bool, default, description });
+        self
+    }
+
+    pub fn integer(mut self, key: &'static str, default: &'static str, description: &'static str) -> Self {
+        self.options.push(OptionSchema { key, r#type: OptionType::Integer, default, description });
+        self
+    }
+
+    pub fn string(mut self, key: &'static str, default: &'static str, description: &'static str) -> Self {
+        self.options.push(OptionSchema { key, r#type: OptionType::String, default, description });
+        self
+    }
+
+    pub fn string_list(mut self, key: &'static str, default: &'static str, description: &'static str) -> Self {
+        self.options.push(OptionSchema { key, r#type: OptionType::StringList, default, description });
+        self
+    }
+
+    pub fn options(&self) -> &[OptionSchema] {
+        &self.options
+    }
+
+    /// Validate a rule's configured options against the schema.
+    ///
+    /// Unknown keys get a "did you mean" suggestion when a declared key is
+    /// within edit distance two; type mismatches name both the expected
+    /// and the found type. `location` is the config-file path prefix
+    /// (e.g. `linter.rules.require-types`) used in the messages.
+    pub fn validate(&self, configured: &toml::value::Table, location: &str) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        for (key, value) in configured {
+            let Some(schema) = self.options.iter().find(|option| option.key == key) else {
+                let mut message = format!("unknown option `{location}.{key}`");
+                if let Some(suggestion) = self.closest_key(key) {
+                    message.push_str(&format!("; did you mean `{suggestion}`?"));
+                }
+                problems.push(message);
+                continue;
+            };
+
+            let matches = match schema.r#type {
+                OptionType::Bool => value.is_bool(),
+                OptionType::Integer => value.is_integer(),
+                OptionType::String => value.is_str(),
+                OptionType::StringList => {
+                    value.as_array().is_some_and(|items| items.iter().all(toml::Value::is_str))
+                }
+            };
+
+            if !matches {
+                problems.push(format!(
+                    "invalid value for `{location}.{key}`: expected {}, found {}",
+                    schema.r#type,
+                    value.type_str(),
+                ));
+            }
+        }
+
+        problems
+    }
+
+    /// Render the schema for `--explain` output and documentation.
+    pub fn render(&self) -> String {
+        if self.options.is_empty() {
+            return "This rule has no options.\n".to_owned();
+        }
+
+        let mut out = String::from("Options:\n");
+        for option in &self.options {
+            out.push_str(&format!(
+                "  {} ({}, default: {})\n      {}\n",
+                option.key, option.r#type, option.default, option.description,
+            ));
+        }
+
+        out
+    }
+
+    fn closest_key(&self, unknown: &str) -> Option<&'static str> {
+        self.options
+            .iter()
+            .map(|option| (levenshtein(unknown, option.key), option.key))
+            .filter(|(distance, _)| *distance <= 2)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, key)| key)
+    }
+}
+
+/// Edit distance between two keys; small inputs, so the quadratic table
+/// is fine.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (row, &char_a) in a.iter().enumerate() {
+        let mut current = vec![row + 1];
+        for (column, &char_b) in b.iter().enumerate() {
+            let substitution = previous[column] + usize::from(char_a != char_b);
+            current.push(substitution.min(previous[column + 1] + 1).min(current[column] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema() -> OptionsSchema {
+        OptionsSchema::new()
+            .integer("max_complexity", "15", "Maximum allowed cyclomatic complexity.")
+            .bool("count_elseifs", "true", "Whether `elseif` branches add to the count.")
+    }
+
+    fn table(source: &str) -> toml::value::Table {
+        toml::from_str(source).expect("test table must parse")
+    }
+
+    #[test]
+    fn test_unknown_key_gets_a_suggestion() {
+        let problems = schema().validate(&table("max_complexty = 10"), "linter.rules.complexity");
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("unknown option `linter.rules.complexity.max_complexty`"));
+        assert!(problems[0].contains("did you mean `max_complexity`?"));
+    }
+
+    #[test]
+    fn test_unrelated_key_gets_no_suggestion() {
+        let problems = schema().validate(&table("threshold = 10"), "rule");
+
+        assert_eq!(problems.len(), 1);
+        assert!(!problems[0].contains("did you mean"));
+    }
+
+    #[test]
+    fn test_type_mismatch_names_both_types() {
+        let problems = schema().validate(&table("max_complexity = \"high\""), "rule");
+
+        assert_eq!(problems, vec!["invalid value for `rule.max_complexity`: expected integer, found string".to_owned()]);
+    }
+
+    #[test]
+    fn test_valid_configuration_passes() {
+        let problems = schema().validate(&table("max_complexity = 10\ncount_elseifs = false"), "rule");
+
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn test_render_lists_defaults() {
+        let rendered = schema().render();
+
+        assert!(rendered.contains("max_complexity (integer, default: 15)"));
+        assert!(rendered.contains("Maximum allowed cyclomatic complexity."));
+    }
+}
@@ -0,0 +1,218 @@
+//! A lightweight syntax checker for PCRE patterns as PHP sees them.
+//!
+//! This is deliberately not a regex engine: it validates exactly the
+//! properties that turn into `preg_*` warnings or silent failures at
+//! runtime — delimiter structure, the modifier set, and bracket balance —
+//! and nothing more. Anything it cannot prove wrong passes.
+
+use std::fmt;
+
+/// The modifiers PHP accepts after the closing delimiter.
+const KNOWN_MODIFIERS: &str = "imsxuADSUXJn";
+
+/// Why a pattern is not a valid PHP regex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternError {
+    /// The pattern is empty or starts with a character that cannot open a
+    /// delimiter (alphanumeric, backslash, or whitespace).
+    MissingDelimiter,
+    /// The closing delimiter never appears.
+    UnmatchedDelimiter { delimiter: char },
+    /// A letter after the closing delimiter that PHP does not know.
+    UnknownModifier { modifier: char },
+    /// The `e` (eval) modifier, removed in PHP 7.
+    EvalModifier,
+    /// An unbalanced `(`, `[`, or `{` in the pattern body.
+    UnbalancedGroup { character: char },
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::MissingDelimiter => f.write_str("the pattern has no delimiter"),
+            PatternError::UnmatchedDelimiter { delimiter } => {
+                write!(f, "the closing delimiter `{delimiter}` is missing")
+            }
+            PatternError::UnknownModifier { modifier } => write!(f, "unknown modifier `{modifier}`"),
+            PatternError::EvalModifier => f.write_str("the `e` modifier was removed in PHP 7"),
+            PatternError::UnbalancedGroup { character } => write!(f, "unbalanced `{character}` in the pattern"),
+        }
+    }
+}
+
+/// Validate a decoded pattern string (quotes already stripped, PHP
+/// escapes already interpreted).
+pub fn check_pattern(pattern: &str) -> Result<(), PatternError> {
+    let mut characters = pattern.char_indices();
+    let Some((_, opening)) = characters.next() else {
+        return Err(PatternError::MissingDelimiter);
+    };
+
+    if opening.is_alphanumeric() || opening == '\\' || opening.is_whitespace() {
+        return Err(PatternError::MissingDelimiter);
+    }
+
+    let closing = match opening {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '<' => '>',
+        other => other,
+    };
+    let bracket_style = closing != opening;
+
+    // PHP finds the closing delimiter by scanning for the first unescaped
+    // occurrence (bracket styles additionally track nesting); character
+    // classes are *not* transparent, which is why an unescaped `/` inside
+    // `[...]` breaks a slash-delimited pattern.
+    let mut body = String::new();
+    let mut depth = 0usize;
+    let mut escaped = false;
+    let mut modifier_offset = None;
+    for (offset, character) in characters {
+        if escaped {
+            escaped = false;
+            body.push(character);
+            continue;
+        }
+
+        match character {
+            '\\' => escaped = true,
+            c if bracket_style && c == opening => {
+                depth += 1;
+                body.push(c);
+            }
+            c if c == closing => {
+                if depth == 0 {
+                    modifier_offset = Some(offset + c.len_utf8());
+                    break;
+                }
+                depth -= 1;
+                body.push(c);
+            }
+            c => body.push(c),
+        }
+    }
+
+    let Some(modifier_offset) = modifier_offset else {
+        return Err(PatternError::UnmatchedDelimiter { delimiter: closing });
+    };
+
+    for modifier in pattern[modifier_offset..].chars() {
+        if modifier.is_whitespace() || modifier == '\r' || modifier == '\n' {
+            continue;
+        }
+
+        if modifier == 'e' {
+            return Err(PatternError::EvalModifier);
+        }
+
+        if !KNOWN_MODIFIERS.contains(modifier) {
+            return Err(PatternError::UnknownModifier { modifier });
+        }
+    }
+
+    check_balance(&body)
+}
+
+/// Check `(` / `[` / `{` balance in a pattern body, honoring escapes and
+/// the fact that metacharacters lose their meaning inside a character
+/// class.
+fn check_balance(body: &str) -> Result<(), PatternError> {
+    let mut stack: Vec<char> = Vec::new();
+    let mut in_class = false;
+    let mut escaped = false;
+
+    for character in body.chars() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match character {
+            '\\' => escaped = true,
+            ']' if in_class => in_class = false,
+            _ if in_class => {}
+            '[' => in_class = true,
+            '(' | '{' => stack.push(character),
+            ')' => {
+                if stack.pop() != Some('(') {
+                    return Err(PatternError::UnbalancedGroup { character: ')' });
+                }
+            }
+            '}' => {
+                // `{` is only a quantifier opener when it was pushed; a
+                // bare `}` is a literal in PCRE and fine.
+                if stack.last() == Some(&'{') {
+                    stack.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if in_class {
+        return Err(PatternError::UnbalancedGroup { character: '[' });
+    }
+
+    match stack.last() {
+        Some(&character) => Err(PatternError::UnbalancedGroup { character }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_patterns_pass() {
+        assert_eq!(check_pattern("/^a(b|c)+$/i"), Ok(()));
+        assert_eq!(check_pattern("#https?://#"), Ok(()));
+        assert_eq!(check_pattern("{a{1,3}}u"), Ok(()));
+        assert_eq!(check_pattern("/[a-z\\/]+/x"), Ok(()));
+    }
+
+    #[test]
+    fn test_bracket_delimiters_nest() {
+        assert_eq!(check_pattern("(a(b)c)"), Ok(()));
+        assert_eq!(check_pattern("[a[b]c]"), Ok(()));
+    }
+
+    #[test]
+    fn test_missing_or_unmatched_delimiters() {
+        assert_eq!(check_pattern(""), Err(PatternError::MissingDelimiter));
+        assert_eq!(check_pattern("abc"), Err(PatternError::MissingDelimiter));
+        assert_eq!(check_pattern("/abc"), Err(PatternError::UnmatchedDelimiter { delimiter: '/' }));
+        assert_eq!(check_pattern("{abc"), Err(PatternError::UnmatchedDelimiter { delimiter: '}' }));
+    }
+
+    #[test]
+    fn test_modifiers_are_validated() {
+        assert_eq!(check_pattern("/a/imsxu"), Ok(()));
+        assert_eq!(check_pattern("/a/q"), Err(PatternError::UnknownModifier { modifier: 'q' }));
+        assert_eq!(check_pattern("/a/e"), Err(PatternError::EvalModifier));
+    }
+
+    #[test]
+    fn test_unescaped_delimiter_in_class_terminates_early() {
+        // `/[a/]/` ends at the `/` inside the class, leaving `]/` as
+        // bogus modifiers — exactly what PHP would complain about.
+        assert!(check_pattern("/[a/]/").is_err());
+        assert_eq!(check_pattern("/[a\\/]/"), Ok(()));
+    }
+
+    #[test]
+    fn test_balance_is_checked_outside_character_classes() {
+        assert_eq!(check_pattern("/a(b/"), Err(PatternError::UnbalancedGroup { character: '(' }));
+        assert_eq!(check_pattern("/a)b/"), Err(PatternError::UnbalancedGroup { character: ')' }));
+        assert_eq!(check_pattern("/[(]/"), Ok(()));
+        assert_eq!(check_pattern("/[a-z/"), Err(PatternError::UnbalancedGroup { character: '[' }));
+    }
+
+    #[test]
+    fn test_escapes_hide_metacharacters() {
+        assert_eq!(check_pattern("/\\(a/"), Ok(()));
+        assert_eq!(check_pattern("/a\\[b/"), Ok(()));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod no_excessive_nesting;
+pub mod no_superglobal_access;
+pub mod nullsafe_operator;
+pub mod prefer_null_coalescing;
+pub mod no_side_effects_in_declaration_files;
@@ -0,0 +1,318 @@
+use mago_ast::*;
+use mago_ast_utils::control_flow::block_ends_with_conditional_wrapping;
+use mago_ast_utils::negation::negate_expression;
+use mago_fixer::SafetyClassification;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_span::Position;
+use mago_span::Span;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// Limits how deeply control structures nest inside a function-like body.
+///
+/// `if`/`foreach` pyramids push the interesting code off the right edge
+/// of the screen and force the reader to hold every enclosing condition
+/// in their head. The rule counts nested `if` / `for` / `foreach` /
+/// `while` / `do-while` / `switch` levels per function-like body —
+/// closures and nested functions restart the count for their own body —
+/// and reports the first statement crossing `max_nesting_depth`, with a
+/// secondary label on each enclosing level so the pyramid is visible in
+/// the diagnostic itself. Colon-delimited bodies count exactly like
+/// braced ones; the traversal is structural.
+///
+/// When the offending structure is a trailing `if` that wraps the
+/// remainder of its enclosing block, the message suggests the guard
+/// clause, and — if the `if` has no `else` and a braced body — offers a
+/// `PotentiallyUnsafe` fix performing the inversion: the condition is
+/// negated, the body becomes a `continue;` (targeting the nearest
+/// enclosing loop) or `return;` guard, and the wrapped statements move up
+/// one level. The fix is only offered when the `if` wraps the remainder
+/// transitively up to the jump target — every level in between is itself
+/// the trailing statement of its block — and no `switch` sits on that
+/// path, where PHP would redirect `continue` to the `switch` itself. The
+/// fix leaves the moved statements' indentation to the formatter.
+#[derive(Clone, Debug)]
+pub struct NoExcessiveNestingRule;
+
+impl Rule for NoExcessiveNestingRule {
+    fn get_name(&self) -> &'static str {
+        "no-excessive-nesting"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new().integer(
+            "max_nesting_depth",
+            "4",
+            "Maximum number of nested control structures allowed inside one function-like body.",
+        )
+    }
+}
+
+impl NoExcessiveNestingRule {
+    fn analyze(&self, body: &Block, context: &mut LintContext<'_>) {
+        let max = context.option_integer("max_nesting_depth").unwrap_or(4).max(1) as usize;
+
+        // `(node, depth, enclosing control statements, nearest enclosing block)`.
+        let mut stack: Vec<(Node<'_>, usize, Vec<(Span, &Statement)>, &Block)> =
+            vec![(Node::Block(body), 0, Vec::new(), body)];
+        while let Some((node, depth, chain, block)) = stack.pop() {
+            match node {
+                // Nested function-likes get their own walk callback and
+                // their own budget.
+                Node::Closure(_) | Node::ArrowFunction(_) | Node::Function(_) | Node::AnonymousClass(_) => {}
+                Node::Statement(statement) => {
+                    let Some(keyword) = control_keyword(statement) else {
+                        for child in node.children().into_iter().rev() {
+                            stack.push((child, depth, chain.clone(), block));
+                        }
+                        continue;
+                    };
+
+                    let depth = depth + 1;
+                    if depth > max {
+                        // One report per pyramid: the children are even
+                        // deeper and would only repeat the message.
+                        self.report(statement, &chain, block, body, max, context);
+                        continue;
+                    }
+
+                    let mut chain = chain.clone();
+                    chain.push((keyword, statement));
+                    for child in node.children().into_iter().rev() {
+                        stack.push((child, depth, chain.clone(), block));
+                    }
+                }
+                Node::Block(inner) => {
+                    for child in node.children().into_iter().rev() {
+                        stack.push((child, depth, chain.clone(), inner));
+                    }
+                }
+                _ => {
+                    for child in node.children().into_iter().rev() {
+                        stack.push((child, depth, chain.clone(), block));
+                    }
+                }
+            }
+        }
+    }
+
+    fn report(
+        &self,
+        statement: &Statement,
+        chain: &[(Span, &Statement)],
+        block: &Block,
+        root: &Block,
+        max: usize,
+        context: &mut LintContext<'_>,
+    ) {
+        let keyword = control_keyword(statement).expect("only control statements are reported");
+
+        let mut issue = Issue::new(
+            context.level(),
+            format!("Control structures nest deeper than {max} levels."),
+        )
+        .with_annotation(Annotation::primary(keyword).with_message(format!("level {} starts here", chain.len() + 1)));
+
+        for (index, (level, _)) in chain.iter().enumerate() {
+            issue = issue.with_annotation(
+                Annotation::secondary(*level).with_message(format!("enclosing level {}", index + 1)),
+            );
+        }
+
+        // A trailing `if` wrapping the rest of its block is the shape a
+        // guard clause flattens by one level.
+        let invertible = match block_ends_with_conditional_wrapping(block) {
+            Some(wrapping) if matches!(statement, Statement::If(r#if) if std::ptr::eq(r#if, wrapping)) => {
+                Some(wrapping)
+            }
+            _ => None,
+        };
+
+        let Some(r#if) = invertible else {
+            context.report(issue.with_help(
+                "Extract the inner levels into a function, or invert a wrapping condition into a guard clause.",
+            ));
+            return;
+        };
+
+        let issue = issue.with_help(
+            "Invert the condition into a guard clause (`if (<negated>) { continue/return; }`) and unindent the body.",
+        );
+
+        // The jump must skip nothing that would otherwise run: the `if`
+        // has to wrap the remainder transitively all the way up to the
+        // jump target, and no `switch` may sit on the path (PHP points
+        // `continue` at the `switch`, where it acts as `break`). The
+        // downward walk verifies both; anything it cannot verify gets the
+        // report without the fix.
+        let Some(jump) = guard_jump(chain, r#if, root) else {
+            context.report(issue);
+            return;
+        };
+
+        match guard_clause_edits(context, r#if) {
+            Some((condition_span, negated, after_brace, closing)) => {
+                context.report_with_fix(issue, |plan| {
+                    plan.replace(condition_span, negated, SafetyClassification::PotentiallyUnsafe)
+                        .insert(after_brace, format!(" {jump} }}"), SafetyClassification::PotentiallyUnsafe)
+                        .delete(closing, SafetyClassification::PotentiallyUnsafe)
+                });
+            }
+            None => context.report(issue),
+        }
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoExcessiveNestingRule {
+    fn walk_in_function(&self, function: &Function, context: &mut LintContext<'a>) {
+        self.analyze(&function.body, context);
+    }
+
+    fn walk_in_closure(&self, closure: &Closure, context: &mut LintContext<'a>) {
+        self.analyze(&closure.body, context);
+    }
+
+    fn walk_in_method(&self, method: &Method, context: &mut LintContext<'a>) {
+        if let MethodBody::Concrete(body) = &method.body {
+            self.analyze(body, context);
+        }
+    }
+}
+
+/// The keyword span of a statement that opens a nesting level.
+fn control_keyword(statement: &Statement) -> Option<Span> {
+    match statement {
+        Statement::If(r#if) => Some(r#if.r#if),
+        Statement::For(r#for) => Some(r#for.r#for),
+        Statement::Foreach(foreach) => Some(foreach.foreach),
+        Statement::While(r#while) => Some(r#while.r#while),
+        Statement::DoWhile(do_while) => Some(do_while.r#do),
+        Statement::Switch(switch) => Some(switch.switch),
+        _ => None,
+    }
+}
+
+/// The jump statement for the guard clause, or `None` when the inversion
+/// would change behavior.
+///
+/// The target is the nearest enclosing loop (`continue;`) or, with no
+/// enclosing loop, the function body (`return;`). The fix is sound only
+/// when everything between the target and the `if` executes after the
+/// `if` anyway — verified by walking trailing statements down from the
+/// target's body until the `if` is reached. A `switch` on the path fails
+/// the walk, which is also correct for `continue`: PHP targets the
+/// `switch` with it (acting as `break`, with a warning since 7.3).
+fn guard_jump(chain: &[(Span, &Statement)], target: &If, root: &Block) -> Option<&'static str> {
+    for (_, level) in chain.iter().rev() {
+        if let Some(body) = loop_body_statement(level) {
+            return statement_leads_to(body, target).then_some("continue;");
+        }
+    }
+
+    let last = root.statements.last()?;
+    statement_leads_to(last, target).then_some("return;")
+}
+
+/// The body statement of a loop; colon-delimited bodies are not handled.
+fn loop_body_statement(statement: &Statement) -> Option<&Statement> {
+    match statement {
+        Statement::For(r#for) => match &r#for.body {
+            ForBody::Statement(body) => Some(body),
+            _ => None,
+        },
+        Statement::Foreach(foreach) => match &foreach.body {
+            ForeachBody::Statement(body) => Some(body),
+            _ => None,
+        },
+        Statement::While(r#while) => match &r#while.body {
+            WhileBody::Statement(body) => Some(body),
+            _ => None,
+        },
+        Statement::DoWhile(do_while) => Some(do_while.statement.as_ref()),
+        _ => None,
+    }
+}
+
+/// Whether following only trailing statements from `statement` reaches
+/// `target` — i.e. a jump inserted inside `target` skips nothing that
+/// would otherwise still run before control leaves `statement`.
+///
+/// Only `if` levels (braced or not, either branch, no `elseif`) and plain
+/// blocks are followed; loops, `switch`, and everything else end the walk,
+/// because they either re-target the jump or hide statements the walk
+/// cannot account for.
+fn statement_leads_to(statement: &Statement, target: &If) -> bool {
+    match statement {
+        Statement::If(r#if) => {
+            if std::ptr::eq(r#if, target) {
+                return true;
+            }
+
+            let IfBody::Statement(body) = &r#if.body else {
+                return false;
+            };
+            if r#if.body.has_else_if() {
+                return false;
+            }
+
+            statement_leads_to(&body.statement, target)
+                || body.else_clause.as_ref().is_some_and(|clause| statement_leads_to(&clause.statement, target))
+        }
+        Statement::Block(block) => block.statements.last().is_some_and(|last| statement_leads_to(last, target)),
+        _ => false,
+    }
+}
+
+/// The pieces of the guard-clause inversion, when the `if` has the shape
+/// the textual fix can handle: `if (<cond>) { ... }` with a braced body
+/// and nothing between `)` and `{` but whitespace.
+///
+/// Returns `(condition span, negated condition text, offset after the
+/// opening brace, span of the closing brace)`.
+fn guard_clause_edits(context: &LintContext<'_>, r#if: &If) -> Option<(Span, String, usize, Span)> {
+    let source = context.source_text_full();
+    let condition_span = r#if.condition.span();
+
+    // Walk from the end of the condition over the closing parenthesis and
+    // whitespace to the opening brace.
+    let mut offset = condition_span.end.offset;
+    let bytes = source.as_bytes();
+    let mut seen_parenthesis = false;
+    loop {
+        match bytes.get(offset) {
+            Some(b')') if !seen_parenthesis => {
+                seen_parenthesis = true;
+                offset += 1;
+            }
+            Some(byte) if byte.is_ascii_whitespace() => offset += 1,
+            Some(b'{') if seen_parenthesis => break,
+            _ => return None,
+        }
+    }
+    let after_brace = offset + 1;
+
+    // With no `else` (guaranteed by the caller) and a braced body, the
+    // statement's last byte is the matching closing brace.
+    let if_span = r#if.span();
+    if !source[..if_span.end.offset].ends_with('}') {
+        return None;
+    }
+    let closing = Span::new(
+        Position { offset: if_span.end.offset - 1, ..if_span.end },
+        if_span.end,
+    );
+
+    let negated = negate_expression(&r#if.condition, source);
+
+    Some((condition_span, negated, after_brace, closing))
+}
@@ -0,0 +1,161 @@
+use mago_ast::*;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// PSR-1 "side effects" check: a file should either declare symbols
+/// (classes, functions, constants) or execute logic, not both.
+///
+/// Architectural report only — no autofix. Each offending top-level
+/// statement is pointed at individually, with a note naming the symbols
+/// the file declares.
+#[derive(Clone, Debug)]
+pub struct NoSideEffectsInDeclarationFilesRule;
+
+impl Rule for NoSideEffectsInDeclarationFilesRule {
+    fn get_name(&self) -> &'static str {
+        "no-side-effects-in-declaration-files"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+
+    fn runs_on_templates(&self) -> bool {
+        // Templates are side effects from top to bottom; the rule's premise
+        // does not apply.
+        false
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new().string_list(
+            "allow_calls",
+            "[]",
+            "Function calls allowed at the top level of declaration files (e.g. `spl_autoload_register`).",
+        )
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoSideEffectsInDeclarationFilesRule {
+    fn walk_in_program(&self, program: &Program, context: &mut LintContext<'a>) {
+        let mut declared: Vec<String> = Vec::new();
+        let mut side_effects: Vec<(mago_span::Span, &'static str)> = Vec::new();
+
+        let allowed_calls = context.option_string_list("allow_calls");
+
+        for statement in top_level_statements(program) {
+            match statement {
+                Statement::Class(class) => declared.push(context.lookup(&class.name.value).to_owned()),
+                Statement::Interface(interface) => declared.push(context.lookup(&interface.name.value).to_owned()),
+                Statement::Trait(r#trait) => declared.push(context.lookup(&r#trait.name.value).to_owned()),
+                Statement::Enum(r#enum) => declared.push(context.lookup(&r#enum.name.value).to_owned()),
+                Statement::Function(function) => declared.push(context.lookup(&function.name.value).to_owned()),
+                Statement::Constant(_) => declared.push("constant".to_owned()),
+                // Structure, not logic:
+                Statement::Namespace(_)
+                | Statement::Use(_)
+                | Statement::Declare(_)
+                | Statement::Inline(_)
+                | Statement::OpeningTag(_)
+                | Statement::ClosingTag(_)
+                | Statement::Noop(_) => {}
+                // Conditional declarations guarded by `class_exists` /
+                // `function_exists` are the accepted polyfill idiom.
+                Statement::If(r#if) if is_existence_guard(&r#if.condition) => {}
+                Statement::Echo(echo) => side_effects.push((echo.span(), "output")),
+                Statement::Expression(expression) => {
+                    if let Some(kind) = expression_side_effect(context, &expression.expression, &allowed_calls) {
+                        side_effects.push((expression.span(), kind));
+                    }
+                }
+                other => side_effects.push((other.span(), "executable logic")),
+            }
+        }
+
+        if declared.is_empty() || side_effects.is_empty() {
+            return;
+        }
+
+        let symbols = declared.join("`, `");
+        for (span, kind) in side_effects {
+            context.report(
+                Issue::new(context.level(), "File mixes symbol declarations with side effects (PSR-1).")
+                    .with_annotation(Annotation::primary(span).with_message(format!("this statement executes {kind}")))
+                    .with_note(format!("This file declares `{symbols}`; declaration files must not execute logic on load."))
+                    .with_help("Move executable statements into a bootstrap file, or the declarations into their own file."),
+            );
+        }
+    }
+}
+
+/// Iterate top-level statements, looking through the namespace wrapper.
+fn top_level_statements(program: &Program) -> impl Iterator<Item = &Statement> {
+    program.statements.iter().flat_map(|statement| match statement {
+        Statement::Namespace(namespace) => namespace.statements().as_slice().iter().collect::<Vec<_>>(),
+        other => vec![other],
+    })
+}
+
+fn is_existence_guard(condition: &Expression) -> bool {
+    let mut stack = vec![condition];
+    while let Some(expression) = stack.pop() {
+        match expression {
+            Expression::UnaryPrefix(unary) => stack.push(&unary.operand),
+            Expression::Parenthesized(inner) => stack.push(&inner.expression),
+            Expression::Call(Call::Function(call)) => {
+                if let Expression::Identifier(identifier) = call.function.as_ref() {
+                    if identifier.is_unqualified_named_ci("class_exists")
+                        || identifier.is_unqualified_named_ci("interface_exists")
+                        || identifier.is_unqualified_named_ci("function_exists")
+                        || identifier.is_unqualified_named_ci("defined")
+                    {
+                        return true;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// Classify a top-level expression statement as a side effect, or `None`
+/// when it is harmless or allow-listed.
+fn expression_side_effect(
+    context: &LintContext<'_>,
+    expression: &Expression,
+    allowed_calls: &[String],
+) -> Option<&'static str> {
+    match expression {
+        Expression::Call(Call::Function(call)) => {
+            let name = context.resolve_function_name(&call.function)?;
+            let name = name.trim_start_matches('\\');
+
+            if allowed_calls.iter().any(|allowed| allowed.eq_ignore_ascii_case(name)) {
+                return None;
+            }
+
+            if name.eq_ignore_ascii_case("ini_set") {
+                return Some("configuration changes (`ini_set`)");
+            }
+
+            Some("a top-level function call")
+        }
+        Expression::Construct(construct) => match construct.as_ref() {
+            Construct::Require(_) | Construct::RequireOnce(_) | Construct::Include(_) | Construct::IncludeOnce(_) => {
+                Some("a file inclusion")
+            }
+            Construct::Print(_) => Some("output"),
+            _ => Some("executable logic"),
+        },
+        Expression::Assignment(_) => Some("a top-level assignment"),
+        _ => Some("executable logic"),
+    }
+}
@@ -0,0 +1,196 @@
+use mago_ast::*;
+use mago_ast_utils::global_state::collect_global_state_usages;
+use mago_ast_utils::global_state::GlobalStateUsage;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::documentation::RuleDocumentation;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// Flags superglobal access outside designated entry-point files.
+///
+/// Code that reaches into `$_GET` / `$_POST` / `$_SESSION` deep inside
+/// the call graph is untestable without faking the whole request
+/// environment and invisible to static reasoning about inputs. The rule
+/// reports superglobal reads and writes, `global` statement bindings, and
+/// process-wide mutator calls (via
+/// `mago_ast_utils::global_state`) in any file whose path matches none of
+/// the configured `entry_point_paths` globs — front controllers and
+/// bootstrap files are where that access belongs, wrapped into a request
+/// abstraction before the rest of the code runs.
+///
+/// `superglobals` narrows which superglobals are reported; `global`
+/// statements and mutator calls are reported regardless, since no request
+/// object abstracts those away. No autofix — the fix is architectural.
+#[derive(Clone, Debug)]
+pub struct NoSuperglobalAccessRule;
+
+impl Rule for NoSuperglobalAccessRule {
+    fn get_name(&self) -> &'static str {
+        "no-superglobal-access"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new()
+            .string_list(
+                "entry_point_paths",
+                "[\"public/**\", \"bin/**\"]",
+                "Path globs (`*` within a segment, `**` across segments) where superglobal access is allowed.",
+            )
+            .string_list(
+                "superglobals",
+                "[]",
+                "Superglobals to report (e.g. `$_GET`); empty means all of them.",
+            )
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation::new(
+            "Reports superglobal access, `global` statements, and process-wide mutator calls \
+             outside entry-point files.",
+            "Code that reads `$_GET` or binds `global $db` deep inside the call graph cannot be \
+             tested without faking the whole request environment. Entry points — front \
+             controllers, console scripts — should touch superglobals once and hand the rest of \
+             the code plain values or a request abstraction. Files matching `entry_point_paths` \
+             are exempt.",
+        )
+        .bad_example(
+            "Superglobal in a service",
+            "<?php function currentUserId(): int { return (int) $_SESSION['user_id']; }",
+        )
+        .bad_example("Global binding", "<?php function handler() { global $db; $db->ping(); }")
+        .good_example(
+            "Dependency passed in",
+            "<?php function currentUserId(Session $session): int { return $session->userId(); }",
+        )
+    }
+}
+
+impl NoSuperglobalAccessRule {
+    fn check_body(&self, body: &Block, context: &mut LintContext<'_>) {
+        let allowed = context.option_string_list("entry_point_paths");
+        if allowed.iter().any(|glob| path_matches(context.source_name(), glob)) {
+            return;
+        }
+
+        let only: Vec<String> = context.option_string_list("superglobals");
+
+        for usage in collect_global_state_usages(context.interner, body) {
+            let issue = match &usage {
+                GlobalStateUsage::Superglobal { span, name, write } => {
+                    if !only.is_empty() && !only.iter().any(|wanted| wanted == name) {
+                        continue;
+                    }
+
+                    let action = if *write { "written" } else { "read" };
+                    Issue::new(context.level(), format!("Superglobal `{name}` {action} outside an entry point."))
+                        .with_annotation(
+                            Annotation::primary(*span).with_message(format!("`{name}` accessed here")),
+                        )
+                        .with_help(
+                            "Accept the value as a parameter or through a request abstraction; touch superglobals only in entry-point files.",
+                        )
+                }
+                GlobalStateUsage::GlobalBinding { span, variable } => {
+                    Issue::new(context.level(), format!("`global {variable};` couples this function to global state."))
+                        .with_annotation(Annotation::primary(*span).with_message("bound to the global scope here"))
+                        .with_help("Pass the dependency in explicitly instead of importing it from the global scope.")
+                }
+                GlobalStateUsage::Mutator { span, function } => {
+                    Issue::new(context.level(), format!("`{function}()` mutates process-wide state."))
+                        .with_annotation(Annotation::primary(*span).with_message("global state changed here"))
+                        .with_help("Confine process-wide configuration to bootstrap code.")
+                }
+            };
+
+            context.report(issue);
+        }
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoSuperglobalAccessRule {
+    fn walk_in_function(&self, function: &Function, context: &mut LintContext<'a>) {
+        self.check_body(&function.body, context);
+    }
+
+    fn walk_in_closure(&self, closure: &Closure, context: &mut LintContext<'a>) {
+        self.check_body(&closure.body, context);
+    }
+
+    fn walk_in_method(&self, method: &Method, context: &mut LintContext<'a>) {
+        if let MethodBody::Concrete(body) = &method.body {
+            self.check_body(body, context);
+        }
+    }
+}
+
+/// Minimal glob matching over `/`-separated paths: `*` matches within a
+/// segment, `**` matches any number of segments, `?` one character.
+fn path_matches(path: &str, glob: &str) -> bool {
+    fn segments(s: &str) -> Vec<&str> {
+        s.split('/').filter(|segment| !segment.is_empty()).collect()
+    }
+
+    fn match_segments(path: &[&str], glob: &[&str]) -> bool {
+        match glob.split_first() {
+            None => path.is_empty(),
+            Some((&"**", rest)) => {
+                (0..=path.len()).any(|skip| match_segments(&path[skip..], rest))
+            }
+            Some((pattern, rest)) => match path.split_first() {
+                Some((segment, path_rest)) => match_segment(segment, pattern) && match_segments(path_rest, rest),
+                None => false,
+            },
+        }
+    }
+
+    fn match_segment(segment: &str, pattern: &str) -> bool {
+        let segment: Vec<char> = segment.chars().collect();
+        let pattern: Vec<char> = pattern.chars().collect();
+
+        fn matches(segment: &[char], pattern: &[char]) -> bool {
+            match pattern.split_first() {
+                None => segment.is_empty(),
+                Some(('*', rest)) => (0..=segment.len()).any(|skip| matches(&segment[skip..], rest)),
+                Some(('?', rest)) => segment.split_first().is_some_and(|(_, tail)| matches(tail, rest)),
+                Some((expected, rest)) => {
+                    segment.split_first().is_some_and(|(actual, tail)| actual == expected && matches(tail, rest))
+                }
+            }
+        }
+
+        matches(&segment, &pattern)
+    }
+
+    match_segments(&segments(path), &segments(glob))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::path_matches;
+
+    #[test]
+    fn test_glob_segments_and_wildcards() {
+        assert!(path_matches("public/index.php", "public/**"));
+        assert!(path_matches("bin/console", "bin/*"));
+        assert!(path_matches("app/Http/Kernel.php", "**/Http/*.php"));
+
+        assert!(!path_matches("src/Service/Mailer.php", "public/**"));
+        assert!(!path_matches("public_html/index.php", "public/**"));
+    }
+
+    #[test]
+    fn test_double_star_spans_zero_or_more_segments() {
+        assert!(path_matches("public/index.php", "**/index.php"));
+        assert!(path_matches("index.php", "**/index.php"));
+        assert!(!path_matches("index.phps", "**/index.php"));
+    }
+}
@@ -0,0 +1,305 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_span::Span;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::documentation::RuleDocumentation;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// Keeps `?->` meaningful: every nullsafe operator should guard a
+/// receiver that can actually be null.
+///
+/// Three individually toggleable checks:
+///
+/// - `flag_never_null_receivers`: `$this?->x` and `(new Foo)?->x` — the
+///   receiver is never null, so `?->` only suggests a nullability that
+///   does not exist. `Safe` fix downgrading to `->`.
+/// - `flag_redundant_chain_links`: in `$a?->b()?->c()`, the first `?->`
+///   already short-circuits the whole chain when `$a` is null; the second
+///   is redundant when the codebase index knows `b()` returns
+///   non-nullable. `PotentiallyUnsafe` fix — the index may be missing a
+///   `@return` lie.
+/// - `prefer_nullsafe_over_ternary` (off by default): the pre-8.0 idiom
+///   `$x !== null ? $x->foo() : null` collapses to `$x?->foo()` on
+///   PHP ≥ 8.0. `Safe` fix: member access binds tighter than the ternary
+///   it replaces, so the rewrite never needs new parentheses.
+///
+/// Receiver classes are resolved the cheap, file-local way — `$this`,
+/// `new Foo(...)`, and chains of method calls whose return classes the
+/// index knows; anything else is left alone.
+#[derive(Clone, Debug)]
+pub struct NullsafeOperatorRule;
+
+impl Rule for NullsafeOperatorRule {
+    fn get_name(&self) -> &'static str {
+        "nullsafe-operator"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new()
+            .bool("flag_never_null_receivers", "true", "Report `?->` on `$this` and on freshly constructed objects.")
+            .bool(
+                "flag_redundant_chain_links",
+                "true",
+                "Report `?->` links made redundant by an earlier short-circuiting link with a non-nullable return.",
+            )
+            .bool(
+                "prefer_nullsafe_over_ternary",
+                "false",
+                "Report `$x !== null ? $x->foo() : null` ternaries convertible to `$x?->foo()` (PHP 8.0+).",
+            )
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation::new(
+            "Reports nullsafe `?->` operators whose receiver can never be null, and optionally \
+             ternaries that re-implement `?->`.",
+            "`?->` documents that the receiver may be null. On `$this`, on a `new` expression, \
+             or after a chain link that already short-circuits, that claim is false, and readers \
+             waste time looking for the nullability it implies.",
+        )
+        .bad_example("Nullsafe on $this", "<?php class C { function f() { return $this?->g(); } }")
+        .bad_example("Nullsafe on a fresh object", "<?php $name = (new User('a'))?->name();")
+        .good_example("Nullsafe on a genuinely nullable receiver", "<?php function f(?User $user) { return $user?->name(); }")
+        .fixable(SafetyClassification::PotentiallyUnsafe)
+        .requires_php("8.0")
+    }
+}
+
+impl NullsafeOperatorRule {
+    fn check_receiver(
+        &self,
+        object: &Expression,
+        question_mark_arrow: Span,
+        context: &mut LintContext<'_>,
+    ) {
+        if context.option_bool("flag_never_null_receivers").unwrap_or(true) {
+            if let Some(what) = never_null_receiver(object, context) {
+                context.report_with_fix(
+                    Issue::new(context.level(), format!("Nullsafe `?->` on {what}, which is never null."))
+                        .with_annotation(
+                            Annotation::primary(question_mark_arrow).with_message("this `?->` can be `->`"),
+                        )
+                        .with_help("The receiver cannot be null here; the nullsafe operator only implies otherwise."),
+                    |plan| plan.replace(question_mark_arrow, "->", SafetyClassification::Safe),
+                );
+                return;
+            }
+        }
+
+        if context.option_bool("flag_redundant_chain_links").unwrap_or(true) {
+            if let Expression::Call(Call::NullSafeMethod(inner)) = unwrap_parentheses(object) {
+                if returns_non_nullable(inner, context) == Some(true) {
+                    context.report_with_fix(
+                        Issue::new(
+                            context.level(),
+                            "Redundant `?->`: the earlier nullsafe link already short-circuits this one.",
+                        )
+                        .with_annotation(
+                            Annotation::primary(question_mark_arrow).with_message("this `?->` can be `->`"),
+                        )
+                        .with_annotation(
+                            Annotation::secondary(inner.question_mark_arrow)
+                                .with_message("a null receiver short-circuits here, and the call returns non-null"),
+                        )
+                        .with_help(
+                            "When the chain gets this far the previous link returned a non-nullable value; write `->`.",
+                        ),
+                        |plan| plan.replace(question_mark_arrow, "->", SafetyClassification::PotentiallyUnsafe),
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NullsafeOperatorRule {
+    fn walk_in_null_safe_method_call(&self, call: &NullSafeMethodCall, context: &mut LintContext<'a>) {
+        self.check_receiver(&call.object, call.question_mark_arrow, context);
+    }
+
+    fn walk_in_null_safe_property_access(&self, access: &NullSafePropertyAccess, context: &mut LintContext<'a>) {
+        self.check_receiver(&access.object, access.question_mark_arrow, context);
+    }
+
+    fn walk_in_conditional(&self, conditional: &Conditional, context: &mut LintContext<'a>) {
+        if !context.option_bool("prefer_nullsafe_over_ternary").unwrap_or(false)
+            || !context.php_version_is_at_least_80()
+        {
+            return;
+        }
+
+        let Some((kept, arrow, variable_name)) = nullsafe_convertible_ternary(conditional, context) else {
+            return;
+        };
+
+        let span = conditional.span();
+        context.report_with_fix(
+            Issue::new(
+                context.level(),
+                format!("This ternary re-implements `?->`; write `{variable_name}?->...` instead."),
+            )
+            .with_annotation(Annotation::primary(span).with_message("null check, access, and null fallback"))
+            .with_help("On PHP 8.0+ the nullsafe operator expresses this in one step."),
+            |mut plan| {
+                // Strip everything around the kept branch and upgrade its
+                // arrow; member access binds tighter than the ternary did,
+                // so the surroundings parse identically.
+                if span.start.offset < kept.span().start.offset {
+                    plan = plan.delete(Span::new(span.start, kept.span().start), SafetyClassification::Safe);
+                }
+                plan = plan.replace(arrow, "?->", SafetyClassification::Safe);
+                if kept.span().end.offset < span.end.offset {
+                    plan = plan.delete(Span::new(kept.span().end, span.end), SafetyClassification::Safe);
+                }
+                plan
+            },
+        );
+    }
+}
+
+/// A receiver expression that can never be null: `$this` (PHP fatals on
+/// a null `$this` long before the call, and it cannot be reassigned) or a
+/// fresh instantiation (`new` either returns an object or throws).
+fn never_null_receiver(object: &Expression, context: &LintContext<'_>) -> Option<&'static str> {
+    match unwrap_parentheses(object) {
+        Expression::Variable(Variable::Direct(variable)) if context.lookup(&variable.name) == "$this" => {
+            Some("`$this`")
+        }
+        Expression::Instantiation(_) => Some("a freshly constructed object"),
+        _ => None,
+    }
+}
+
+fn unwrap_parentheses(expression: &Expression) -> &Expression {
+    let mut current = expression;
+    while let Expression::Parenthesized(inner) = current {
+        current = &inner.expression;
+    }
+
+    current
+}
+
+/// Whether the index knows `call` to return a non-nullable value.
+/// `None` means the receiver class or the method is unknown.
+fn returns_non_nullable(call: &NullSafeMethodCall, context: &LintContext<'_>) -> Option<bool> {
+    let class = receiver_class(&call.object, context)?;
+    let ClassLikeMemberSelector::Identifier(name) = &call.method else {
+        return None;
+    };
+    let method = context.lookup(&name.value).to_ascii_lowercase();
+
+    context.codebase.method_return_is_nullable(&class, &method).map(|nullable| !nullable)
+}
+
+/// The class a receiver expression evaluates to, resolved the file-local
+/// way: `$this`, `new Foo(...)`, or a method-call chain whose return
+/// classes the codebase index knows.
+fn receiver_class(expression: &Expression, context: &LintContext<'_>) -> Option<String> {
+    match unwrap_parentheses(expression) {
+        Expression::Variable(Variable::Direct(variable)) if context.lookup(&variable.name) == "$this" => {
+            enclosing_class_name(context)
+        }
+        Expression::Instantiation(instantiation) => match instantiation.class.as_ref() {
+            Expression::Identifier(identifier) => {
+                Some(context.lookup_identifier(identifier).trim_start_matches('\\').to_ascii_lowercase())
+            }
+            _ => None,
+        },
+        Expression::Call(Call::Method(call)) => method_return_class(&call.object, &call.method, context),
+        Expression::Call(Call::NullSafeMethod(call)) => method_return_class(&call.object, &call.method, context),
+        _ => None,
+    }
+}
+
+fn method_return_class(
+    object: &Expression,
+    method: &ClassLikeMemberSelector,
+    context: &LintContext<'_>,
+) -> Option<String> {
+    let class = receiver_class(object, context)?;
+    let ClassLikeMemberSelector::Identifier(name) = method else {
+        return None;
+    };
+
+    context.codebase.get_method_return_class(&class, &context.lookup(&name.value).to_ascii_lowercase())
+}
+
+fn enclosing_class_name(context: &LintContext<'_>) -> Option<String> {
+    for node in context.ancestors() {
+        if let Node::Class(class) = node {
+            return Some(context.lookup(&class.name.value).to_ascii_lowercase());
+        }
+    }
+
+    None
+}
+
+/// Match `$x !== null ? $x->foo() : null` (and the `=== null` mirror with
+/// the branches swapped), returning the kept access expression, the span
+/// of its `->`, and the tested variable's name.
+fn nullsafe_convertible_ternary<'a>(
+    conditional: &'a Conditional,
+    context: &LintContext<'_>,
+) -> Option<(&'a Expression, Span, String)> {
+    let then = conditional.then.as_ref()?;
+
+    let Expression::Binary(binary) = &conditional.condition else {
+        return None;
+    };
+
+    let (tested, kept, fallback): (&Expression, &Expression, &Expression) = match binary.operator {
+        BinaryOperator::NotIdentical(_) => {
+            (non_null_side(&binary.lhs, &binary.rhs)?, then.as_ref(), conditional.r#else.as_ref())
+        }
+        BinaryOperator::Identical(_) => {
+            (non_null_side(&binary.lhs, &binary.rhs)?, conditional.r#else.as_ref(), then.as_ref())
+        }
+        _ => return None,
+    };
+
+    if !matches!(fallback, Expression::Literal(Literal::Null(_))) {
+        return None;
+    }
+
+    let Expression::Variable(Variable::Direct(tested_variable)) = tested else {
+        return None;
+    };
+    let variable_name = context.lookup(&tested_variable.name).to_owned();
+
+    // The kept branch must be a plain (non-nullsafe) access on exactly the
+    // tested variable; a deeper receiver would change evaluation order.
+    let (receiver, arrow) = match kept {
+        Expression::Call(Call::Method(call)) => (&call.object, call.arrow),
+        Expression::Access(Access::Property(access)) => (&access.object, access.arrow),
+        _ => return None,
+    };
+
+    match receiver {
+        Expression::Variable(Variable::Direct(receiver_variable))
+            if context.lookup(&receiver_variable.name) == variable_name =>
+        {
+            Some((kept, arrow, variable_name))
+        }
+        _ => None,
+    }
+}
+
+/// Of a comparison's two sides, the non-`null` one — or `None` when
+/// neither side is the literal `null`.
+fn non_null_side<'a>(lhs: &'a Expression, rhs: &'a Expression) -> Option<&'a Expression> {
+    match (lhs, rhs) {
+        (Expression::Literal(Literal::Null(_)), other) | (other, Expression::Literal(Literal::Null(_))) => Some(other),
+        _ => None,
+    }
+}
@@ -0,0 +1,219 @@
+use mago_ast::*;
+use mago_ast_utils::structural_eq::structurally_equal;
+use mago_fixer::SafetyClassification;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// Prefers `??` / `??=` over `isset()` ternaries and guarded assignments.
+///
+/// Recognized shapes, with structural (span-insensitive) equality between
+/// the tested and returned expressions:
+///
+/// - `isset($a) ? $a : $b`          → `$a ?? $b` (`Safe`)
+/// - `!isset($a) ? $b : $a`         → `$a ?? $b` (`Safe`)
+/// - `array_key_exists('k', $arr) ? $arr['k'] : $d`
+///                                  → `$arr['k'] ?? $d` (`PotentiallyUnsafe`:
+///   `??` also skips null values, `array_key_exists` does not)
+/// - `if (!isset($x)) { $x = $d; }` → `$x ??= $d` (`Safe`, PHP 7.4+)
+///
+/// `isset()` with several arguments has no `??` equivalent and is ignored.
+#[derive(Clone, Debug)]
+pub struct PreferNullCoalescingRule;
+
+impl Rule for PreferNullCoalescingRule {
+    fn get_name(&self) -> &'static str {
+        "prefer-null-coalescing"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Note)
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new().bool(
+            "check_array_key_exists",
+            "true",
+            "Also report `array_key_exists(...) ? ... : ...` ternaries (the fix changes null-value behavior).",
+        )
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for PreferNullCoalescingRule {
+    fn walk_in_conditional(&self, conditional: &Conditional, context: &mut LintContext<'a>) {
+        let Some(then) = conditional.then.as_ref() else {
+            // `$a ?: $b` is already coalescing-adjacent; not our pattern.
+            return;
+        };
+
+        // `isset($a) ? $a : $b` and the negated spelling.
+        let (subject, kept, negated) = match &conditional.condition {
+            Expression::Construct(construct) => match construct.as_ref() {
+                Construct::Isset(isset) => match single_isset_value(isset) {
+                    Some(subject) => (subject, then.as_ref(), false),
+                    None => return,
+                },
+                _ => return,
+            },
+            Expression::UnaryPrefix(unary) if unary.operator.is_not() => match unary.operand.as_ref() {
+                Expression::Construct(construct) => match construct.as_ref() {
+                    Construct::Isset(isset) => match single_isset_value(isset) {
+                        Some(subject) => (subject, conditional.r#else.as_ref(), true),
+                        None => return,
+                    },
+                    _ => return,
+                },
+                Expression::Call(Call::Function(call)) => {
+                    self.check_array_key_exists(conditional, call, true, context);
+                    return;
+                }
+                _ => return,
+            },
+            Expression::Call(Call::Function(call)) => {
+                self.check_array_key_exists(conditional, call, false, context);
+                return;
+            }
+            _ => return,
+        };
+
+        if !structurally_equal(context.interner, Node::Expression(subject), Node::Expression(kept)) {
+            return;
+        }
+
+        let fallback = if negated { then.as_ref() } else { conditional.r#else.as_ref() };
+        let replacement =
+            format!("{} ?? {}", context.source_text(subject.span()), context.source_text(fallback.span()));
+
+        let issue = Issue::new(context.level(), "Use `??` instead of an `isset()` ternary.")
+            .with_annotation(Annotation::primary(conditional.span()).with_message("this ternary re-reads the tested expression"))
+            .with_help("`$a ?? $b` evaluates `$a` once and has identical null semantics to `isset($a) ? $a : $b`.");
+
+        context.report_with_fix(issue, |plan| {
+            plan.replace(conditional.span(), replacement, SafetyClassification::Safe)
+        });
+    }
+
+    fn walk_in_if(&self, r#if: &If, context: &mut LintContext<'a>) {
+        // `if (!isset($x)) { $x = $d; }` → `$x ??= $d;`
+        if !context.php_version_is_at_least_74() || r#if.body.has_else() || r#if.body.has_else_if() {
+            return;
+        }
+
+        let Expression::UnaryPrefix(unary) = &r#if.condition else {
+            return;
+        };
+
+        if !unary.operator.is_not() {
+            return;
+        }
+
+        let Expression::Construct(construct) = unary.operand.as_ref() else {
+            return;
+        };
+
+        let Construct::Isset(isset) = construct.as_ref() else {
+            return;
+        };
+
+        let Some(subject) = single_isset_value(isset) else {
+            return;
+        };
+
+        let Some(Statement::Expression(statement)) = r#if.body.single_statement() else {
+            return;
+        };
+
+        let Expression::Assignment(assignment) = &statement.expression else {
+            return;
+        };
+
+        if !matches!(assignment.operator, AssignmentOperator::Assign(_))
+            || !structurally_equal(context.interner, Node::Expression(subject), Node::Expression(&assignment.lhs))
+        {
+            return;
+        }
+
+        let replacement = format!(
+            "{} ??= {};",
+            context.source_text(assignment.lhs.span()),
+            context.source_text(assignment.rhs.span()),
+        );
+
+        let issue = Issue::new(context.level(), "Use `??=` instead of an `isset()` guard.")
+            .with_annotation(Annotation::primary(r#if.span()).with_message("this guarded assignment is a coalescing assignment"))
+            .with_help("`$x ??= $default;` assigns only when `$x` is unset or null.");
+
+        context.report_with_fix(issue, |plan| plan.replace(r#if.span(), replacement, SafetyClassification::Safe));
+    }
+}
+
+impl PreferNullCoalescingRule {
+    fn check_array_key_exists(
+        &self,
+        conditional: &Conditional,
+        call: &FunctionCall,
+        negated: bool,
+        context: &mut LintContext<'_>,
+    ) {
+        if !context.option_bool("check_array_key_exists").unwrap_or(true) {
+            return;
+        }
+
+        let Expression::Identifier(identifier) = call.function.as_ref() else {
+            return;
+        };
+
+        if !identifier.is_unqualified_named_ci("array_key_exists") || call.argument_list.arguments.len() != 2 {
+            return;
+        }
+
+        let key = call.argument_list.arguments.as_slice()[0].value();
+        let array = call.argument_list.arguments.as_slice()[1].value();
+
+        let Some(then) = conditional.then.as_ref() else {
+            return;
+        };
+
+        let kept = if negated { conditional.r#else.as_ref() } else { then.as_ref() };
+        let fallback = if negated { then.as_ref() } else { conditional.r#else.as_ref() };
+
+        // The kept branch must be exactly `$array[$key]`.
+        let Expression::ArrayAccess(access) = kept else {
+            return;
+        };
+
+        if !structurally_equal(context.interner, Node::Expression(&access.array), Node::Expression(array))
+            || !structurally_equal(context.interner, Node::Expression(&access.index), Node::Expression(key))
+        {
+            return;
+        }
+
+        let replacement =
+            format!("{} ?? {}", context.source_text(kept.span()), context.source_text(fallback.span()));
+
+        let issue = Issue::new(context.level(), "Consider `??` instead of an `array_key_exists()` ternary.")
+            .with_annotation(Annotation::primary(conditional.span()).with_message("this ternary re-reads the array entry"))
+            .with_note("`??` treats a present-but-null entry as missing, while `array_key_exists` does not; review call sites that store null.")
+            .with_help("Replace with `$array[$key] ?? $default` if null entries should fall back too.");
+
+        context.report_with_fix(issue, |plan| {
+            plan.replace(conditional.span(), replacement, SafetyClassification::PotentiallyUnsafe)
+        });
+    }
+}
+
+/// The single tested expression of an `isset(...)`, or `None` when isset
+/// has several arguments (not convertible to `??`).
+fn single_isset_value(isset: &IssetConstruct) -> Option<&Expression> {
+    let values = isset.values.as_slice();
+    match values {
+        [single] => Some(single),
+        _ => None,
+    }
+}
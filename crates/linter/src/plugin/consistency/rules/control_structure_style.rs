@@ -0,0 +1,132 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_formatter::control_structure::edits_for_if;
+use mago_formatter::control_structure::edits_for_loop;
+use mago_formatter::control_structure::ControlBraces;
+use mago_formatter::control_structure::ControlStructureSettings;
+use mago_formatter::control_structure::ElseIfStyle;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// Enforces PSR-12 control structure style: braced bodies, a single
+/// `elseif` spelling, and one space around the condition parentheses.
+///
+/// The actual transformations live in
+/// `mago_formatter::control_structure`, shared with the formatter so that
+/// running `lint --fix` and running the formatter produce identical
+/// output.
+#[derive(Clone, Debug)]
+pub struct ControlStructureStyleRule;
+
+impl Rule for ControlStructureStyleRule {
+    fn get_name(&self) -> &'static str {
+        "control-structure-style"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new()
+            .string("control_braces", "\"always\"", "Brace single-statement bodies: `always` or `preserve`.")
+            .string("elseif_style", "\"elseif\"", "Spelling to enforce: `elseif`, `else_if`, or `preserve`.")
+            .bool("normalize_spacing", "true", "Enforce one space around the condition parentheses.")
+    }
+}
+
+impl ControlStructureStyleRule {
+    fn settings(context: &LintContext<'_>) -> ControlStructureSettings {
+        ControlStructureSettings {
+            control_braces: match context.option_str("control_braces") {
+                Some("preserve") => ControlBraces::Preserve,
+                _ => ControlBraces::Always,
+            },
+            elseif_style: match context.option_str("elseif_style") {
+                Some("else_if") => ElseIfStyle::ElseIf,
+                Some("preserve") => ElseIfStyle::Preserve,
+                _ => ElseIfStyle::Elseif,
+            },
+            normalize_spacing: context.option_bool("normalize_spacing").unwrap_or(true),
+        }
+    }
+
+    fn report_edits(
+        context: &mut LintContext<'_>,
+        subject: mago_span::Span,
+        edits: Vec<(mago_span::Span, String)>,
+    ) {
+        if edits.is_empty() {
+            return;
+        }
+
+        context.report_with_fix(
+            Issue::new(context.level(), "Control structure does not follow the configured style.")
+                .with_annotation(
+                    Annotation::primary(subject).with_message("braces, spacing, or `elseif` spelling differ here"),
+                )
+                .with_help("Run the fixer or the formatter to normalize this control structure."),
+            |mut plan| {
+                for (span, text) in edits {
+                    plan = plan.replace(span, text, SafetyClassification::Safe);
+                }
+                plan
+            },
+        );
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for ControlStructureStyleRule {
+    fn walk_in_if(&self, r#if: &If, context: &mut LintContext<'a>) {
+        let edits = edits_for_if(r#if, context.source_text_full(), &Self::settings(context));
+        Self::report_edits(context, r#if.r#if, edits);
+    }
+
+    fn walk_in_while(&self, r#while: &While, context: &mut LintContext<'a>) {
+        let WhileBody::Statement(body) = &r#while.body else {
+            return;
+        };
+
+        let edits = edits_for_loop(
+            r#while.r#while,
+            r#while.condition.span(),
+            body,
+            context.source_text_full(),
+            &Self::settings(context),
+        );
+        Self::report_edits(context, r#while.r#while, edits);
+    }
+
+    fn walk_in_for(&self, r#for: &For, context: &mut LintContext<'a>) {
+        let ForBody::Statement(body) = &r#for.body else {
+            return;
+        };
+
+        let conditions = r#for.initializations.span().join(r#for.increments.span());
+        let edits =
+            edits_for_loop(r#for.r#for, conditions, body, context.source_text_full(), &Self::settings(context));
+        Self::report_edits(context, r#for.r#for, edits);
+    }
+
+    fn walk_in_foreach(&self, foreach: &Foreach, context: &mut LintContext<'a>) {
+        let ForeachBody::Statement(body) = &foreach.body else {
+            return;
+        };
+
+        let edits = edits_for_loop(
+            foreach.foreach,
+            foreach.expression.span().join(foreach.target.span()),
+            body,
+            context.source_text_full(),
+            &Self::settings(context),
+        );
+        Self::report_edits(context, foreach.foreach, edits);
+    }
+}
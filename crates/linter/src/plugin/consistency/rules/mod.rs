@@ -0,0 +1,6 @@
+pub mod control_structure_style;
+pub mod modifier_order;
+pub mod prefer_imports;
+pub mod require_visibility;
+pub mod short_array_syntax;
+pub mod string_style;
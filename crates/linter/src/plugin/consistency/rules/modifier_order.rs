@@ -0,0 +1,86 @@
+use mago_ast::*;
+use mago_ast_utils::modifier_order::ModifierOrder;
+use mago_fixer::SafetyClassification;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+/// Normalizes declaration modifier order (`static public final` →
+/// `final public static`, with the default order being visibility,
+/// `abstract`/`final`, `static`, `readonly`).
+///
+/// The canonical order lives in `mago_ast_utils::modifier_order` and is the
+/// same one the formatter prints, so the rule's fix and the formatter can
+/// never disagree. The fix swaps the existing keyword tokens in place,
+/// preserving their original casing and any comments written between them;
+/// `var` is never reordered.
+#[derive(Clone, Debug)]
+pub struct ModifierOrderRule;
+
+impl Rule for ModifierOrderRule {
+    fn get_name(&self) -> &'static str {
+        "modifier-order"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Note)
+    }
+}
+
+impl ModifierOrderRule {
+    fn check(&self, modifiers: &Sequence<Modifier>, context: &mut LintContext<'_>) {
+        if modifiers.len() < 2 {
+            return;
+        }
+
+        let order: ModifierOrder = context.shared_modifier_order();
+        let replacements = order.reorder_replacements(modifiers, context.source_text_full());
+        if replacements.is_empty() {
+            return;
+        }
+
+        let span = modifiers.span();
+        let issue = Issue::new(context.level(), "Modifiers are not in the canonical order.")
+            .with_annotation(Annotation::primary(span).with_message("reorder these modifiers"))
+            .with_help("Order modifiers as: visibility, abstract/final, static, readonly (configurable via `modifier_order`).");
+
+        context.report_with_fix(issue, |mut plan| {
+            for (span, text) in replacements {
+                plan = plan.replace(span, text, SafetyClassification::Safe);
+            }
+
+            plan
+        });
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for ModifierOrderRule {
+    fn walk_in_method(&self, method: &Method, context: &mut LintContext<'a>) {
+        self.check(&method.modifiers, context);
+    }
+
+    fn walk_in_plain_property(&self, property: &PlainProperty, context: &mut LintContext<'a>) {
+        self.check(&property.modifiers, context);
+    }
+
+    fn walk_in_hooked_property(&self, property: &HookedProperty, context: &mut LintContext<'a>) {
+        self.check(&property.modifiers, context);
+    }
+
+    fn walk_in_class_like_constant(&self, constant: &ClassLikeConstant, context: &mut LintContext<'a>) {
+        self.check(&constant.modifiers, context);
+    }
+
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        self.check(&class.modifiers, context);
+    }
+
+    fn walk_in_function_like_parameter(&self, parameter: &FunctionLikeParameter, context: &mut LintContext<'a>) {
+        self.check(&parameter.modifiers, context);
+    }
+}
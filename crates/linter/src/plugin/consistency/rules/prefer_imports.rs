@@ -0,0 +1,314 @@
+use std::collections::BTreeMap;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_span::Span;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::documentation::RuleDocumentation;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// Prefers imported short names over inline fully-qualified references.
+///
+/// Two modes. Always on: a `\Foo\Bar` reference whose class is already
+/// imported is reported with a `Safe` fix replacing it by the import's
+/// short name (or alias) — the two spellings resolve identically, so
+/// nothing can change. Behind `suggest_missing_imports`: any inline FQCN
+/// of more than one segment is reported with a `PotentiallyUnsafe` fix
+/// that inserts a `use` statement in sorted position among the existing
+/// imports and shortens every occurrence in the file.
+///
+/// Conflict detection is file-local: the proposed short name must collide
+/// with neither an existing import nor a class-like declared in the file
+/// (the same-namespace symbols visible without an import). On collision
+/// the reference is left alone — the qualification is what disambiguates
+/// it. Files using grouped or `function`/`const` imports are excluded
+/// from suggestion mode, since the collision set cannot be computed from
+/// plain import sequences alone.
+///
+/// Fully-qualified *function* and *constant* references (`\strlen(...)`)
+/// are not class references: the leading backslash there is a deliberate
+/// opcode-level optimization, and they are skipped unless
+/// `include_global_functions` is set.
+#[derive(Clone, Debug)]
+pub struct PreferImportsRule;
+
+impl Rule for PreferImportsRule {
+    fn get_name(&self) -> &'static str {
+        "prefer-imports"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new()
+            .bool(
+                "suggest_missing_imports",
+                "false",
+                "Also report multi-segment FQCNs that are not imported yet, with a fix adding the import.",
+            )
+            .bool(
+                "include_global_functions",
+                "false",
+                "Also report fully-qualified function and constant references such as `\\strlen`.",
+            )
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation::new(
+            "Reports inline fully-qualified class references that an existing or addable import \
+             would shorten.",
+            "A file that imports `Foo\\Bar` and still writes `\\Foo\\Bar` inline has two \
+             spellings for one class; readers must check whether they differ. Keeping class \
+             references short and the qualification in one `use` block makes renames and \
+             namespace moves single-line diffs.",
+        )
+        .bad_example(
+            "Imported but referenced in full",
+            "<?php\nuse Foo\\Bar;\n\nfunction f(): \\Foo\\Bar { return new \\Foo\\Bar(); }",
+        )
+        .good_example(
+            "Short name after the import",
+            "<?php\nuse Foo\\Bar;\n\nfunction f(): Bar { return new Bar(); }",
+        )
+        .fixable(SafetyClassification::PotentiallyUnsafe)
+    }
+}
+
+/// One class import collected from a plain `use` sequence.
+struct Import {
+    /// Normalized full name: lowercased, no leading `\`.
+    full: String,
+    /// The short name as written — the alias, or the last segment.
+    short_written: String,
+    short_lower: String,
+    statement_span: Span,
+}
+
+/// One fully-qualified reference found outside `use` statements.
+struct Reference {
+    span: Span,
+    /// As written, without the leading `\`.
+    written: String,
+}
+
+impl<'a> Walker<LintContext<'a>> for PreferImportsRule {
+    fn walk_in_program(&self, program: &Program, context: &mut LintContext<'a>) {
+        let suggest = context.option_bool("suggest_missing_imports").unwrap_or(false);
+        let include_functions = context.option_bool("include_global_functions").unwrap_or(false);
+
+        let (imports, imports_are_complete) = collect_imports(program, context);
+        let declared = collect_declared_short_names(program, context);
+        let references = collect_fully_qualified_references(program, context, include_functions);
+
+        let mut missing: BTreeMap<String, (String, Vec<Span>)> = BTreeMap::new();
+
+        for reference in &references {
+            let full = reference.written.to_ascii_lowercase();
+
+            if let Some(import) = imports.iter().find(|import| import.full == full) {
+                let short = import.short_written.clone();
+                context.report_with_fix(
+                    Issue::new(
+                        context.level(),
+                        format!("`\\{}` is already imported; use `{short}`.", reference.written),
+                    )
+                    .with_annotation(
+                        Annotation::primary(reference.span).with_message("spelled out in full here"),
+                    )
+                    .with_help(format!("The `use` statement makes `{short}` mean exactly this class.")),
+                    |plan| plan.replace(reference.span, short.clone(), SafetyClassification::Safe),
+                );
+                continue;
+            }
+
+            // Suggestions need an import block to extend: with no imports
+            // at all there is no sorted position to insert into, and such
+            // files (single-class scripts, templates) rarely want one.
+            if !suggest || !imports_are_complete || imports.is_empty() {
+                continue;
+            }
+
+            let Some(short) = last_segment(&reference.written) else {
+                continue;
+            };
+
+            // A colliding short name is exactly why the author qualified
+            // the reference; leave it alone.
+            let short_lower = short.to_ascii_lowercase();
+            if imports.iter().any(|import| import.short_lower == short_lower) || declared.contains(&short_lower) {
+                continue;
+            }
+
+            let entry = missing.entry(full).or_insert_with(|| (reference.written.clone(), Vec::new()));
+            entry.1.push(reference.span);
+        }
+
+        for (written, spans) in missing.into_values() {
+            let short = last_segment(&written).expect("multi-segment by construction").to_owned();
+            let insertion = sorted_insertion(&imports, &written);
+
+            let mut issue = Issue::new(
+                context.level(),
+                format!("Inline fully-qualified reference to `{written}`; import it instead."),
+            )
+            .with_annotation(Annotation::primary(spans[0]).with_message("spelled out in full here"))
+            .with_help(format!("Add `use {written};` and refer to the class as `{short}`."));
+            for span in &spans[1..] {
+                issue = issue.with_annotation(Annotation::secondary(*span).with_message("and here"));
+            }
+
+            context.report_with_fix(issue, |mut plan| {
+                let (offset, text) = &insertion;
+                plan = plan.insert(*offset, text.clone(), SafetyClassification::PotentiallyUnsafe);
+                for span in &spans {
+                    plan = plan.replace(*span, short.clone(), SafetyClassification::PotentiallyUnsafe);
+                }
+                plan
+            });
+        }
+    }
+}
+
+/// Collect plain class imports, and whether they are the *complete*
+/// import picture — grouped lists and `use function` / `use const` make
+/// the collision set unknowable here, which disables suggestion mode.
+fn collect_imports(program: &Program, context: &LintContext<'_>) -> (Vec<Import>, bool) {
+    let mut imports = Vec::new();
+    let mut complete = true;
+
+    let mut stack = vec![Node::Program(program)];
+    while let Some(node) = stack.pop() {
+        if let Node::Statement(Statement::Use(r#use)) = node {
+            match &r#use.items {
+                UseItems::Sequence(sequence) => {
+                    for item in sequence.items.iter() {
+                        let written = context.lookup_identifier(&item.name).trim_start_matches('\\').to_owned();
+                        let short_written = match &item.alias {
+                            Some(alias) => context.lookup(&alias.identifier.value).to_owned(),
+                            None => match last_segment(&written) {
+                                Some(segment) => segment.to_owned(),
+                                None => written.clone(),
+                            },
+                        };
+
+                        imports.push(Import {
+                            full: written.to_ascii_lowercase(),
+                            short_lower: short_written.to_ascii_lowercase(),
+                            short_written,
+                            statement_span: r#use.span(),
+                        });
+                    }
+                }
+                _ => complete = false,
+            }
+            continue;
+        }
+
+        stack.extend(node.children());
+    }
+
+    (imports, complete)
+}
+
+/// Lowercased short names of every class-like declared in the file.
+fn collect_declared_short_names(program: &Program, context: &LintContext<'_>) -> Vec<String> {
+    let mut names = Vec::new();
+
+    let mut stack = vec![Node::Program(program)];
+    while let Some(node) = stack.pop() {
+        match node {
+            Node::Class(class) => names.push(context.lookup(&class.name.value).to_ascii_lowercase()),
+            Node::Interface(interface) => names.push(context.lookup(&interface.name.value).to_ascii_lowercase()),
+            Node::Trait(r#trait) => names.push(context.lookup(&r#trait.name.value).to_ascii_lowercase()),
+            Node::Enum(r#enum) => names.push(context.lookup(&r#enum.name.value).to_ascii_lowercase()),
+            _ => {}
+        }
+
+        stack.extend(node.children());
+    }
+
+    names
+}
+
+/// Every fully-qualified identifier used as a class reference: in
+/// expressions, type hints, attributes — everywhere except inside `use`
+/// statements, and except function-call targets and constant accesses
+/// unless `include_functions`.
+fn collect_fully_qualified_references(
+    program: &Program,
+    context: &LintContext<'_>,
+    include_functions: bool,
+) -> Vec<Reference> {
+    let mut references = Vec::new();
+    let mut excluded: Vec<Span> = Vec::new();
+
+    let mut stack = vec![Node::Program(program)];
+    while let Some(node) = stack.pop() {
+        match node {
+            Node::Statement(Statement::Use(_)) => continue,
+            Node::Expression(Expression::Call(Call::Function(call))) if !include_functions => {
+                if let Expression::Identifier(identifier) = call.function.as_ref() {
+                    excluded.push(identifier.span());
+                }
+            }
+            Node::Expression(Expression::ConstantAccess(access)) if !include_functions => {
+                excluded.push(access.name.span());
+            }
+            Node::Identifier(identifier) => {
+                if matches!(identifier, Identifier::FullyQualified(_)) && !excluded.contains(&identifier.span()) {
+                    references.push(Reference {
+                        span: identifier.span(),
+                        written: context.lookup_identifier(identifier).trim_start_matches('\\').to_owned(),
+                    });
+                }
+            }
+            _ => {}
+        }
+
+        stack.extend(node.children());
+    }
+
+    references.sort_by_key(|reference| reference.span.start.offset);
+    references
+}
+
+fn last_segment(name: &str) -> Option<&str> {
+    let (_, segment) = name.rsplit_once('\\')?;
+    Some(segment)
+}
+
+/// Where and what to insert for a new `use {name};`, keeping the import
+/// block sorted (case-insensitively, like most import sorters).
+///
+/// Before the first import sorting after the new name, the text ends in a
+/// newline so the displaced import keeps its line; after the last import
+/// otherwise. Suggestion mode guarantees at least one import exists.
+fn sorted_insertion(imports: &[Import], name: &str) -> (usize, String) {
+    let lower = name.to_ascii_lowercase();
+
+    let mut successor: Option<&Import> = None;
+    let mut last: Option<&Import> = None;
+    for import in imports {
+        if import.full > lower && successor.is_none_or(|found| import.statement_span.start.offset < found.statement_span.start.offset) {
+            successor = Some(import);
+        }
+        if last.is_none_or(|found| import.statement_span.end.offset > found.statement_span.end.offset) {
+            last = Some(import);
+        }
+    }
+
+    match (successor, last) {
+        (Some(import), _) => (import.statement_span.start.offset, format!("use {name};\n")),
+        (None, Some(import)) => (import.statement_span.end.offset, format!("\nuse {name};")),
+        (None, None) => unreachable!("suggestion mode requires at least one existing import"),
+    }
+}
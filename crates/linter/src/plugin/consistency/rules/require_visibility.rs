@@ -0,0 +1,131 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// Requires explicit visibility on methods, properties, and class constants
+/// (PSR-12: no implicit `public`, no bare `var`).
+///
+/// The `Safe` fix inserts `public ` (or replaces `var`) at the position the
+/// configured modifier order dictates; the order option is shared with the
+/// formatter's modifier normalization so the two can never disagree.
+#[derive(Clone, Debug)]
+pub struct RequireVisibilityRule;
+
+impl Rule for RequireVisibilityRule {
+    fn get_name(&self) -> &'static str {
+        "require-visibility"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new().bool(
+            "explicit_in_interfaces",
+            "true",
+            "Require explicit `public` on interface members too, where it is the only legal visibility.",
+        )
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for RequireVisibilityRule {
+    fn walk_in_method(&self, method: &Method, context: &mut LintContext<'a>) {
+        if method.modifiers.contains_visibility() {
+            return;
+        }
+
+        if context.in_interface() && !context.option_bool("explicit_in_interfaces").unwrap_or(true) {
+            return;
+        }
+
+        let name = context.lookup(&method.name.value);
+        let offset = visibility_insertion_offset(context, &method.modifiers, method.function.span().start.offset);
+        let issue = Issue::new(context.level(), format!("Method `{name}` has no visibility declared."))
+            .with_annotation(Annotation::primary(method.name.span()).with_message("implicitly public"))
+            .with_help("Declare the visibility explicitly: `public function ...`.");
+
+        context.report_with_fix(issue, |plan| plan.insert(offset, "public ", SafetyClassification::Safe));
+    }
+
+    fn walk_in_plain_property(&self, property: &PlainProperty, context: &mut LintContext<'a>) {
+        if property.modifiers.contains_visibility() {
+            return;
+        }
+
+        if let Some(var) = &property.var {
+            // `var $x` is the PHP 4 spelling of `public $x`.
+            let issue = Issue::new(context.level(), "Use an explicit visibility instead of `var`.")
+                .with_annotation(Annotation::primary(var.span()).with_message("`var` means implicit public"))
+                .with_help("Replace `var` with `public` (PSR-12).");
+
+            let var_span = var.span();
+            context.report_with_fix(issue, |plan| plan.replace(var_span, "public", SafetyClassification::Safe));
+            return;
+        }
+
+        let anchor = property
+            .hint
+            .as_ref()
+            .map(|hint| hint.span().start.offset)
+            .unwrap_or_else(|| property.first_variable().span().start.offset);
+        let offset = visibility_insertion_offset(context, &property.modifiers, anchor);
+
+        let issue = Issue::new(context.level(), "Property has no visibility declared.")
+            .with_annotation(Annotation::primary(property.first_variable().span()).with_message("implicitly public"))
+            .with_help("Declare the visibility explicitly (PSR-12).");
+
+        context.report_with_fix(issue, |plan| plan.insert(offset, "public ", SafetyClassification::Safe));
+    }
+
+    fn walk_in_class_like_constant(&self, constant: &ClassLikeConstant, context: &mut LintContext<'a>) {
+        if constant.modifiers.contains_visibility() {
+            return;
+        }
+
+        if context.in_interface() && !context.option_bool("explicit_in_interfaces").unwrap_or(true) {
+            return;
+        }
+
+        if !context.php_version_is_at_least_71() {
+            // Constant visibility is PHP 7.1+ syntax.
+            return;
+        }
+
+        let offset = visibility_insertion_offset(context, &constant.modifiers, constant.r#const.span().start.offset);
+        let issue = Issue::new(context.level(), "Class constant has no visibility declared.")
+            .with_annotation(Annotation::primary(constant.span()).with_message("implicitly public"))
+            .with_help("Declare the visibility explicitly: `public const ...` (PSR-12).");
+
+        context.report_with_fix(issue, |plan| plan.insert(offset, "public ", SafetyClassification::Safe));
+    }
+}
+
+/// Where `public ` must be inserted so the result matches the configured
+/// modifier order (shared with the formatter via the `modifier_order`
+/// setting; default places visibility first, before `static`, `final`,
+/// `abstract`, and `readonly`).
+fn visibility_insertion_offset(context: &LintContext<'_>, modifiers: &Sequence<Modifier>, fallback: usize) -> usize {
+    let order = context.shared_modifier_order();
+    let visibility_rank = order.rank_of("public");
+
+    for modifier in modifiers.iter() {
+        if order.rank_of(modifier.keyword()) > visibility_rank {
+            return modifier.span().start.offset;
+        }
+    }
+
+    match modifiers.last() {
+        // All present modifiers sort before visibility: insert after them.
+        Some(last) if order.rank_of(last.keyword()) <= visibility_rank => fallback,
+        _ => modifiers.first().map(|modifier| modifier.span().start.offset).unwrap_or(fallback),
+    }
+}
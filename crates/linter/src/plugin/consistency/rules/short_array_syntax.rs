@@ -0,0 +1,95 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_formatter::array_syntax::edits_for_legacy_array;
+use mago_formatter::array_syntax::edits_for_list;
+use mago_formatter::array_syntax::ArraySyntaxSettings;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::documentation::RuleDocumentation;
+use crate::rule::Rule;
+
+/// Prefers `[...]` over `array(...)` literals and `list(...)`
+/// destructuring.
+///
+/// The conversion lives in `mago_formatter::array_syntax`, shared with
+/// the formatter: only the delimiters change, so keys, references,
+/// skipped slots, trailing commas, and comments inside the parentheses
+/// are untouched, and nested `array(list(...))` constructs convert one
+/// node at a time. The fix is `Safe` — the two spellings are the same
+/// construct.
+///
+/// `list(...)` is reported only when the configured PHP target is at
+/// least 7.1, where short destructuring exists; `array(...)` converts
+/// unconditionally (short arrays are PHP 5.4+).
+#[derive(Clone, Debug)]
+pub struct ShortArraySyntaxRule;
+
+impl Rule for ShortArraySyntaxRule {
+    fn get_name(&self) -> &'static str {
+        "short-array-syntax"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation::new(
+            "Reports `array(...)` literals and `list(...)` destructuring where `[...]` works.",
+            "The short syntax is the same construct with less noise, and mixing the two \
+             spellings in one codebase makes diffs and searches harder. The fix replaces only \
+             the delimiters, so keys, references, trailing commas, and comments inside the \
+             parentheses survive the conversion.",
+        )
+        .bad_example("Long array literal", "<?php $map = array('a' => 1, 'b' => 2);")
+        .bad_example("Long destructuring", "<?php list($a, $b) = $pair;")
+        .good_example("Short syntax", "<?php $map = ['a' => 1, 'b' => 2]; [$a, $b] = $pair;")
+        .fixable(SafetyClassification::Safe)
+    }
+}
+
+impl ShortArraySyntaxRule {
+    fn settings(context: &LintContext<'_>) -> ArraySyntaxSettings {
+        ArraySyntaxSettings { short_arrays: true, short_lists: context.php_version_is_at_least_71() }
+    }
+
+    fn report(
+        context: &mut LintContext<'_>,
+        subject: mago_span::Span,
+        spelling: &str,
+        edits: Vec<(mago_span::Span, String)>,
+    ) {
+        if edits.is_empty() {
+            return;
+        }
+
+        context.report_with_fix(
+            Issue::new(context.level(), format!("Use short array syntax instead of `{spelling}`."))
+                .with_annotation(Annotation::primary(subject).with_message("this spells `[...]` the long way"))
+                .with_help("`[...]` is the same construct; the formatter and fixer convert it in place."),
+            |mut plan| {
+                for (span, text) in edits {
+                    plan = plan.replace(span, text, SafetyClassification::Safe);
+                }
+                plan
+            },
+        );
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for ShortArraySyntaxRule {
+    fn walk_in_legacy_array(&self, array: &LegacyArray, context: &mut LintContext<'a>) {
+        let edits = edits_for_legacy_array(array, &Self::settings(context));
+        Self::report(context, array.array.join(array.right_parenthesis), "array()", edits);
+    }
+
+    fn walk_in_list(&self, list: &List, context: &mut LintContext<'a>) {
+        let edits = edits_for_list(list, &Self::settings(context));
+        Self::report(context, list.list.join(list.right_parenthesis), "list()", edits);
+    }
+}
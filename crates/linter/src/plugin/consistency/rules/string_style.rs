@@ -0,0 +1,237 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// Enforces one string-building style: interpolation (`"Hello {$name}"`) or
+/// concatenation (`'Hello ' . $name`), per the `style` option.
+///
+/// Only conversions that are provably meaning-preserving are offered as
+/// `Safe` fixes: the pieces must be string literals plus *simple*
+/// expressions (variables and property access chains). Method calls, and
+/// array access with quoted string keys — whose interpolation semantics
+/// changed across PHP versions — are left alone.
+#[derive(Clone, Debug)]
+pub struct StringStyleRule;
+
+impl Rule for StringStyleRule {
+    fn get_name(&self) -> &'static str {
+        "string-style"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        None
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new().string(
+            "style",
+            "\"\"",
+            "The enforced style: `interpolation` or `concatenation`. Unset (the default) disables the rule.",
+        )
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for StringStyleRule {
+    fn walk_in_binary(&self, binary: &Binary, context: &mut LintContext<'a>) {
+        if !matches!(context.option_str("style").as_deref(), Some("interpolation")) {
+            return;
+        }
+
+        if !matches!(binary.operator, BinaryOperator::StringConcat(_)) {
+            return;
+        }
+
+        // Only handle the outermost concatenation of a chain.
+        if context.parent_is_concatenation() {
+            return;
+        }
+
+        let Some(pieces) = flatten_concatenation(binary) else {
+            return;
+        };
+
+        let mut interpolated = String::from("\"");
+        for piece in &pieces {
+            match piece {
+                ConcatPiece::Literal(literal) => {
+                    let raw = context.lookup(&literal.value);
+                    let Some(decoded) = decode_quoted(raw) else {
+                        return;
+                    };
+
+                    interpolated.push_str(&escape_for_double_quotes(&decoded));
+                }
+                ConcatPiece::Simple(expression) => {
+                    let source = context.source_text(expression.span());
+                    interpolated.push('{');
+                    interpolated.push_str(source);
+                    interpolated.push('}');
+                }
+            }
+        }
+        interpolated.push('"');
+
+        let issue = Issue::new(context.level(), "Prefer string interpolation over concatenation.")
+            .with_annotation(Annotation::primary(binary.span()).with_message("this concatenation can be interpolated"))
+            .with_help("Rewrite as a double-quoted interpolated string.");
+
+        context.report_with_fix(issue, |plan| plan.replace(binary.span(), interpolated, SafetyClassification::Safe));
+    }
+
+    fn walk_in_composite_string(&self, string: &CompositeString, context: &mut LintContext<'a>) {
+        if !matches!(context.option_str("style").as_deref(), Some("concatenation")) {
+            return;
+        }
+
+        let CompositeString::Interpolated(interpolated) = string else {
+            return;
+        };
+
+        let mut parts: Vec<String> = Vec::new();
+        for part in interpolated.parts.iter() {
+            match part {
+                StringPart::Literal(literal) => {
+                    let raw = context.lookup(&literal.value);
+                    parts.push(format!("'{}'", escape_for_single_quotes(raw)));
+                }
+                StringPart::Expression(expression) if expression_is_simple(expression) => {
+                    parts.push(context.source_text(expression.span()).to_owned());
+                }
+                StringPart::BracedExpression(braced) if expression_is_simple(&braced.expression) => {
+                    parts.push(context.source_text(braced.expression.span()).to_owned());
+                }
+                _ => return,
+            }
+        }
+
+        if parts.iter().all(|part| part.starts_with('\'')) {
+            // A literal-only double-quoted string is a different rule's
+            // business; converting it here would just churn quotes.
+            return;
+        }
+
+        let concatenated = parts.join(" . ");
+        let issue = Issue::new(context.level(), "Prefer concatenation over string interpolation.")
+            .with_annotation(Annotation::primary(string.span()).with_message("this interpolation can be concatenated"))
+            .with_help("Rewrite as single-quoted literals joined with `.`.");
+
+        context.report_with_fix(issue, |plan| plan.replace(string.span(), concatenated, SafetyClassification::Safe));
+    }
+}
+
+enum ConcatPiece<'b> {
+    Literal(&'b LiteralString),
+    Simple(&'b Expression),
+}
+
+/// Flatten a `.` chain into pieces, or `None` if any piece is neither a
+/// string literal nor a simple interpolatable expression.
+fn flatten_concatenation(binary: &Binary) -> Option<Vec<ConcatPiece<'_>>> {
+    fn collect<'b>(expression: &'b Expression, pieces: &mut Vec<ConcatPiece<'b>>) -> bool {
+        match expression {
+            Expression::Binary(binary) if matches!(binary.operator, BinaryOperator::StringConcat(_)) => {
+                collect(&binary.lhs, pieces) && collect(&binary.rhs, pieces)
+            }
+            Expression::Literal(Literal::String(literal)) => {
+                pieces.push(ConcatPiece::Literal(literal));
+                true
+            }
+            expression if expression_is_simple(expression) => {
+                pieces.push(ConcatPiece::Simple(expression));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    let mut pieces = Vec::new();
+    let root = Expression::Binary(binary.clone());
+    if !collect(&root, &mut pieces) {
+        return None;
+    }
+
+    // Interpolation needs at least one literal and one expression to be
+    // worth converting.
+    let literals = pieces.iter().filter(|piece| matches!(piece, ConcatPiece::Literal(_))).count();
+    (literals > 0 && literals < pieces.len()).then_some(pieces)
+}
+
+/// Simple enough to interpolate inside `{...}` without semantic surprises:
+/// a variable or a property-access chain ending in plain names.
+fn expression_is_simple(expression: &Expression) -> bool {
+    match expression {
+        Expression::Variable(Variable::Direct(_)) => true,
+        Expression::Access(Access::Property(access)) => {
+            matches!(&access.property, ClassLikeMemberSelector::Identifier(_))
+                && expression_is_simple(&access.object)
+        }
+        _ => false,
+    }
+}
+
+/// Decode a quoted literal's raw text, or `None` when the literal uses
+/// escapes whose meaning we'd have to reinterpret (conservatively skipped).
+fn decode_quoted(raw: &str) -> Option<String> {
+    let body = raw.get(1..raw.len().checked_sub(1)?)?;
+    match raw.as_bytes().first() {
+        Some(b'\'') => Some(body.replace("\\\\", "\u{0}").replace("\\'", "'").replace('\u{0}', "\\")),
+        // Double-quoted bodies are already in double-quote semantics.
+        Some(b'"') => Some(body.to_owned()),
+        _ => None,
+    }
+}
+
+/// Escape decoded text for placement inside a double-quoted interpolated
+/// string: `"`, `$`, `{`, and `\` must not change meaning.
+fn escape_for_double_quotes(decoded: &str) -> String {
+    let mut escaped = String::with_capacity(decoded.len());
+    for character in decoded.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '$' => escaped.push_str("\\$"),
+            '{' => escaped.push_str("\\{"),
+            '\\' => escaped.push_str("\\\\"),
+            _ => escaped.push(character),
+        }
+    }
+
+    escaped
+}
+
+/// Escape literal-part text for a single-quoted string.
+fn escape_for_single_quotes(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_for_double_quotes_protects_metacharacters() {
+        assert_eq!(escape_for_double_quotes(r#"costs $5 "really" {sic}"#), r#"costs \$5 \"really\" \{sic}"#);
+        assert_eq!(escape_for_double_quotes(r"back\slash"), r"back\\slash");
+    }
+
+    #[test]
+    fn test_single_quote_decode_round_trip() {
+        // `'it\'s a \\ path'` decodes to `it's a \ path`, which re-encodes
+        // for single quotes identically.
+        let decoded = decode_quoted(r"'it\'s a \\ path'").unwrap();
+        assert_eq!(decoded, r"it's a \ path");
+        assert_eq!(escape_for_single_quotes(&decoded), r"it\'s a \\ path");
+    }
+
+    #[test]
+    fn test_double_quoted_bodies_pass_through() {
+        assert_eq!(decode_quoted(r#""a\tb""#).unwrap(), r"a\tb");
+    }
+}
@@ -0,0 +1,2 @@
+pub mod no_duplicate_conditions;
+pub mod no_unused_private_members;
@@ -0,0 +1,123 @@
+use mago_ast::*;
+use mago_ast_utils::structural_eq::structurally_equal;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_span::Span;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+/// Detects duplicated conditions across sibling `elseif` clauses, `switch`
+/// cases, and `match` arm condition lists.
+///
+/// `if ($x === 1) {...} elseif ($x === 1) {...}` is almost always a
+/// copy-paste bug: the later branch is unreachable. Conditions are compared
+/// with the span-insensitive structural equality helper, so parentheses and
+/// identifier casing don't hide duplicates. Identical consecutive bodies
+/// under *different* conditions get a separate, lower-severity hint to
+/// merge the conditions instead.
+#[derive(Clone, Debug)]
+pub struct NoDuplicateConditionsRule;
+
+impl Rule for NoDuplicateConditionsRule {
+    fn get_name(&self) -> &'static str {
+        "no-duplicate-conditions"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoDuplicateConditionsRule {
+    fn walk_in_if(&self, r#if: &If, context: &mut LintContext<'a>) {
+        let mut conditions: Vec<(&Expression, Span)> = vec![(&r#if.condition, r#if.condition.span())];
+        let mut bodies: Vec<(Node<'_>, Span)> = vec![(body_node(&r#if.body), r#if.body.span())];
+
+        for clause in r#if.body.else_if_clauses() {
+            check_condition(context, &mut conditions, &clause.condition);
+            check_consecutive_body(context, &mut bodies, Node::Statement(clause.statement()), clause.span());
+        }
+    }
+
+    fn walk_in_switch(&self, switch: &Switch, context: &mut LintContext<'a>) {
+        let mut conditions: Vec<(&Expression, Span)> = Vec::new();
+        for case in switch.body.cases() {
+            if let SwitchCase::Expression(case) = case {
+                check_condition(context, &mut conditions, &case.expression);
+            }
+        }
+    }
+
+    fn walk_in_match(&self, r#match: &Match, context: &mut LintContext<'a>) {
+        let mut conditions: Vec<(&Expression, Span)> = Vec::new();
+        for arm in r#match.arms.iter() {
+            let MatchArm::Expression(arm) = arm else {
+                continue;
+            };
+
+            for condition in arm.conditions.iter() {
+                check_condition(context, &mut conditions, condition);
+            }
+        }
+    }
+}
+
+fn check_condition<'b>(
+    context: &mut LintContext<'_>,
+    previous: &mut Vec<(&'b Expression, Span)>,
+    condition: &'b Expression,
+) {
+    for (earlier, earlier_span) in previous.iter() {
+        if structurally_equal(
+            context.interner,
+            Node::Expression(earlier),
+            Node::Expression(condition),
+        ) {
+            context.report(
+                Issue::new(context.level(), "Duplicated condition: this branch can never run.")
+                    .with_annotation(
+                        Annotation::primary(condition.span()).with_message("this condition repeats an earlier one"),
+                    )
+                    .with_annotation(
+                        Annotation::secondary(*earlier_span).with_message("first occurrence of the condition"),
+                    )
+                    .with_help("Remove the duplicate branch, or fix the condition it was copied from."),
+            );
+
+            return;
+        }
+    }
+
+    previous.push((condition, condition.span()));
+}
+
+fn check_consecutive_body<'b>(
+    context: &mut LintContext<'_>,
+    previous: &mut Vec<(Node<'b>, Span)>,
+    body: Node<'b>,
+    span: Span,
+) {
+    if let Some((last_body, last_span)) = previous.last() {
+        if structurally_equal(context.interner, *last_body, body) {
+            context.report(
+                Issue::note("Consecutive branches have identical bodies.")
+                    .with_annotation(Annotation::primary(span).with_message("this body repeats the previous branch"))
+                    .with_annotation(Annotation::secondary(*last_span).with_message("previous branch"))
+                    .with_help("Merge the conditions with `||` (or combine the `match` arms) instead of duplicating the body."),
+            );
+        }
+    }
+
+    previous.push((body, span));
+}
+
+fn body_node(body: &IfBody) -> Node<'_> {
+    match body {
+        IfBody::Statement(body) => Node::Statement(&body.statement),
+        IfBody::ColonDelimited(body) => Node::IfColonDelimitedBody(body),
+    }
+}
@@ -0,0 +1,308 @@
+use std::collections::HashSet;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// Reports private methods, properties, and class constants that are never
+/// referenced inside their declaring class.
+///
+/// `private` members are invisible to subclasses and to the outside world,
+/// so a member with zero in-class references is dead. The analysis is
+/// purely file-local and syntactic — only `$this->`, `self::`, `static::`
+/// and `new static` references count; string-based access (`$this->{$x}`,
+/// variable method names, reflection) disables the rule for the affected
+/// member kind via the magic-method escape hatches below.
+#[derive(Clone, Debug)]
+pub struct NoUnusedPrivateMembersRule;
+
+/// Magic methods whose presence means member access may happen dynamically.
+const MAGIC_PROPERTY_METHODS: &[&str] = &["__get", "__set", "__isset", "__unset"];
+const MAGIC_CALL_METHODS: &[&str] = &["__call", "__callstatic"];
+
+/// Serialization hooks are invoked by the engine, never by in-class code.
+const SERIALIZATION_METHODS: &[&str] =
+    &["__construct", "__destruct", "__clone", "__serialize", "__unserialize", "__sleep", "__wakeup", "__set_state", "__debuginfo", "__tostring", "__invoke"];
+
+impl Rule for NoUnusedPrivateMembersRule {
+    fn get_name(&self) -> &'static str {
+        "no-unused-private-members"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new().bool(
+            "ignore_magic_methods",
+            "false",
+            "Report private members even when `__call` / `__get` style magic methods are present.",
+        )
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoUnusedPrivateMembersRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let usage = collect_usage(context, class);
+
+        // Dynamic property access defeats the analysis for properties,
+        // dynamic calls defeat it for methods. Both are configurable so a
+        // codebase that knows its magic methods never touch private state
+        // can keep the rule on.
+        let has_magic_property_access = !context.option_bool("ignore_magic_methods").unwrap_or(false)
+            && class_has_method_in(context, class, MAGIC_PROPERTY_METHODS);
+        let has_magic_call = !context.option_bool("ignore_magic_methods").unwrap_or(false)
+            && class_has_method_in(context, class, MAGIC_CALL_METHODS);
+
+        for member in class.members.iter() {
+            match member {
+                ClassLikeMember::Method(method) if method.modifiers.contains_private() => {
+                    if has_magic_call || usage.dynamic_member_access {
+                        continue;
+                    }
+
+                    let name = context.lookup(&method.name.value).to_ascii_lowercase();
+                    if SERIALIZATION_METHODS.contains(&name.as_str()) {
+                        continue;
+                    }
+
+                    if usage.methods.contains(&name) || context.docblock_has_tag(method.span(), "@uses") {
+                        continue;
+                    }
+
+                    let display = context.lookup(&method.name.value);
+                    context.report_with_fix(
+                        Issue::new(context.level(), format!("Private method `{display}()` is never used."))
+                            .with_annotation(
+                                Annotation::primary(method.name.span()).with_message("this method has no callers"),
+                            )
+                            .with_help("Remove the method, or document dynamic usage with a `@uses` docblock tag."),
+                        |plan| plan.delete(method.span(), SafetyClassification::Unsafe),
+                    );
+                }
+                ClassLikeMember::Property(Property::Plain(property)) if property.modifiers.contains_private() => {
+                    if has_magic_property_access || usage.dynamic_member_access {
+                        continue;
+                    }
+
+                    for item in property.items.iter() {
+                        let name = context.lookup(&item.variable.name).trim_start_matches('$').to_owned();
+                        if usage.properties.contains(&name) || context.docblock_has_tag(property.span(), "@uses") {
+                            continue;
+                        }
+
+                        context.report(
+                            Issue::new(context.level(), format!("Private property `${name}` is never used."))
+                                .with_annotation(
+                                    Annotation::primary(item.variable.span())
+                                        .with_message("this property is never read or written"),
+                                )
+                                .with_help("Remove the property, or document dynamic usage with a `@uses` docblock tag."),
+                        );
+                    }
+                }
+                ClassLikeMember::Constant(constant) if constant.modifiers.contains_private() => {
+                    let name = context.lookup(&constant.item.name.value).to_owned();
+                    if usage.constants.contains(&name) || context.docblock_has_tag(constant.span(), "@uses") {
+                        continue;
+                    }
+
+                    context.report_with_fix(
+                        Issue::new(context.level(), format!("Private constant `{name}` is never used."))
+                            .with_annotation(
+                                Annotation::primary(constant.item.name.span())
+                                    .with_message("this constant is never referenced"),
+                            )
+                            .with_help("Remove the constant, or document dynamic usage with a `@uses` docblock tag."),
+                        |plan| plan.delete(constant.span(), SafetyClassification::Unsafe),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        report_constructor_only_promotions(context, class, &usage);
+    }
+}
+
+/// Every `$this->x`, `self::x` / `static::x`, and `new static` reference
+/// found in the class body, including inside closures (which inherit
+/// `$this` when declared in a method).
+#[derive(Default)]
+struct MemberUsage {
+    methods: HashSet<String>,
+    properties: HashSet<String>,
+    constants: HashSet<String>,
+    /// `$this->{$expr}` or `$this->$name` style access was seen; the
+    /// analysis cannot know which member it touches.
+    dynamic_member_access: bool,
+    /// Property names read or written outside the constructor.
+    properties_outside_constructor: HashSet<String>,
+}
+
+fn collect_usage(context: &LintContext<'_>, class: &Class) -> MemberUsage {
+    let mut usage = MemberUsage::default();
+
+    for member in class.members.iter() {
+        let ClassLikeMember::Method(method) = member else {
+            continue;
+        };
+        let MethodBody::Concrete(body) = &method.body else {
+            continue;
+        };
+
+        let in_constructor = context.lookup(&method.name.value).eq_ignore_ascii_case("__construct");
+        // Closures are walked too: a `function () { $this->x; }` declared
+        // inside a method is bound to the same `$this`. Static closures
+        // rebinding aside, counting them as usage is the conservative
+        // choice — it can only under-report, never false-positive.
+        let mut stack = vec![Node::Block(body)];
+        while let Some(node) = stack.pop() {
+            record_usage(context, node, in_constructor, &mut usage);
+            stack.extend(node.children());
+        }
+    }
+
+    usage
+}
+
+fn record_usage(context: &LintContext<'_>, node: Node<'_>, in_constructor: bool, usage: &mut MemberUsage) {
+    match node {
+        Node::Expression(Expression::Access(Access::Property(access))) => {
+            if !is_this(context, &access.object) {
+                return;
+            }
+
+            match &access.property {
+                ClassLikeMemberSelector::Identifier(name) => {
+                    let name = context.lookup(&name.value).to_owned();
+                    if !in_constructor {
+                        usage.properties_outside_constructor.insert(name.clone());
+                    }
+                    usage.properties.insert(name);
+                }
+                _ => usage.dynamic_member_access = true,
+            }
+        }
+        Node::Expression(Expression::Call(Call::Method(call))) => {
+            if !is_this(context, &call.object) {
+                return;
+            }
+
+            match &call.method {
+                ClassLikeMemberSelector::Identifier(name) => {
+                    usage.methods.insert(context.lookup(&name.value).to_ascii_lowercase());
+                }
+                _ => usage.dynamic_member_access = true,
+            }
+        }
+        Node::Expression(Expression::Call(Call::StaticMethod(call))) => {
+            if !is_self_or_static(&call.class) {
+                return;
+            }
+
+            match &call.method {
+                ClassLikeMemberSelector::Identifier(name) => {
+                    usage.methods.insert(context.lookup(&name.value).to_ascii_lowercase());
+                }
+                _ => usage.dynamic_member_access = true,
+            }
+        }
+        Node::Expression(Expression::Access(Access::ClassConstant(access))) => {
+            if !is_self_or_static(&access.class) {
+                return;
+            }
+
+            if let ClassLikeConstantSelector::Identifier(name) = &access.constant {
+                usage.constants.insert(context.lookup(&name.value).to_owned());
+            }
+        }
+        Node::Expression(Expression::Access(Access::StaticProperty(access))) => {
+            if !is_self_or_static(&access.class) {
+                return;
+            }
+
+            let name = context.lookup(&access.property.name).trim_start_matches('$').to_owned();
+            if !in_constructor {
+                usage.properties_outside_constructor.insert(name.clone());
+            }
+            usage.properties.insert(name);
+        }
+        _ => {}
+    }
+}
+
+/// Promoted private properties only touched inside the constructor get a
+/// dedicated message: demoting them to a plain parameter (local) removes
+/// per-instance state nobody reads.
+fn report_constructor_only_promotions(context: &mut LintContext<'_>, class: &Class, usage: &MemberUsage) {
+    for member in class.members.iter() {
+        let ClassLikeMember::Method(method) = member else {
+            continue;
+        };
+
+        if !context.lookup(&method.name.value).eq_ignore_ascii_case("__construct") {
+            continue;
+        }
+
+        for parameter in method.parameter_list.parameters.iter() {
+            if !parameter.is_promoted_property() || !parameter.modifiers.contains_private() {
+                continue;
+            }
+
+            let name = context.lookup(&parameter.variable.name).trim_start_matches('$').to_owned();
+            if usage.dynamic_member_access
+                || !usage.properties.contains(&name)
+                || usage.properties_outside_constructor.contains(&name)
+            {
+                // Completely unused promotions are caught by the plain
+                // property arm; here we only care about constructor-only
+                // usage.
+                continue;
+            }
+
+            context.report(
+                Issue::new(
+                    context.level(),
+                    format!("Promoted private property `${name}` is only used inside the constructor."),
+                )
+                .with_annotation(
+                    Annotation::primary(parameter.variable.span())
+                        .with_message("only the constructor reads this property"),
+                )
+                .with_help("Demote the parameter to a plain argument and use a local variable instead."),
+            );
+        }
+    }
+}
+
+fn class_has_method_in(context: &LintContext<'_>, class: &Class, names: &[&str]) -> bool {
+    class.members.iter().any(|member| match member {
+        ClassLikeMember::Method(method) => {
+            names.contains(&context.lookup(&method.name.value).to_ascii_lowercase().as_str())
+        }
+        _ => false,
+    })
+}
+
+fn is_this(context: &LintContext<'_>, expression: &Expression) -> bool {
+    matches!(expression, Expression::Variable(Variable::Direct(variable)) if context.lookup(&variable.name) == "$this")
+}
+
+/// `self::` and `static::` both resolve to the declaring class for
+/// *private* members: private symbols are not inherited, so even under
+/// `static::` in a subclass the engine binds to the class that declared
+/// them. Treating both as in-class usage is therefore exact, not an
+/// approximation.
+fn is_self_or_static(expression: &Expression) -> bool {
+    matches!(expression, Expression::Self_(_) | Expression::Static(_))
+}
@@ -0,0 +1,322 @@
+use mago_ast::*;
+use mago_ast_utils::string_literals::decode_literal;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+/// Validates literal `printf`-family format strings against the arguments
+/// actually passed.
+///
+/// Since PHP 8, too few arguments is an `ArgumentCountError` /
+/// `ValueError` at runtime; this catches it statically whenever the
+/// format is a literal (including concatenations of literals). Checked:
+/// the required argument count — positional (`%1$s`) placeholders count
+/// by their highest index — and the one type error visible syntactically,
+/// an array literal passed to a non-`%s`-escaping conversion. Invalid
+/// conversion specifiers get their own diagnostic. Dynamic format strings
+/// are out of scope. No autofix: only the author knows which side is
+/// wrong.
+#[derive(Clone, Debug)]
+pub struct FormatStringArgumentsRule;
+
+/// `(function name, index of the format argument, arguments-as-array)`.
+const FORMAT_FUNCTIONS: &[(&str, usize, bool)] = &[
+    ("sprintf", 0, false),
+    ("printf", 0, false),
+    ("fprintf", 1, false),
+    ("vsprintf", 0, true),
+    ("vprintf", 0, true),
+    ("sscanf", 1, false),
+];
+
+impl Rule for FormatStringArgumentsRule {
+    fn get_name(&self) -> &'static str {
+        "format-string-arguments"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for FormatStringArgumentsRule {
+    fn walk_in_function_call(&self, call: &FunctionCall, context: &mut LintContext<'a>) {
+        let Some(function_name) = context.resolve_function_name(&call.function) else {
+            return;
+        };
+
+        let Some((_, format_index, array_form)) =
+            FORMAT_FUNCTIONS.iter().find(|(name, _, _)| function_name.eq_ignore_ascii_case(name))
+        else {
+            return;
+        };
+
+        let arguments = call.argument_list.arguments.as_slice();
+        let Some(format_argument) = arguments.get(*format_index) else {
+            return;
+        };
+
+        let Some(format) = fold_literal_string(context, format_argument.value()) else {
+            return;
+        };
+
+        let placeholders = match parse_format(&format) {
+            Ok(placeholders) => placeholders,
+            Err(invalid) => {
+                context.report(
+                    Issue::new(
+                        context.level(),
+                        format!("Invalid conversion specifier `%{}` in format string.", invalid.specifier),
+                    )
+                    .with_annotation(
+                        Annotation::primary(format_argument.span()).with_message("this format string cannot be parsed"),
+                    )
+                    .with_help("Valid conversions are %b %c %d %e %E %f %F %g %G %o %s %u %x %X (and %% for a literal percent)."),
+                );
+                return;
+            }
+        };
+
+        let expected = required_argument_count(&placeholders);
+
+        if *array_form {
+            // `vsprintf($fmt, $values)`: only an array *literal* has a
+            // statically known length.
+            let Some(values) = arguments.get(format_index + 1) else {
+                return;
+            };
+            let Expression::Array(array) = values.value() else {
+                return;
+            };
+
+            let given = array.elements.len();
+            if given != expected {
+                report_count(context, format_argument, values.span(), expected, given);
+            }
+            return;
+        }
+
+        let value_arguments = &arguments[(*format_index + 1).min(arguments.len())..];
+        let given = value_arguments.len();
+
+        // `sscanf` with no out-parameters returns the matches instead.
+        if given == 0 && function_name.eq_ignore_ascii_case("sscanf") {
+            return;
+        }
+
+        if given != expected {
+            let span = call.argument_list.span();
+            report_count(context, format_argument, span, expected, given);
+            return;
+        }
+
+        // Arrays cannot be formatted by any conversion; PHP emits
+        // "Array to string conversion" at best.
+        for placeholder in &placeholders {
+            let index = placeholder.position.unwrap_or(placeholder.ordinal);
+            let Some(argument) = value_arguments.get(index) else {
+                continue;
+            };
+
+            if matches!(argument.value(), Expression::Array(_) | Expression::LegacyArray(_)) {
+                context.report(
+                    Issue::new(
+                        context.level(),
+                        format!("Array passed for conversion `%{}`.", placeholder.specifier),
+                    )
+                    .with_annotation(Annotation::primary(argument.span()).with_message("arrays cannot be formatted"))
+                    .with_annotation(
+                        Annotation::secondary(format_argument.span()).with_message("for this format string"),
+                    ),
+                );
+            }
+        }
+    }
+}
+
+fn report_count(
+    context: &mut LintContext<'_>,
+    format_argument: &Argument,
+    arguments_span: mago_span::Span,
+    expected: usize,
+    given: usize,
+) {
+    context.report(
+        Issue::new(context.level(), format!("Format expects {expected} arguments, {given} given."))
+            .with_annotation(
+                Annotation::primary(format_argument.span())
+                    .with_message(format!("this format string consumes {expected} arguments")),
+            )
+            .with_annotation(Annotation::secondary(arguments_span).with_message(format!("{given} provided here"))),
+    );
+}
+
+/// Fold an expression to a string when it is a literal or a concatenation
+/// of literals; anything dynamic yields `None`.
+fn fold_literal_string(context: &LintContext<'_>, expression: &Expression) -> Option<String> {
+    match expression {
+        Expression::Parenthesized(inner) => fold_literal_string(context, &inner.expression),
+        Expression::Literal(Literal::String(literal)) => Some(decode_literal(context.lookup(&literal.value))),
+        Expression::Binary(binary) if matches!(binary.operator, BinaryOperator::StringConcat(_)) => {
+            let mut folded = fold_literal_string(context, &binary.lhs)?;
+            folded.push_str(&fold_literal_string(context, &binary.rhs)?);
+            Some(folded)
+        }
+        _ => None,
+    }
+}
+
+/// One parsed `%` placeholder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Placeholder {
+    /// Zero-based index among non-positional placeholders, in order.
+    ordinal: usize,
+    /// Zero-based argument index from `%N$` syntax, when present.
+    position: Option<usize>,
+    specifier: char,
+}
+
+/// An unparseable conversion, with the offending character.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct InvalidSpecifier {
+    specifier: char,
+}
+
+const CONVERSIONS: &str = "bcdeEfFgGosuxX";
+
+/// Parse every placeholder out of `format`, handling `%%` escapes,
+/// positional `%1$s` syntax, flags (`-+ 0` and `'<pad>`), width, and
+/// `.precision`.
+fn parse_format(format: &str) -> Result<Vec<Placeholder>, InvalidSpecifier> {
+    let mut placeholders = Vec::new();
+    let mut ordinal = 0usize;
+    let mut characters = format.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        if character != '%' {
+            continue;
+        }
+
+        if characters.peek() == Some(&'%') {
+            characters.next();
+            continue;
+        }
+
+        // Optional `N$` positional prefix: digits followed by `$`.
+        let mut digits = String::new();
+        while characters.peek().is_some_and(|c| c.is_ascii_digit()) {
+            digits.push(characters.next().expect("peeked"));
+        }
+
+        let position = if characters.peek() == Some(&'$') {
+            characters.next();
+            let index: usize = digits.parse().ok().filter(|&n| n >= 1).ok_or(InvalidSpecifier { specifier: '$' })?;
+            Some(index - 1)
+        } else {
+            // The digits were a width; fall through to the specifier.
+            None
+        };
+
+        // Flags (after the position, if any).
+        loop {
+            match characters.peek() {
+                Some('-' | '+' | ' ' | '0') => {
+                    characters.next();
+                }
+                Some('\'') => {
+                    characters.next();
+                    characters.next(); // the custom padding character
+                }
+                _ => break,
+            }
+        }
+
+        // Width.
+        while characters.peek().is_some_and(|c| c.is_ascii_digit()) {
+            characters.next();
+        }
+
+        // Precision.
+        if characters.peek() == Some(&'.') {
+            characters.next();
+            while characters.peek().is_some_and(|c| c.is_ascii_digit()) {
+                characters.next();
+            }
+        }
+
+        let specifier = characters.next().ok_or(InvalidSpecifier { specifier: '\0' })?;
+        if !CONVERSIONS.contains(specifier) {
+            return Err(InvalidSpecifier { specifier });
+        }
+
+        let placeholder = Placeholder { ordinal, position, specifier };
+        if position.is_none() {
+            ordinal += 1;
+        }
+        placeholders.push(placeholder);
+    }
+
+    Ok(placeholders)
+}
+
+/// How many arguments the format consumes: the count of sequential
+/// placeholders, or the highest positional index if that is larger.
+fn required_argument_count(placeholders: &[Placeholder]) -> usize {
+    let sequential = placeholders.iter().filter(|placeholder| placeholder.position.is_none()).count();
+    let highest_positional =
+        placeholders.iter().filter_map(|placeholder| placeholder.position).map(|index| index + 1).max().unwrap_or(0);
+
+    sequential.max(highest_positional)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(format: &str) -> usize {
+        required_argument_count(&parse_format(format).expect("format must parse"))
+    }
+
+    #[test]
+    fn test_counts_sequential_placeholders() {
+        assert_eq!(count("%s and %d"), 2);
+        assert_eq!(count("no placeholders"), 0);
+    }
+
+    #[test]
+    fn test_double_percent_is_an_escape() {
+        assert_eq!(count("100%% of %d"), 1);
+    }
+
+    #[test]
+    fn test_positional_placeholders_count_by_highest_index() {
+        assert_eq!(count("%1$s %2$s %1$s"), 2);
+        assert_eq!(count("%3$s"), 3);
+    }
+
+    #[test]
+    fn test_flags_width_and_precision_are_consumed() {
+        assert_eq!(count("%-10s|%05d|%.2f|%'x8d"), 4);
+
+        let placeholders = parse_format("%.2f").expect("must parse");
+        assert_eq!(placeholders[0].specifier, 'f');
+    }
+
+    #[test]
+    fn test_invalid_specifier_is_reported() {
+        assert_eq!(parse_format("%y"), Err(InvalidSpecifier { specifier: 'y' }));
+        assert_eq!(parse_format("trailing %"), Err(InvalidSpecifier { specifier: '\0' }));
+    }
+
+    #[test]
+    fn test_width_digits_without_dollar_are_not_positional() {
+        let placeholders = parse_format("%10s").expect("must parse");
+        assert_eq!(placeholders[0].position, None);
+        assert_eq!(count("%10s"), 1);
+    }
+}
@@ -0,0 +1,9 @@
+pub mod format_string_arguments;
+pub mod no_confusing_generator_return;
+pub mod no_duplicate_enum_values;
+pub mod no_error_suppression;
+pub mod no_foreach_reference_leak;
+pub mod no_unescaped_output;
+pub mod override_attribute;
+pub mod require_parent_constructor_call;
+pub mod valid_preg_pattern;
@@ -0,0 +1,148 @@
+use mago_ast::*;
+use mago_ast_utils::control_flow::block_has_yield;
+use mago_ast_utils::control_flow::find_returns_in_block;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+/// Flags generator functions whose control flow suggests the author did
+/// not realize they were writing a generator.
+///
+/// Two shapes are reported:
+///
+/// - `yield` mixed with `return $value;` when nothing declares the
+///   function a generator (`Generator`/`iterable` hint or `@return`
+///   docblock). The returned value is only reachable via
+///   `Generator::getReturn()` — callers expecting "the array this function
+///   returns" silently get a generator instead.
+/// - `yield` inside a function whose return hint is `array` or a scalar,
+///   which PHP rejects at runtime ("generators cannot return values using
+///   `return`" / incompatible return type).
+///
+/// No autofix: which of the two intents was meant cannot be inferred.
+#[derive(Clone, Debug)]
+pub struct NoConfusingGeneratorReturnRule;
+
+/// Return hints under which a generator body is an outright runtime error.
+const NON_GENERATOR_HINTS: &[&str] = &["array", "int", "float", "string", "bool", "false", "true", "void", "never"];
+
+/// Return hints that declare generator semantics explicitly.
+const GENERATOR_HINTS: &[&str] = &["generator", "iterable", "traversable", "iterator"];
+
+impl Rule for NoConfusingGeneratorReturnRule {
+    fn get_name(&self) -> &'static str {
+        "no-confusing-generator-return"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoConfusingGeneratorReturnRule {
+    fn walk_in_function(&self, function: &Function, context: &mut LintContext<'a>) {
+        check_function_like(context, function.span(), function.return_type_hint.as_ref(), &function.body);
+    }
+
+    fn walk_in_method(&self, method: &Method, context: &mut LintContext<'a>) {
+        let MethodBody::Concrete(body) = &method.body else {
+            return;
+        };
+
+        check_function_like(context, method.span(), method.return_type_hint.as_ref(), body);
+    }
+
+    fn walk_in_closure(&self, closure: &Closure, context: &mut LintContext<'a>) {
+        check_function_like(context, closure.span(), closure.return_type_hint.as_ref(), &closure.body);
+    }
+}
+
+fn check_function_like(
+    context: &mut LintContext<'_>,
+    function_span: mago_span::Span,
+    return_hint: Option<&FunctionLikeReturnTypeHint>,
+    body: &Block,
+) {
+    // `block_has_yield` already ignores nested closures, so a closure
+    // returning from inside a generator (or vice versa) does not bleed
+    // into this function's classification.
+    if !block_has_yield(body) {
+        return;
+    }
+
+    let hint_name = return_hint.and_then(|hint| simple_hint_name(context, &hint.hint));
+
+    if let Some(name) = &hint_name {
+        if NON_GENERATOR_HINTS.contains(&name.as_str()) {
+            context.report(
+                Issue::new(
+                    context.level(),
+                    format!("This function contains `yield` but declares a `{name}` return type."),
+                )
+                .with_annotation(
+                    Annotation::primary(return_hint.expect("hint exists").hint.span())
+                        .with_message("a function that yields always returns a `Generator`"),
+                )
+                .with_note(
+                    "A single `yield` anywhere in the body turns the whole function into a generator; PHP rejects this declaration at runtime.",
+                )
+                .with_help("Change the return type to `Generator` (or `iterable`), or remove the `yield`."),
+            );
+
+            return;
+        }
+
+        if GENERATOR_HINTS.contains(&name.as_str()) {
+            // Explicitly declared generator: value-carrying returns are a
+            // deliberate `getReturn()` protocol.
+            return;
+        }
+    }
+
+    if context.docblock_simple_type(function_span, "@return")
+        .is_some_and(|doc| GENERATOR_HINTS.contains(&doc.trim_start_matches('\\').to_ascii_lowercase().as_str()))
+    {
+        return;
+    }
+
+    for r#return in find_returns_in_block(body) {
+        let Some(value) = &r#return.value else {
+            continue;
+        };
+
+        context.report(
+            Issue::new(
+                context.level(),
+                "This generator returns a value, but nothing declares it a generator.",
+            )
+            .with_annotation(
+                Annotation::primary(r#return.span())
+                    .with_message("this value is only reachable via `Generator::getReturn()`"),
+            )
+            .with_annotation(Annotation::secondary(value.span()).with_message("not the function's iteration result"))
+            .with_note(
+                "Because the body contains `yield`, calling this function produces a `Generator`; the `return` value is not what callers iterating the result will see.",
+            )
+            .with_help(
+                "If the return value is intentional, declare `Generator` as the return type (or add `@return Generator`); if you meant to return a collection, replace the `yield`s.",
+            ),
+        );
+    }
+}
+
+/// The lowercased name of a simple (single-identifier, possibly nullable)
+/// return hint; unions and intersections yield `None` and are left alone.
+fn simple_hint_name(context: &LintContext<'_>, hint: &Hint) -> Option<String> {
+    match hint {
+        Hint::Identifier(identifier) => {
+            Some(context.lookup_identifier(identifier).trim_start_matches('\\').to_ascii_lowercase())
+        }
+        Hint::Nullable(nullable) => simple_hint_name(context, &nullable.hint),
+        _ => None,
+    }
+}
@@ -0,0 +1,48 @@
+use mago_ast::*;
+use mago_ast_utils::enums::find_duplicate_enum_case_values;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::rule::Rule;
+
+/// Reports backed enum cases whose backing values are equal after
+/// constant folding.
+///
+/// PHP fatals with "duplicate value in enum" the moment such an enum is
+/// loaded, so this is never a style question. Folding lives in
+/// `mago_ast_utils::enums` and covers literals across notations (`1`
+/// versus `0x1`, `'x'` versus `"x"`); values it cannot fold are left
+/// alone. No autofix — which of the two cases is the wrong one is a
+/// judgement call.
+#[derive(Clone, Debug)]
+pub struct NoDuplicateEnumValuesRule;
+
+impl Rule for NoDuplicateEnumValuesRule {
+    fn get_name(&self) -> &'static str {
+        "no-duplicate-enum-values"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoDuplicateEnumValuesRule {
+    fn walk_in_enum(&self, r#enum: &Enum, context: &mut LintContext<'a>) {
+        let enum_name = context.lookup(&r#enum.name.value).to_owned();
+
+        for (first, duplicate) in find_duplicate_enum_case_values(context.interner(), r#enum) {
+            context.report(
+                Issue::new(context.level(), format!("Duplicate backing value in enum `{enum_name}`."))
+                    .with_annotation(
+                        Annotation::primary(duplicate).with_message("this case repeats an earlier backing value"),
+                    )
+                    .with_annotation(Annotation::secondary(first).with_message("first case with this value"))
+                    .with_help("Backed enum cases must have distinct values; PHP fatals on this at runtime."),
+            );
+        }
+    }
+}
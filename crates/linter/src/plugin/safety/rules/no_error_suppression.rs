@@ -0,0 +1,137 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// Reports every use of the `@` error-suppression operator.
+///
+/// Suppression hides the failure *and* costs time: PHP still builds the
+/// error, and debugging code that fails silently is expensive. Accepted
+/// idioms (`@unlink`, `@mkdir`, ...) can be allow-listed per function, and
+/// whole paths (e.g. legacy trees) can be exempted.
+#[derive(Clone, Debug)]
+pub struct NoErrorSuppressionRule;
+
+/// Functions with a well-known throwing alternative, suggested in a note;
+/// `json_decode`/`json_encode` additionally get a fix adding the flag.
+const THROWING_ALTERNATIVES: &[(&str, &str)] = &[
+    ("json_decode", "json_decode(..., JSON_THROW_ON_ERROR)"),
+    ("json_encode", "json_encode(..., JSON_THROW_ON_ERROR)"),
+    ("file_get_contents", "wrap the call and inspect error_get_last(), or use a filesystem abstraction that throws"),
+    ("simplexml_load_string", "libxml_use_internal_errors(true) with explicit error handling"),
+];
+
+impl Rule for NoErrorSuppressionRule {
+    fn get_name(&self) -> &'static str {
+        "no-error-suppression"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new()
+            .string_list(
+                "allow_functions",
+                "[]",
+                "Functions whose suppression is the accepted idiom (e.g. `unlink`, `mkdir`).",
+            )
+            .string_list(
+                "allow_in_paths",
+                "[]",
+                "Path globs (legacy trees, generated code) where suppression is not reported.",
+            )
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoErrorSuppressionRule {
+    fn walk_in_suppressed(&self, suppressed: &Suppressed, context: &mut LintContext<'a>) {
+        // Paths configured as allowed keep their suppressions (typically
+        // deprecation layers and generated code).
+        if context.current_path_matches_option_globs("allow_in_paths") {
+            return;
+        }
+
+        let called = called_function_name(context, &suppressed.expression);
+
+        if let Some(name) = called.as_deref() {
+            let allowed = context.option_string_list("allow_functions");
+            if allowed.iter().any(|function| function.eq_ignore_ascii_case(name)) {
+                return;
+            }
+        }
+
+        let mut issue = Issue::new(context.level(), "Avoid the `@` error-suppression operator.")
+            .with_annotation(
+                Annotation::primary(suppressed.at).with_message("this silences every error the expression raises"),
+            )
+            .with_note(
+                "Suppressed errors are still raised and formatted by the engine before being discarded, \
+                 so `@` costs time while hiding failures that later debugging has to rediscover.",
+            )
+            .with_help("Handle the failure explicitly, or allow-list this function via `allow_functions` if suppression is the accepted idiom.");
+
+        if let Some(name) = called.as_deref() {
+            if let Some((_, alternative)) =
+                THROWING_ALTERNATIVES.iter().find(|(function, _)| function.eq_ignore_ascii_case(name))
+            {
+                issue = issue.with_note(format!("`{name}` has a throwing alternative: {alternative}."));
+            }
+        }
+
+        // The JSON flag case is the only one with a mechanical fix: drop the
+        // `@` and pass JSON_THROW_ON_ERROR. Changing error behavior is
+        // deliberate here, hence `PotentiallyUnsafe`.
+        if let Some(last_argument) = json_call_without_flags(&suppressed.expression, called.as_deref()) {
+            // Anchor after the last argument, not before `)`, so a trailing
+            // comma (`json_decode($s,)`) stays behind the inserted flag.
+            let insertion_offset = last_argument.span().end.offset;
+            context.report_with_fix(issue, |plan| {
+                plan.delete(suppressed.at.span(), SafetyClassification::PotentiallyUnsafe).insert(
+                    insertion_offset,
+                    ", flags: JSON_THROW_ON_ERROR",
+                    SafetyClassification::PotentiallyUnsafe,
+                )
+            });
+        } else {
+            context.report(issue);
+        }
+    }
+}
+
+fn called_function_name(context: &LintContext<'_>, expression: &Expression) -> Option<String> {
+    let Expression::Call(Call::Function(call)) = expression else {
+        return None;
+    };
+
+    context.resolve_function_name(&call.function).map(|name| name.trim_start_matches('\\').to_owned())
+}
+
+/// The argument the JSON flag is appended after, when the call is a
+/// `json_decode`/`json_encode` without a flags argument yet.
+fn json_call_without_flags<'b>(expression: &'b Expression, name: Option<&str>) -> Option<&'b Argument> {
+    let name = name?;
+    if !name.eq_ignore_ascii_case("json_decode") && !name.eq_ignore_ascii_case("json_encode") {
+        return None;
+    }
+
+    let Expression::Call(Call::Function(call)) = expression else {
+        return None;
+    };
+
+    // Only offer the fix when no flags argument is present yet.
+    let maximum_arguments = if name.eq_ignore_ascii_case("json_decode") { 3 } else { 1 };
+    if call.argument_list.arguments.len() > maximum_arguments {
+        return None;
+    }
+
+    call.argument_list.arguments.as_slice().last()
+}
@@ -0,0 +1,195 @@
+use mago_ast::*;
+use mago_ast_utils::variables::find_variable_usages;
+use mago_fixer::SafetyClassification;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::documentation::RuleDocumentation;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// Detects `foreach ($a as &$v)` whose reference outlives the loop.
+///
+/// After a by-reference iteration, `$v` stays aliased to the last
+/// element; the next `$v = ...` or — the classic corruption — a later
+/// `foreach ($a as $v)` writes *through* the alias into the array. The
+/// rule flags a by-reference value target whose variable is used again
+/// after the loop without an intervening `unset($v)`, with a `Safe` fix
+/// inserting the `unset` directly after the loop.
+///
+/// When the variable is never used after the loop the alias is harmless
+/// today but a trap for the next edit; `hint_when_unused` reports those
+/// sites at `Note` level (without a fix — the better fix there is often
+/// dropping the `&`).
+///
+/// Only `foreach` statements that sit directly in a statement list are
+/// analyzed: a loop that is itself a branch body may never have run, and
+/// claiming a leak there would be a guess.
+#[derive(Clone, Debug)]
+pub struct NoForeachReferenceLeakRule;
+
+impl Rule for NoForeachReferenceLeakRule {
+    fn get_name(&self) -> &'static str {
+        "no-foreach-reference-leak"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new().bool(
+            "hint_when_unused",
+            "false",
+            "Also hint (at `Note` level) on by-reference targets whose variable is never used after the loop.",
+        )
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation::new(
+            "Reports `foreach ($a as &$v)` loops whose reference variable is used again after \
+             the loop without an `unset`.",
+            "After a by-reference iteration the loop variable stays aliased to the last array \
+             element. The next assignment to it — most famously a second, by-value `foreach` \
+             over the same array — writes through the alias and silently corrupts the array. \
+             `unset($v)` directly after the loop breaks the alias.",
+        )
+        .bad_example(
+            "Reference reused after the loop",
+            "<?php\nforeach ($items as &$item) { $item *= 2; }\nforeach ($items as $item) { echo $item; }",
+        )
+        .good_example(
+            "Alias broken before reuse",
+            "<?php\nforeach ($items as &$item) { $item *= 2; }\nunset($item);\nforeach ($items as $item) { echo $item; }",
+        )
+        .fixable(SafetyClassification::Safe)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoForeachReferenceLeakRule {
+    fn walk_in_foreach(&self, foreach: &Foreach, context: &mut LintContext<'a>) {
+        let value = match &foreach.target {
+            ForeachTarget::Value(target) => &target.value,
+            ForeachTarget::KeyValue(target) => &target.value,
+        };
+
+        let Expression::UnaryPrefix(unary) = value else {
+            return;
+        };
+        if !matches!(unary.operator, UnaryPrefixOperator::Reference(_)) {
+            return;
+        }
+        let Expression::Variable(Variable::Direct(variable)) = unary.operand.as_ref() else {
+            return;
+        };
+
+        let name = context.lookup(&variable.name).to_owned();
+
+        let Some(following) = statements_after_loop(context, foreach) else {
+            return;
+        };
+
+        for statement in following {
+            if unsets_variable(context, statement, &name) {
+                return;
+            }
+
+            let usages = find_variable_usages(context.interner, Node::Statement(statement), &name);
+            if let Some(first_usage) = usages.first() {
+                report_leak(context, foreach, variable, &name, *first_usage);
+                return;
+            }
+        }
+
+        if context.option_bool("hint_when_unused").unwrap_or(false) {
+            context.report(
+                Issue::new(
+                    Level::Note,
+                    format!("By-reference `foreach` value `{name}` stays aliased after the loop."),
+                )
+                .with_annotation(
+                    Annotation::primary(unary.span()).with_message("this reference outlives the loop"),
+                )
+                .with_help(format!(
+                    "Nothing uses `{name}` afterwards today, but any later use will see the alias; drop the `&` or `unset({name});` after the loop."
+                )),
+            );
+        }
+    }
+}
+
+/// The sibling statements after the `foreach`, when the loop is a direct
+/// child of the nearest statement list; `None` when it is a bare branch
+/// body or the list cannot be located.
+fn statements_after_loop<'b>(context: &LintContext<'b>, foreach: &Foreach) -> Option<Vec<&'b Statement>> {
+    let loop_span = foreach.span();
+
+    for ancestor in context.ancestors().iter().rev() {
+        let statements: Vec<&Statement> = match ancestor {
+            Node::Block(block) => block.statements.iter().collect(),
+            Node::Program(program) => program.statements.iter().collect(),
+            Node::Statement(Statement::Namespace(namespace)) => namespace.statements().as_slice().iter().collect(),
+            _ => continue,
+        };
+
+        // The loop must *be* one of the list's statements, not merely sit
+        // inside one (e.g. as an unbraced `if` body).
+        if !statements.iter().any(
+            |statement| matches!(statement, Statement::Foreach(_)) && statement.span() == loop_span,
+        ) {
+            return None;
+        }
+
+        return Some(
+            statements.into_iter().filter(|statement| statement.span().start.offset >= loop_span.end.offset).collect(),
+        );
+    }
+
+    None
+}
+
+/// Whether `statement` is an `unset(...)` naming `$name`.
+fn unsets_variable(context: &LintContext<'_>, statement: &Statement, name: &str) -> bool {
+    let Statement::Unset(unset) = statement else {
+        return false;
+    };
+
+    unset.values.iter().any(|value| {
+        matches!(value, Expression::Variable(Variable::Direct(variable)) if context.lookup(&variable.name) == name)
+    })
+}
+
+fn report_leak(
+    context: &mut LintContext<'_>,
+    foreach: &Foreach,
+    variable: &DirectVariable,
+    name: &str,
+    usage: mago_span::Span,
+) {
+    let issue = Issue::new(
+        context.level(),
+        format!("`{name}` is still a reference into the array after this loop."),
+    )
+    .with_annotation(Annotation::primary(variable.span()).with_message("iterated by reference here"))
+    .with_annotation(Annotation::secondary(usage).with_message("used again here through the leftover alias"))
+    .with_help(format!("`unset({name});` after the loop breaks the alias without touching the array."));
+
+    let loop_span = foreach.span();
+    let line_start = context.source_text_full()[..loop_span.start.offset].rfind('\n').map_or(0, |newline| newline + 1);
+    let indent = context.source_text_full()[line_start..loop_span.start.offset].to_owned();
+    if indent.chars().all(char::is_whitespace) {
+        context.report_with_fix(issue, |plan| {
+            plan.insert(loop_span.end.offset, format!("\n{indent}unset({name});"), SafetyClassification::Safe)
+        });
+    } else {
+        // The loop does not start its line; append on the same line
+        // rather than guessing indentation.
+        context.report_with_fix(issue, |plan| {
+            plan.insert(loop_span.end.offset, format!(" unset({name});"), SafetyClassification::Safe)
+        });
+    }
+}
@@ -0,0 +1,90 @@
+use mago_ast::*;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::classification::SourceClassification;
+use crate::context::LintContext;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// Requires `<?= ... ?>` output in templates to pass through an escape
+/// function.
+///
+/// Only runs on sources classified as templates: in scripts, echo
+/// statements routinely emit pre-escaped or non-HTML output, and the rule
+/// would be pure noise. Literals are exempt — `<?= 'label' ?>` cannot
+/// carry user input.
+#[derive(Clone, Debug)]
+pub struct NoUnescapedOutputRule;
+
+/// Escape functions accepted when the user configures none.
+const DEFAULT_ESCAPE_FUNCTIONS: &[&str] = &["htmlspecialchars", "htmlentities", "rawurlencode", "e"];
+
+impl Rule for NoUnescapedOutputRule {
+    fn get_name(&self) -> &'static str {
+        "no-unescaped-output"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new().string_list(
+            "escape_functions",
+            "[]",
+            "Escape functions accepted in template output; a non-empty list replaces the built-in defaults.",
+        )
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoUnescapedOutputRule {
+    fn walk_in_echo_tag(&self, echo_tag: &EchoTag, context: &mut LintContext<'a>) {
+        if context.classification != SourceClassification::Template {
+            return;
+        }
+
+        let configured = context.option_string_list("escape_functions");
+
+        for value in echo_tag.values.iter() {
+            if output_is_safe(context, value, &configured) {
+                continue;
+            }
+
+            context.report(
+                Issue::new(context.level(), "Template output is not escaped.")
+                    .with_annotation(
+                        Annotation::primary(value.span()).with_message("this value is echoed without escaping"),
+                    )
+                    .with_note("Unescaped values in templates are the classic cross-site-scripting vector.")
+                    .with_help(
+                        "Wrap the value in an escape function (`htmlspecialchars`, or one configured via `escape_functions`).",
+                    ),
+            );
+        }
+    }
+}
+
+fn output_is_safe(context: &LintContext<'_>, value: &Expression, configured: &[String]) -> bool {
+    match value {
+        Expression::Parenthesized(inner) => output_is_safe(context, &inner.expression, configured),
+        // Literals cannot carry user input.
+        Expression::Literal(_) => true,
+        Expression::Call(Call::Function(call)) => {
+            let Some(name) = context.resolve_function_name(&call.function) else {
+                return false;
+            };
+            let name = name.trim_start_matches('\\').to_ascii_lowercase();
+
+            if configured.is_empty() {
+                DEFAULT_ESCAPE_FUNCTIONS.contains(&name.as_str())
+            } else {
+                configured.iter().any(|function| function.trim_start_matches('\\').eq_ignore_ascii_case(&name))
+            }
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,177 @@
+use mago_ast::*;
+use mago_fixer::CleanupOptions;
+use mago_fixer::SafetyClassification;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// Checks `#[\Override]` (PHP 8.3) against the codebase index's
+/// inheritance information.
+///
+/// Two directions:
+///
+/// - a method carrying `#[\Override]` that overrides nothing is the exact
+///   error PHP throws at runtime, reported here statically with a `Safe`
+///   fix removing the attribute;
+/// - a method that *does* override a parent or interface declaration but
+///   lacks the attribute is reported only when `require` is enabled — a
+///   style choice — with a `Safe` fix inserting it. Insertion is skipped
+///   below PHP 8.3, where the attribute does not exist; removal still
+///   runs, since shipping it to an older target is itself the bug.
+///
+/// Traits are skipped entirely: whether a trait method overrides anything
+/// depends on the class that uses it, and PHP itself defers the check to
+/// the use site. Methods inherited *from* traits by the inspected class
+/// count as declarations the index resolves like any other. Abstract
+/// parent methods count as overridable declarations, matching the engine.
+#[derive(Clone, Debug)]
+pub struct OverrideAttributeRule;
+
+impl Rule for OverrideAttributeRule {
+    fn get_name(&self) -> &'static str {
+        "override-attribute"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new().bool(
+            "require",
+            "false",
+            "Also require `#[\\Override]` on every method that overrides a parent or interface method.",
+        )
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for OverrideAttributeRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let class_name = context.lookup(&class.name.value).to_owned();
+
+        for member in class.members.iter() {
+            let ClassLikeMember::Method(method) = member else {
+                continue;
+            };
+
+            // Private methods never participate in overriding, and PHP
+            // rejects the attribute on them accordingly.
+            if method.modifiers.contains_private() {
+                continue;
+            }
+
+            let method_name = context.lookup(&method.name.value).to_owned();
+
+            // `None` means the index does not know the full ancestry
+            // (e.g. the parent is outside the analyzed paths); stay
+            // silent rather than guess in either direction.
+            let Some(overrides) = context.codebase.method_overrides(&class_name, &method_name) else {
+                continue;
+            };
+
+            match (overrides, find_override_attribute(context, method)) {
+                (false, Some((list, attribute))) => report_superfluous(context, method, list, attribute),
+                (true, None) => {
+                    if context.php_version_is_at_least_83() && context.option_bool("require").unwrap_or(false) {
+                        report_missing(context, method);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// The attribute list and attribute spelling `\Override` (any
+/// qualification, any case), if the method carries one.
+fn find_override_attribute<'b>(
+    context: &LintContext<'_>,
+    method: &'b Method,
+) -> Option<(&'b AttributeList, &'b Attribute)> {
+    for attribute_list in method.attribute_lists.iter() {
+        for attribute in attribute_list.attributes.iter() {
+            let name = context.lookup(&attribute.name.value());
+            if name.trim_start_matches('\\').eq_ignore_ascii_case("Override") {
+                return Some((attribute_list, attribute));
+            }
+        }
+    }
+
+    None
+}
+
+fn report_superfluous(context: &mut LintContext<'_>, method: &Method, list: &AttributeList, attribute: &Attribute) {
+    let method_name = context.lookup(&method.name.value).to_owned();
+    let issue = Issue::new(
+        context.level(),
+        format!("`{method_name}()` is marked `#[\\Override]` but overrides nothing."),
+    )
+    .with_annotation(
+        Annotation::primary(attribute.span()).with_message("no parent or interface declares this method"),
+    )
+    .with_help("Remove the attribute, or rename the method if it was meant to override something.");
+
+    // Sole attribute in its group: remove the whole `#[...]` block and
+    // the line it occupied; otherwise remove just the attribute and the
+    // comma separating it from its neighbors.
+    if list.attributes.len() == 1 {
+        let span = list.span();
+        let options = CleanupOptions { remove_empty_line: true, ..CleanupOptions::default() };
+        let source = context.source_text_full().to_owned();
+        context.report_with_fix(issue, |plan| {
+            plan.delete_with_cleanup(span, &source, options, SafetyClassification::Safe)
+        });
+    } else {
+        let span = attribute.span();
+        let options = CleanupOptions { trim_comma: true, ..CleanupOptions::default() };
+        let source = context.source_text_full().to_owned();
+        context.report_with_fix(issue, |plan| {
+            plan.delete_with_cleanup(span, &source, options, SafetyClassification::Safe)
+        });
+    }
+}
+
+fn report_missing(context: &mut LintContext<'_>, method: &Method) {
+    let method_name = context.lookup(&method.name.value).to_owned();
+    let issue = Issue::new(
+        context.level(),
+        format!("`{method_name}()` overrides a parent method without `#[\\Override]`."),
+    )
+    .with_annotation(
+        Annotation::primary(method.name.span()).with_message("a parent or interface declares this method"),
+    )
+    .with_help("Add `#[\\Override]` so renaming the parent method becomes a compile-time error here.");
+
+    // Respect the method's existing grouping style: join an existing
+    // group when one is present, otherwise add a line of its own above
+    // the method (and above its other attribute lines, if any).
+    if let Some(first_list) = method.attribute_lists.first() {
+        let offset = first_list.span().start.offset + "#[".len();
+        context.report_with_fix(issue, |plan| {
+            plan.insert(offset, "\\Override, ", SafetyClassification::Safe)
+        });
+    } else {
+        let span = method.span();
+        let line_start = context.source_text_full()[..span.start.offset]
+            .rfind('\n')
+            .map_or(0, |newline| newline + 1);
+        let indent = context.source_text_full()[line_start..span.start.offset].to_owned();
+        if indent.chars().all(char::is_whitespace) {
+            context.report_with_fix(issue, |plan| {
+                plan.insert(line_start, format!("{indent}#[\\Override]\n"), SafetyClassification::Safe)
+            });
+        } else {
+            // The method does not start its line (generated code); keep
+            // the attribute inline rather than guessing indentation.
+            context.report_with_fix(issue, |plan| {
+                plan.insert(span.start.offset, "#[\\Override] ", SafetyClassification::Safe)
+            });
+        }
+    }
+}
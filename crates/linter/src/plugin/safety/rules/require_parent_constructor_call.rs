@@ -0,0 +1,165 @@
+use mago_ast::*;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// Flags child constructors that never call `parent::__construct()` when
+/// the parent class declares a constructor.
+///
+/// Defaults to a warning — frameworks sometimes skip chaining on purpose —
+/// and offers no autofix, since the argument mapping for the parent call
+/// cannot be derived mechanically.
+#[derive(Clone, Debug)]
+pub struct RequireParentConstructorCallRule;
+
+/// Internal classes whose constructors are documented as optional to chain.
+const PARENTS_NOT_REQUIRING_CHAIN: &[&str] = &["ArrayObject", "SplStack", "SplQueue", "Exception", "Error"];
+
+impl Rule for RequireParentConstructorCallRule {
+    fn get_name(&self) -> &'static str {
+        "require-parent-constructor-call"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new().string_list(
+            "ignore_extending",
+            "[]",
+            "Parent classes whose children may intentionally skip `parent::__construct()`.",
+        )
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for RequireParentConstructorCallRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let Some(extends) = &class.extends else {
+            return;
+        };
+
+        let Some(parent_name) = extends.types.first().map(|parent| context.lookup_identifier(parent)) else {
+            return;
+        };
+
+        // Annotated/configured as intentionally not chaining?
+        if context
+            .option_string_list("ignore_extending")
+            .iter()
+            .any(|ignored| ignored.trim_start_matches('\\').eq_ignore_ascii_case(parent_name.trim_start_matches('\\')))
+        {
+            return;
+        }
+
+        if PARENTS_NOT_REQUIRING_CHAIN.iter().any(|known| parent_name.trim_start_matches('\\').eq_ignore_ascii_case(known)) {
+            return;
+        }
+
+        // The index must know the parent and its constructor.
+        let Some(parent_constructor) = context.codebase.get_constructor(parent_name) else {
+            return;
+        };
+
+        // A private or bodiless (abstract) parent constructor cannot or
+        // need not be chained.
+        if parent_constructor.is_private() || parent_constructor.is_abstract() {
+            return;
+        }
+
+        let Some(constructor) = class.members.iter().find_map(|member| match member {
+            ClassLikeMember::Method(method)
+                if context.lookup(&method.name.value).eq_ignore_ascii_case("__construct") =>
+            {
+                Some(method)
+            }
+            _ => None,
+        }) else {
+            // No child constructor: the parent's runs implicitly.
+            return;
+        };
+
+        let MethodBody::Concrete(body) = &constructor.body else {
+            return;
+        };
+
+        if body_calls_parent_constructor(context, body) {
+            return;
+        }
+
+        // A child that explicitly assigns every parent-promoted property is
+        // deliberately replacing the parent's initialization.
+        let promoted = parent_constructor.promoted_property_names();
+        if !promoted.is_empty() && promoted.iter().all(|property| body_assigns_property(context, body, property)) {
+            return;
+        }
+
+        let child_name = context.lookup(&class.name.value);
+        context.report(
+            Issue::new(
+                context.level(),
+                format!("`{child_name}::__construct()` does not call `parent::__construct()`."),
+            )
+            .with_annotation(
+                Annotation::primary(constructor.name.span()).with_message("this constructor never chains to its parent"),
+            )
+            // Cross-file: the parent definition usually lives elsewhere.
+            .with_annotation(
+                Annotation::secondary(parent_constructor.definition_span())
+                    .with_message(format!("`{parent_name}::__construct()` is declared here")),
+            )
+            .with_help("Call `parent::__construct(...)`, or configure `ignore_extending` if skipping is intentional."),
+        );
+    }
+}
+
+fn body_calls_parent_constructor(context: &LintContext<'_>, body: &Block) -> bool {
+    let mut stack = vec![Node::Block(body)];
+    while let Some(node) = stack.pop() {
+        if let Node::Closure(_) | Node::ArrowFunction(_) | Node::AnonymousClass(_) = node {
+            continue;
+        }
+
+        if let Node::Expression(Expression::Call(Call::StaticMethod(call))) = node {
+            if let Expression::Parent(_) = call.class.as_ref() {
+                if let ClassLikeMemberSelector::Identifier(method) = &call.method {
+                    if context.lookup(&method.value).eq_ignore_ascii_case("__construct") {
+                        return true;
+                    }
+                }
+            }
+        }
+
+        stack.extend(node.children());
+    }
+
+    false
+}
+
+fn body_assigns_property(context: &LintContext<'_>, body: &Block, property: &str) -> bool {
+    let mut stack = vec![Node::Block(body)];
+    while let Some(node) = stack.pop() {
+        if let Node::Expression(Expression::Assignment(assignment)) = node {
+            if let Expression::Access(Access::Property(access)) = assignment.lhs.as_ref() {
+                if matches!(access.object.as_ref(), Expression::Variable(Variable::Direct(variable)) if context.lookup(&variable.name) == "$this")
+                {
+                    if let ClassLikeMemberSelector::Identifier(name) = &access.property {
+                        if context.lookup(&name.value) == property.trim_start_matches('$') {
+                            return true;
+                        }
+                    }
+                }
+            }
+        }
+
+        stack.extend(node.children());
+    }
+
+    false
+}
@@ -0,0 +1,107 @@
+use mago_ast::*;
+use mago_ast_utils::string_literals::decode_literal;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::pcre;
+use crate::rule::Rule;
+
+/// Validates literal patterns passed to the `preg_*` functions.
+///
+/// The check is `crate::pcre`'s lightweight syntax checker — delimiters,
+/// modifiers (including the removed `/e`), bracket balance — run over the
+/// *decoded* PHP string, so `"/\\d+/"` is seen as `/\d+/` the way PCRE
+/// will. Patterns built by concatenating dynamic values additionally get
+/// a hint to pass the dynamic part through `preg_quote()`: unescaped user
+/// input inside a pattern is both a correctness and an injection problem.
+#[derive(Clone, Debug)]
+pub struct ValidPregPatternRule;
+
+/// Functions whose first argument is a pattern.
+const PREG_FUNCTIONS: &[&str] =
+    &["preg_match", "preg_match_all", "preg_replace", "preg_replace_callback", "preg_split", "preg_grep"];
+
+impl Rule for ValidPregPatternRule {
+    fn get_name(&self) -> &'static str {
+        "valid-preg-pattern"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Error)
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for ValidPregPatternRule {
+    fn walk_in_function_call(&self, call: &FunctionCall, context: &mut LintContext<'a>) {
+        let Some(function_name) = context.resolve_function_name(&call.function) else {
+            return;
+        };
+
+        if !PREG_FUNCTIONS.iter().any(|preg| function_name.eq_ignore_ascii_case(preg)) {
+            return;
+        }
+
+        let Some(pattern_argument) = call.argument_list.arguments.as_slice().first() else {
+            return;
+        };
+
+        match pattern_argument.value() {
+            Expression::Literal(Literal::String(literal)) => {
+                let pattern = decode_literal(context.lookup(&literal.value));
+                if let Err(error) = pcre::check_pattern(&pattern) {
+                    context.report(
+                        Issue::new(context.level(), format!("Invalid regular expression: {error}."))
+                            .with_annotation(
+                                Annotation::primary(pattern_argument.span())
+                                    .with_message("this pattern will fail at runtime"),
+                            )
+                            .with_help("`preg_*` returns false and emits a warning on invalid patterns; fix the pattern here."),
+                    );
+                }
+            }
+            Expression::Binary(binary) if matches!(binary.operator, BinaryOperator::StringConcat(_)) => {
+                if concatenation_has_unquoted_dynamic_part(context, binary) {
+                    context.report(
+                        Issue::new(
+                            context.level().min(Level::Warning),
+                            "Dynamic value concatenated into a regex pattern.",
+                        )
+                        .with_annotation(
+                            Annotation::primary(pattern_argument.span())
+                                .with_message("part of this pattern comes from a runtime value"),
+                        )
+                        .with_help(
+                            "Wrap the dynamic part in `preg_quote($value, $delimiter)` so metacharacters and the delimiter are escaped.",
+                        ),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Whether a pattern concatenation includes a dynamic piece that is not
+/// already passed through `preg_quote()`.
+fn concatenation_has_unquoted_dynamic_part(context: &LintContext<'_>, binary: &Binary) -> bool {
+    let mut stack: Vec<&Expression> = vec![&binary.lhs, &binary.rhs];
+    while let Some(expression) = stack.pop() {
+        match expression {
+            Expression::Binary(inner) if matches!(inner.operator, BinaryOperator::StringConcat(_)) => {
+                stack.push(&inner.lhs);
+                stack.push(&inner.rhs);
+            }
+            Expression::Parenthesized(inner) => stack.push(&inner.expression),
+            Expression::Literal(_) => {}
+            Expression::Call(Call::Function(call))
+                if context.resolve_function_name(&call.function).is_some_and(|name| name.eq_ignore_ascii_case("preg_quote")) => {}
+            _ => return true,
+        }
+    }
+
+    false
+}
@@ -0,0 +1,188 @@
+use mago_ast::*;
+use mago_ast_utils::enclosing::enclosing_function_like;
+use mago_fixer::SafetyClassification;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// Reports `match` expressions over a known enum that neither cover every
+/// case nor have a `default` arm.
+///
+/// The subject's enum type is established syntactically — a parameter of
+/// the enclosing function typed with an enum the codebase index knows, or
+/// an `instanceof` guard on an enclosing `if` — so the rule under-reports
+/// rather than guessing from dataflow. Missing cases are listed by name,
+/// and the `Safe` fix appends one arm per missing case whose body comes
+/// from the `missing_arm_template` option (`{case}` expands to the
+/// qualified case). Unit and backed enums behave identically here, and an
+/// enum's interfaces are irrelevant: coverage is about cases, not
+/// methods.
+#[derive(Clone, Debug)]
+pub struct ExhaustiveEnumMatchRule;
+
+const DEFAULT_ARM_TEMPLATE: &str = "throw new \\LogicException('Unhandled case {case}')";
+
+impl Rule for ExhaustiveEnumMatchRule {
+    fn get_name(&self) -> &'static str {
+        "exhaustive-enum-match"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new().string(
+            "missing_arm_template",
+            "\"throw new \\\\LogicException('Unhandled case {case}')\"",
+            "Body of the arms the fix appends; `{case}` expands to the qualified case name.",
+        )
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for ExhaustiveEnumMatchRule {
+    fn walk_in_match(&self, r#match: &Match, context: &mut LintContext<'a>) {
+        // A default arm makes the match exhaustive by construction.
+        if r#match.arms.iter().any(|arm| matches!(arm, MatchArm::Default(_))) {
+            return;
+        }
+
+        let Some(enum_name) = subject_enum_name(context, r#match) else {
+            return;
+        };
+
+        let Some(cases) = context.codebase.get_enum_cases(&enum_name) else {
+            return;
+        };
+
+        let covered = covered_cases(context, r#match, &enum_name);
+        let missing: Vec<&str> =
+            cases.iter().map(String::as_str).filter(|case| !covered.iter().any(|c| c == case)).collect();
+        if missing.is_empty() {
+            return;
+        }
+
+        let template = context
+            .option_str("missing_arm_template")
+            .map(str::to_owned)
+            .unwrap_or_else(|| DEFAULT_ARM_TEMPLATE.to_owned());
+
+        let issue = Issue::new(
+            context.level(),
+            format!("Non-exhaustive match over enum `{enum_name}`: missing {}.", list_cases(&enum_name, &missing)),
+        )
+        .with_annotation(
+            Annotation::primary(r#match.r#match).with_message("not all enum cases are handled here"),
+        )
+        .with_help("Handle every case explicitly, or add a `default` arm if the remainder is truly uniform.");
+
+        // Append one arm per missing case just before the closing brace;
+        // unhandled values then fail loudly instead of hitting PHP's
+        // generic UnhandledMatchError without context.
+        let insert_at = r#match.right_brace.start.offset;
+        let mut arms = String::new();
+
+        // `match ($x) { Foo::A => 1 }` carries no trailing comma after the
+        // last arm; supply the separator ourselves when it is missing.
+        if let Some(last) = r#match.arms.iter().last() {
+            if !context.source_text_full()[last.span().end.offset..insert_at].contains(',') {
+                arms.push_str(", ");
+            }
+        }
+
+        for case in &missing {
+            let body = template.replace("{case}", &format!("{enum_name}::{case}"));
+            arms.push_str(&format!("{enum_name}::{case} => {body}, "));
+        }
+
+        context.report_with_fix(issue, |plan| plan.insert(insert_at, arms, SafetyClassification::Safe));
+    }
+}
+
+/// The names of the subject enum's cases this match already covers, via
+/// `Enum::Case` (or `self::Case` inside the enum itself) arm conditions.
+fn covered_cases(context: &LintContext<'_>, r#match: &Match, enum_name: &str) -> Vec<String> {
+    let mut covered = Vec::new();
+
+    for arm in r#match.arms.iter() {
+        let MatchArm::Expression(arm) = arm else {
+            continue;
+        };
+
+        for condition in arm.conditions.iter() {
+            let Expression::Access(Access::ClassConstant(access)) = condition else {
+                continue;
+            };
+
+            let matches_enum = match access.class.as_ref() {
+                Expression::Identifier(identifier) => {
+                    let name = context.lookup_identifier(identifier);
+                    name.trim_start_matches('\\').eq_ignore_ascii_case(enum_name.trim_start_matches('\\'))
+                }
+                Expression::Self_(_) | Expression::Static(_) => true,
+                _ => false,
+            };
+
+            if !matches_enum {
+                continue;
+            }
+
+            if let ClassLikeConstantSelector::Identifier(name) = &access.constant {
+                covered.push(context.lookup(&name.value).to_owned());
+            }
+        }
+    }
+
+    covered
+}
+
+/// The enum type of the match subject, when it can be read off the syntax.
+fn subject_enum_name(context: &LintContext<'_>, r#match: &Match) -> Option<String> {
+    let Expression::Variable(Variable::Direct(variable)) = r#match.expression.as_ref() else {
+        return None;
+    };
+    let variable_name = context.lookup(&variable.name).to_owned();
+
+    // An enclosing `if ($x instanceof Foo)` guard wins over weaker hints.
+    for ancestor in context.ancestors() {
+        let Node::Statement(Statement::If(r#if)) = ancestor else {
+            continue;
+        };
+
+        if let Expression::Binary(binary) = &r#if.condition {
+            if matches!(binary.operator, BinaryOperator::Instanceof(_)) {
+                if let (Expression::Variable(Variable::Direct(guarded)), Expression::Identifier(class)) =
+                    (binary.lhs.as_ref(), binary.rhs.as_ref())
+                {
+                    if context.lookup(&guarded.name) == variable_name {
+                        return Some(context.lookup_identifier(class).trim_start_matches('\\').to_owned());
+                    }
+                }
+            }
+        }
+    }
+
+    // A typed parameter of the enclosing function-like.
+    let function_like = enclosing_function_like(context.ancestors())?;
+    for parameter in function_like.parameter_list().parameters.iter() {
+        if context.lookup(&parameter.variable.name) != variable_name {
+            continue;
+        }
+
+        if let Some(Hint::Identifier(identifier)) = &parameter.hint {
+            return Some(context.lookup_identifier(identifier).trim_start_matches('\\').to_owned());
+        }
+    }
+
+    None
+}
+
+fn list_cases(enum_name: &str, missing: &[&str]) -> String {
+    missing.iter().map(|case| format!("`{enum_name}::{case}`")).collect::<Vec<_>>().join(", ")
+}
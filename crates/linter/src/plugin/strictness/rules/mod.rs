@@ -0,0 +1,4 @@
+pub mod exhaustive_enum_match;
+pub mod prefer_final_class;
+pub mod require_strict_comparison;
+pub mod require_types;
@@ -0,0 +1,133 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// Flags non-abstract, non-final classes, encouraging composition over
+/// inheritance. Opt-in.
+///
+/// The rule is inheritance-aware: classes the codebase index knows to be
+/// extended somewhere in the project are not flagged (an informational note
+/// is offered instead), and classes designed for extension can be excused
+/// via configuration.
+#[derive(Clone, Debug)]
+pub struct PreferFinalClassRule;
+
+/// Attributes that mark a class as externally instantiated/proxied, where
+/// `final` would break the consumer. Extendable via the
+/// `ignore_attributes` option.
+const DEFAULT_IGNORED_ATTRIBUTES: &[&str] = &["Doctrine\\ORM\\Mapping\\Entity", "ORM\\Entity", "Entity"];
+
+impl Rule for PreferFinalClassRule {
+    fn get_name(&self) -> &'static str {
+        "prefer-final-class"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        // Opt-in: enforcing `final` by default is a policy decision.
+        None
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new()
+            .string_list(
+                "ignore_attributes",
+                "[]",
+                "Attributes (beyond the built-in Doctrine entity list) marking classes that must stay extendable.",
+            )
+            .bool(
+                "allow_protected_api",
+                "false",
+                "Treat classes with protected members as intentionally designed for extension.",
+            )
+            .bool(
+                "treat_vendor_as_consumers",
+                "false",
+                "Count vendor subclasses as consumers rather than as proof the class must stay open.",
+            )
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for PreferFinalClassRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        if class.modifiers.contains_final() || class.modifiers.contains_abstract() {
+            return;
+        }
+
+        let name = context.lookup(&class.name.value);
+
+        // Skip classes marked by configured attributes (Doctrine entities
+        // and friends) — making those final breaks proxying.
+        let ignored_attributes = context.option_string_list("ignore_attributes");
+        for attribute_list in &class.attribute_lists {
+            for attribute in attribute_list.attributes.iter() {
+                let attribute_name = context.lookup(&attribute.name.value());
+                let attribute_name = attribute_name.trim_start_matches('\\');
+                if DEFAULT_IGNORED_ATTRIBUTES.iter().any(|ignored| attribute_name.ends_with(ignored))
+                    || ignored_attributes.iter().any(|ignored| attribute_name.eq_ignore_ascii_case(ignored))
+                {
+                    return;
+                }
+            }
+        }
+
+        // Skip classes with protected members when configured as
+        // intentionally-designed-for-extension.
+        if context.option_bool("allow_protected_api").unwrap_or(false)
+            && class.members.iter().any(has_protected_modifier)
+        {
+            return;
+        }
+
+        // Classes the index knows to be extended get a note, not a warning —
+        // unless vendor code is configured to count as a consumer rather
+        // than proof of extension.
+        let treat_vendor_as_consumers = context.option_bool("treat_vendor_as_consumers").unwrap_or(false);
+        if let Some(extenders) = context.codebase.get_direct_children(name) {
+            let project_extenders =
+                extenders.iter().filter(|child| !treat_vendor_as_consumers || !context.is_vendor_symbol(child)).count();
+
+            if project_extenders > 0 {
+                context.report(
+                    Issue::note(format!("Class `{name}` could be final, but it is extended in the project."))
+                        .with_annotation(Annotation::primary(class.name.span())),
+                );
+
+                return;
+            }
+        }
+
+        // Insert `final ` before `class`, but after attributes and before
+        // `readonly` so the modifier order stays canonical.
+        let insertion_offset = match class.modifiers.first() {
+            Some(modifier) => modifier.span().start.offset,
+            None => class.class.span().start.offset,
+        };
+
+        let issue = Issue::new(context.level(), format!("Class `{name}` should be final."))
+            .with_annotation(Annotation::primary(class.name.span()).with_message("this class is neither final nor abstract"))
+            .with_help("Add the `final` modifier, or mark the class `abstract` if it is designed for extension.");
+
+        context.report_with_fix(issue, |plan| {
+            plan.insert(insertion_offset, "final ", SafetyClassification::Safe)
+        });
+    }
+}
+
+fn has_protected_modifier(member: &ClassLikeMember) -> bool {
+    let modifiers = match member {
+        ClassLikeMember::Property(Property::Plain(property)) => &property.modifiers,
+        ClassLikeMember::Method(method) => &method.modifiers,
+        ClassLikeMember::Constant(constant) => &constant.modifiers,
+        _ => return false,
+    };
+
+    modifiers.contains_protected()
+}
@@ -0,0 +1,190 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// Flags loose equality (`==` / `!=`) and strictness-less `in_array` /
+/// `array_search` calls.
+///
+/// The upgrade fix is `PotentiallyUnsafe` in general — `'0' == false` is
+/// true while `'0' === false` is false — but becomes `Safe` when both
+/// operands are provably the same scalar type: two literals of one type, a
+/// `count()` call against an integer literal, or two comparison results.
+#[derive(Clone, Debug)]
+pub struct RequireStrictComparisonRule;
+
+/// Callbacks passed to these functions conventionally return -1/0/1 from
+/// subtraction or loose comparison; flagging inside them is noise.
+const SORT_CALLBACK_FUNCTIONS: &[&str] = &["usort", "uasort", "uksort", "array_udiff", "array_uintersect"];
+
+impl Rule for RequireStrictComparisonRule {
+    fn get_name(&self) -> &'static str {
+        "require-strict-comparison"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new().bool(
+            "allow_loose_null",
+            "false",
+            "Keep idiomatic `$x == null` / `$x != null` checks unreported.",
+        )
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for RequireStrictComparisonRule {
+    fn walk_in_binary(&self, binary: &Binary, context: &mut LintContext<'a>) {
+        let (loose, strict, operator_span) = match &binary.operator {
+            BinaryOperator::Equal(span) => ("==", "===", *span),
+            BinaryOperator::NotEqual(span) => ("!=", "!==", *span),
+            _ => return,
+        };
+
+        // `$x == null` is an idiomatic null check; keep it when configured.
+        if context.option_bool("allow_loose_null").unwrap_or(false)
+            && (is_null_literal(&binary.lhs) || is_null_literal(&binary.rhs))
+        {
+            return;
+        }
+
+        // Don't fire inside usort-style callbacks, whose contract is loose
+        // by convention.
+        if context
+            .nearest_enclosing_call_name()
+            .is_some_and(|name| SORT_CALLBACK_FUNCTIONS.iter().any(|f| name.eq_ignore_ascii_case(f)))
+        {
+            return;
+        }
+
+        let safety = if same_provable_scalar_type(&binary.lhs, &binary.rhs) {
+            SafetyClassification::Safe
+        } else {
+            // `'0' == false` is true but `'0' === false` is false; without
+            // type information the upgrade can change behavior.
+            SafetyClassification::PotentiallyUnsafe
+        };
+
+        let issue = Issue::new(context.level(), format!("Use `{strict}` instead of `{loose}`."))
+            .with_annotation(
+                Annotation::primary(operator_span).with_message(format!("`{loose}` compares after type juggling")),
+            )
+            .with_note(format!(
+                "Loose comparison applies type juggling: e.g. `'0' {loose} false` does not behave like `'0' {strict} false`.",
+            ))
+            .with_help(format!("Replace `{loose}` with `{strict}`, or cast the operands to a common type explicitly."));
+
+        context.report_with_fix(issue, |plan| plan.replace(operator_span, strict, safety));
+    }
+
+    fn walk_in_function_call(&self, call: &FunctionCall, context: &mut LintContext<'a>) {
+        let Some(name) = context.resolve_function_name(&call.function) else {
+            return;
+        };
+
+        if !name.eq_ignore_ascii_case("in_array") && !name.eq_ignore_ascii_case("array_search") {
+            return;
+        }
+
+        let arguments = call.argument_list.arguments.as_slice();
+        if arguments.len() != 2 {
+            return;
+        }
+
+        let issue = Issue::new(context.level(), format!("Call to `{name}` without the `$strict` argument."))
+            .with_annotation(
+                Annotation::primary(call.span()).with_message("this search compares loosely without `$strict`"),
+            )
+            .with_help(format!("Pass `true` as the third argument to make `{name}` use strict comparison."));
+
+        // Appending `, true` is only provably behavior-preserving when the
+        // haystack is a literal array of one scalar type.
+        let safety = if haystack_is_uniform_scalar_array(arguments.get(1)) {
+            SafetyClassification::Safe
+        } else {
+            SafetyClassification::PotentiallyUnsafe
+        };
+
+        // Anchor after the haystack rather than before `)`, so a trailing
+        // comma (`in_array($a, $b,)`) stays behind the inserted argument.
+        let insertion_offset = arguments[1].span().end.offset;
+        context.report_with_fix(issue, |plan| plan.insert(insertion_offset, ", true", safety));
+    }
+}
+
+fn is_null_literal(expression: &Expression) -> bool {
+    matches!(expression, Expression::Literal(Literal::Null(_)))
+}
+
+/// Whether both operands are provably of the same scalar type.
+fn same_provable_scalar_type(lhs: &Expression, rhs: &Expression) -> bool {
+    match (scalar_type_of(lhs), scalar_type_of(rhs)) {
+        (Some(left), Some(right)) => left == right,
+        _ => false,
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum ScalarType {
+    Int,
+    Float,
+    String,
+    Bool,
+}
+
+fn scalar_type_of(expression: &Expression) -> Option<ScalarType> {
+    Some(match expression {
+        Expression::Literal(Literal::Integer(_)) => ScalarType::Int,
+        Expression::Literal(Literal::Float(_)) => ScalarType::Float,
+        Expression::Literal(Literal::String(_)) => ScalarType::String,
+        Expression::Literal(Literal::True(_) | Literal::False(_)) => ScalarType::Bool,
+        Expression::Parenthesized(inner) => scalar_type_of(&inner.expression)?,
+        // Comparison results are always booleans.
+        Expression::Binary(binary) if binary.operator.is_comparison() => ScalarType::Bool,
+        // `count()` always yields an int.
+        Expression::Call(Call::Function(call)) => {
+            let Expression::Identifier(identifier) = call.function.as_ref() else {
+                return None;
+            };
+
+            if identifier.is_unqualified_named_ci("count") { ScalarType::Int } else { return None }
+        }
+        _ => return None,
+    })
+}
+
+/// Whether the haystack argument is a literal array whose values are all
+/// literals of one scalar type.
+fn haystack_is_uniform_scalar_array(argument: Option<&Argument>) -> bool {
+    let Some(Expression::Array(array)) = argument.map(Argument::value) else {
+        return false;
+    };
+
+    let mut element_type: Option<ScalarType> = None;
+    for element in array.elements.iter() {
+        let ArrayElement::Value(value) = element else {
+            return false;
+        };
+
+        let Some(current) = scalar_type_of(&value.value) else {
+            return false;
+        };
+
+        match element_type {
+            None => element_type = Some(current),
+            Some(existing) if existing == current => {}
+            Some(_) => return false,
+        }
+    }
+
+    element_type.is_some()
+}
@@ -0,0 +1,178 @@
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::options::OptionsSchema;
+use crate::rule::Rule;
+
+/// Requires native type declarations on class properties and on function
+/// parameters.
+///
+/// Options:
+///
+/// - `accept_docblock`: a `@var` / `@param` docblock counts as typed;
+/// - `public_only`: skip protected and private members;
+/// - `exempt_extends`: base classes (legacy ORMs) whose subclasses are
+///   exempt entirely.
+///
+/// Constructor-promoted properties are parameters syntactically, so they
+/// are checked once through their parameter form and skipped when visiting
+/// properties would double-report them.
+#[derive(Clone, Debug)]
+pub struct RequireTypesRule;
+
+impl Rule for RequireTypesRule {
+    fn get_name(&self) -> &'static str {
+        "require-types"
+    }
+
+    fn get_default_level(&self) -> Option<Level> {
+        Some(Level::Warning)
+    }
+
+    fn runs_on_templates(&self) -> bool {
+        false
+    }
+
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new()
+            .bool(
+                "accept_docblock",
+                "false",
+                "Treat `@var` / `@param` / `@return` docblock types as satisfying the requirement.",
+            )
+            .bool("public_only", "false", "Only check public properties and promoted properties.")
+            .string_list(
+                "exempt_extends",
+                "[]",
+                "Base classes whose subclasses are exempt entirely (e.g. legacy ORM entities).",
+            )
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for RequireTypesRule {
+    fn walk_in_plain_property(&self, property: &PlainProperty, context: &mut LintContext<'a>) {
+        if property.hint.is_some() {
+            return;
+        }
+
+        if context.option_bool("public_only").unwrap_or(false) && !property.modifiers.contains_public_visibility() {
+            return;
+        }
+
+        if class_is_exempt(context) {
+            return;
+        }
+
+        if context.option_bool("accept_docblock").unwrap_or(false) && context.docblock_has_tag(property.span(), "@var")
+        {
+            return;
+        }
+
+        let Some(item) = property.items.first() else {
+            return;
+        };
+
+        let variable_name = context.lookup(&item.variable().name);
+        let issue = Issue::new(context.level(), format!("Property `{variable_name}` has no type declaration."))
+            .with_annotation(Annotation::primary(item.variable().span()).with_message("declare a native type for this property"))
+            .with_help("Add a native property type; untyped properties accept any value and default to null.");
+
+        // A scalar default or a simple docblock type gives us a hint to
+        // insert; anything else is report-only.
+        let inferred = match item {
+            PropertyItem::Concrete(item) => inferred_scalar_hint(&item.value),
+            PropertyItem::Abstract(_) => None,
+        }
+        .or_else(|| context.docblock_simple_type(property.span(), "@var"));
+
+        match inferred {
+            Some(hint) => {
+                let insertion_offset = item.variable().span().start.offset;
+                context.report_with_fix(issue, |plan| {
+                    plan.insert(insertion_offset, format!("{hint} "), SafetyClassification::PotentiallyUnsafe)
+                });
+            }
+            None => context.report(issue),
+        }
+    }
+
+    fn walk_in_function_like_parameter(&self, parameter: &FunctionLikeParameter, context: &mut LintContext<'a>) {
+        if parameter.hint.is_some() {
+            return;
+        }
+
+        // Promoted properties are checked here, in parameter form, exactly
+        // once; visibility options still apply to them.
+        let promoted = parameter.is_promoted_property();
+        if promoted
+            && context.option_bool("public_only").unwrap_or(false)
+            && !parameter.modifiers.contains_public_visibility()
+        {
+            return;
+        }
+
+        if class_is_exempt(context) {
+            return;
+        }
+
+        if context.option_bool("accept_docblock").unwrap_or(false)
+            && context.docblock_has_tag(parameter.span(), "@param")
+        {
+            return;
+        }
+
+        let variable_name = context.lookup(&parameter.variable.name);
+        let what = if promoted { "Promoted property" } else { "Parameter" };
+        let issue = Issue::new(context.level(), format!("{what} `{variable_name}` has no type declaration."))
+            .with_annotation(Annotation::primary(parameter.variable.span()).with_message("declare a native type"))
+            .with_help("Add a native parameter type so invalid arguments fail at the boundary.");
+
+        let inferred = parameter
+            .default_value
+            .as_ref()
+            .and_then(|default| inferred_scalar_hint(&default.value))
+            .or_else(|| context.docblock_simple_type(parameter.span(), "@param"));
+
+        match inferred {
+            Some(hint) => {
+                let insertion_offset = parameter.variable.span().start.offset;
+                context.report_with_fix(issue, |plan| {
+                    plan.insert(insertion_offset, format!("{hint} "), SafetyClassification::PotentiallyUnsafe)
+                });
+            }
+            None => context.report(issue),
+        }
+    }
+}
+
+fn class_is_exempt(context: &LintContext<'_>) -> bool {
+    let exempt = context.option_string_list("exempt_extends");
+    if exempt.is_empty() {
+        return false;
+    }
+
+    context.enclosing_class_parents().iter().any(|parent| {
+        let parent = parent.trim_start_matches('\\');
+        exempt.iter().any(|base| parent.eq_ignore_ascii_case(base.trim_start_matches('\\')))
+    })
+}
+
+/// The native hint implied by a scalar default value, nullable for `null`.
+fn inferred_scalar_hint(default: &Expression) -> Option<String> {
+    Some(match default {
+        Expression::Literal(Literal::Integer(_)) => "int".to_owned(),
+        Expression::Literal(Literal::Float(_)) => "float".to_owned(),
+        Expression::Literal(Literal::String(_)) => "string".to_owned(),
+        Expression::Literal(Literal::True(_) | Literal::False(_)) => "bool".to_owned(),
+        Expression::Array(_) | Expression::LegacyArray(_) => "array".to_owned(),
+        // A null default alone names no type; the docblock may. Callers
+        // combine this with docblock information before giving up.
+        _ => return None,
+    })
+}
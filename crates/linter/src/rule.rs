@@ -0,0 +1,48 @@
+use mago_reporting::Level;
+
+use crate::documentation::RuleDocumentation;
+use crate::options::OptionsSchema;
+
+/// A lint rule: its identity, default severity, and which sources it
+/// applies to. Traversal behavior lives in the rule's `Walker` impl.
+pub trait Rule {
+    /// The kebab-case rule name used in configuration and `@mago-ignore`.
+    fn get_name(&self) -> &'static str;
+
+    /// The level the rule reports at when the user has not configured one;
+    /// `None` disables the rule by default.
+    fn get_default_level(&self) -> Option<Level>;
+
+    /// The rule's option schema: every key it reads, with type, default,
+    /// and description.
+    ///
+    /// Configuration validation rejects keys and types outside the schema
+    /// (with a "did you mean" suggestion for near-misses), and
+    /// `mago lint --explain <rule>` and the documentation generator render
+    /// it — so reading an undeclared option is a bug, not a feature.
+    fn get_options(&self) -> OptionsSchema {
+        OptionsSchema::new()
+    }
+
+    /// The rule's user-facing documentation: summary, rationale, code
+    /// examples, fix availability, and version requirements.
+    ///
+    /// `mago lint --explain <rule>` and `mago docs generate` render it,
+    /// and the registry test harness parses every example and asserts the
+    /// reported/accepted claims against the rule itself. The stub default
+    /// exists only for rules that predate structured documentation; new
+    /// rules must override it.
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation::undocumented()
+    }
+
+    /// Whether the rule runs on sources classified as templates (see
+    /// [`crate::classification::SourceClassification`]).
+    ///
+    /// Defaults to `true`; rules whose assumptions break on mixed
+    /// HTML/PHP files — side-effect analysis, strict-types requirements —
+    /// override this to `false` and are skipped for template files.
+    fn runs_on_templates(&self) -> bool {
+        true
+    }
+}
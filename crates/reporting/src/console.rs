@@ -0,0 +1,176 @@
+//! Plain-text console rendering.
+//!
+//! Annotations are grouped by file: the primary annotation's file is
+//! rendered first, and every other file referenced by a secondary label
+//! gets its own code frame headed by that file's path. This is how
+//! cross-file diagnostics ("declared here", in another file) stay
+//! readable without interleaving unrelated sources.
+
+use mago_span::FileId;
+
+use crate::Annotation;
+use crate::AnnotationKind;
+use crate::Issue;
+use crate::Level;
+use crate::SourceAccess;
+
+/// Render a single issue as plain text.
+pub fn render(issue: &Issue, sources: &dyn SourceAccess) -> String {
+    let mut out = String::new();
+
+    out.push_str(level_name(issue.level));
+    if let Some(code) = &issue.code {
+        out.push_str(&format!("[{code}]"));
+    }
+    out.push_str(": ");
+    out.push_str(&issue.message);
+    out.push('\n');
+
+    for file_id in files_in_order(issue) {
+        render_frame(&mut out, issue, file_id, sources);
+    }
+
+    for note in &issue.notes {
+        out.push_str(&format!("  = note: {note}\n"));
+    }
+
+    if let Some(help) = &issue.help {
+        out.push_str(&format!("  = help: {help}\n"));
+    }
+
+    out
+}
+
+/// The files referenced by the issue's annotations: the primary file
+/// first, then each additional file in order of first appearance.
+fn files_in_order(issue: &Issue) -> Vec<FileId> {
+    let mut files = Vec::new();
+    if let Some(span) = issue.primary_span() {
+        files.push(span.file_id());
+    }
+
+    for annotation in &issue.annotations {
+        let file_id = annotation.span.file_id();
+        if !files.contains(&file_id) {
+            files.push(file_id);
+        }
+    }
+
+    files
+}
+
+fn render_frame(out: &mut String, issue: &Issue, file_id: FileId, sources: &dyn SourceAccess) {
+    let content = sources.content(file_id);
+    let annotations: Vec<&Annotation> =
+        issue.annotations.iter().filter(|annotation| annotation.span.file_id() == file_id).collect();
+
+    let Some(first) = annotations.first() else {
+        return;
+    };
+
+    let (line, column) = line_column(content, first.span.start.offset);
+    out.push_str(&format!("  --> {}:{}:{}\n", sources.path(file_id), line + 1, column + 1));
+
+    for annotation in annotations {
+        let (line, _) = line_column(content, annotation.span.start.offset);
+        let text = line_text(content, line);
+
+        out.push_str(&format!("{:>4} | {}\n", line + 1, text));
+
+        let line_start = content[..annotation.span.start.offset].rfind('\n').map_or(0, |index| index + 1);
+        let indent = annotation.span.start.offset - line_start;
+        let width = annotation.span.length().clamp(1, text.len().saturating_sub(indent).max(1));
+        let marker = if annotation.kind == AnnotationKind::Primary { '^' } else { '-' };
+
+        out.push_str(&format!("     | {}{}", " ".repeat(indent), marker.to_string().repeat(width)));
+        if let Some(message) = &annotation.message {
+            out.push_str(&format!(" {message}"));
+        }
+        out.push('\n');
+    }
+}
+
+fn line_column(content: &str, offset: usize) -> (usize, usize) {
+    let prefix = &content[..offset.min(content.len())];
+    let line = prefix.matches('\n').count();
+    let column = offset - prefix.rfind('\n').map_or(0, |index| index + 1);
+
+    (line, column)
+}
+
+fn line_text(content: &str, line: usize) -> &str {
+    content.lines().nth(line).unwrap_or("")
+}
+
+fn level_name(level: Level) -> &'static str {
+    match level {
+        Level::Error => "error",
+        Level::Warning => "warning",
+        Level::Note => "note",
+        Level::Help => "help",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_span::Position;
+    use mago_span::Span;
+
+    use super::*;
+
+    struct TwoFiles;
+
+    impl SourceAccess for TwoFiles {
+        fn path(&self, file_id: FileId) -> &str {
+            match file_id.0 {
+                0 => "src/Child.php",
+                _ => "vendor/lib/Base.php",
+            }
+        }
+
+        fn content(&self, file_id: FileId) -> &str {
+            match file_id.0 {
+                0 => "<?php\nclass Child extends Base {}\n",
+                _ => "<?php\nclass Base {\n    public function __construct() {}\n}\n",
+            }
+        }
+    }
+
+    fn span(file: u32, start: usize, end: usize) -> Span {
+        Span {
+            start: Position { file_id: FileId(file), offset: start, line: 0 },
+            end: Position { file_id: FileId(file), offset: end, line: 0 },
+        }
+    }
+
+    #[test]
+    fn test_secondary_label_in_vendor_file_gets_its_own_frame() {
+        let issue = Issue::warning("constructor is never chained")
+            .with_annotation(Annotation::primary(span(0, 12, 17)).with_message("this class"))
+            .with_annotation(Annotation::secondary(span(1, 37, 48)).with_message("declared here"));
+
+        let rendered = render(&issue, &TwoFiles);
+
+        // Both frames appear, primary file first.
+        let child = rendered.find("src/Child.php").expect("primary frame");
+        let vendor = rendered.find("vendor/lib/Base.php").expect("secondary frame");
+        assert!(child < vendor);
+        assert!(rendered.contains("declared here"));
+    }
+
+    #[test]
+    fn test_sorting_is_keyed_by_primary_file() {
+        let mut issues = vec![
+            Issue::warning("b").with_annotation(Annotation::primary(span(1, 0, 1))),
+            Issue::warning("a")
+                .with_annotation(Annotation::primary(span(0, 5, 6)))
+                // A secondary label in a later file must not move the issue.
+                .with_annotation(Annotation::secondary(span(2, 0, 1))),
+        ];
+
+        crate::sort_issues(&mut issues);
+
+        assert_eq!(issues[0].message, "a");
+        assert_eq!(issues[1].message, "b");
+    }
+}
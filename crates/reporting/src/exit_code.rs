@@ -0,0 +1,137 @@
+use crate::Issue;
+use crate::Level;
+
+/// The process exit codes every `mago` command maps its outcome onto.
+///
+/// The contract, which CI pipelines are entitled to depend on:
+///
+/// - `0` — the run completed and nothing failed the configured threshold.
+///   `mago lint --fix` exits `0` when every reported issue was fixed;
+///   diagnostics that existed but were repaired do not fail the run.
+/// - `1` — the run completed and diagnostics at or above the failing
+///   severity remain. For `mago fmt --check` this means "changes would be
+///   made". The code is computed from the *final* diagnostic set: after
+///   `@mago-ignore` suppression, after baseline subtraction, and after any
+///   diff filter — a diagnostic the user never sees never fails the run.
+/// - `2` — the run could not complete: invalid configuration, unreadable
+///   input, an unwritable output path. Diagnostics that may have been
+///   collected before the failure do not upgrade or downgrade this.
+/// - `3` — an internal error (a panic caught at the top level). Exit `3`
+///   is always a mago bug worth reporting, never a problem with the code
+///   being analyzed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ExitCode {
+    Success = 0,
+    FailingDiagnostics = 1,
+    OperationalError = 2,
+    InternalError = 3,
+}
+
+impl From<ExitCode> for std::process::ExitCode {
+    fn from(code: ExitCode) -> Self {
+        std::process::ExitCode::from(code as u8)
+    }
+}
+
+/// Which diagnostics fail the run; the CLI builds this from `--error-on`
+/// and `--warnings-as-errors`.
+///
+/// `--error-on=error` (the default) fails only on errors;
+/// `--error-on=warning` also fails on warnings. `--warnings-as-errors` is
+/// shorthand for the latter kept for familiarity — the two flags name the
+/// same threshold and combine idempotently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExitPolicy {
+    failing_level: Level,
+}
+
+impl Default for ExitPolicy {
+    fn default() -> Self {
+        Self { failing_level: Level::Error }
+    }
+}
+
+impl ExitPolicy {
+    /// Parse an `--error-on` value. Only `warning` and `error` are
+    /// accepted: notes and help texts are advisory by design and cannot be
+    /// made failing.
+    pub fn from_error_on(value: &str) -> Option<Self> {
+        match value {
+            "error" => Some(Self { failing_level: Level::Error }),
+            "warning" => Some(Self { failing_level: Level::Warning }),
+            _ => None,
+        }
+    }
+
+    /// Apply `--warnings-as-errors` on top of whatever `--error-on` chose.
+    pub fn with_warnings_as_errors(self) -> Self {
+        Self { failing_level: self.failing_level.min(Level::Warning) }
+    }
+
+    /// Whether a diagnostic at `level` fails the run under this policy.
+    pub fn fails_on(&self, level: Level) -> bool {
+        level >= self.failing_level
+    }
+
+    /// The exit code for a completed run that produced `issues`.
+    ///
+    /// Callers must pass the post-suppression, post-baseline, post-filter
+    /// set — the issues actually reported to the user. Operational and
+    /// internal errors never flow through here; commands return
+    /// [`ExitCode::OperationalError`] / [`ExitCode::InternalError`]
+    /// directly.
+    pub fn exit_code(&self, issues: &[Issue]) -> ExitCode {
+        if issues.iter().any(|issue| self.fails_on(issue.level)) {
+            ExitCode::FailingDiagnostics
+        } else {
+            ExitCode::Success
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_policy_fails_only_on_errors() {
+        let policy = ExitPolicy::default();
+        let issues = vec![Issue::warning("w"), Issue::note("n"), Issue::help("h")];
+
+        assert_eq!(policy.exit_code(&issues), ExitCode::Success);
+        assert_eq!(policy.exit_code(&[Issue::error("e")]), ExitCode::FailingDiagnostics);
+        assert_eq!(policy.exit_code(&[]), ExitCode::Success);
+    }
+
+    #[test]
+    fn test_error_on_warning_fails_on_warnings_but_not_notes() {
+        let policy = ExitPolicy::from_error_on("warning").unwrap();
+
+        assert_eq!(policy.exit_code(&[Issue::warning("w")]), ExitCode::FailingDiagnostics);
+        assert_eq!(policy.exit_code(&[Issue::note("n"), Issue::help("h")]), ExitCode::Success);
+    }
+
+    #[test]
+    fn test_warnings_as_errors_is_idempotent_and_never_relaxes() {
+        let strict = ExitPolicy::from_error_on("warning").unwrap().with_warnings_as_errors();
+        assert!(strict.fails_on(Level::Warning));
+
+        let from_default = ExitPolicy::default().with_warnings_as_errors();
+        assert_eq!(from_default, strict);
+    }
+
+    #[test]
+    fn test_notes_cannot_be_made_failing() {
+        assert!(ExitPolicy::from_error_on("note").is_none());
+        assert!(ExitPolicy::from_error_on("").is_none());
+    }
+
+    #[test]
+    fn test_codes_map_to_the_documented_numbers() {
+        assert_eq!(ExitCode::Success as u8, 0);
+        assert_eq!(ExitCode::FailingDiagnostics as u8, 1);
+        assert_eq!(ExitCode::OperationalError as u8, 2);
+        assert_eq!(ExitCode::InternalError as u8, 3);
+    }
+}
@@ -0,0 +1,196 @@
+//! Static HTML report generation.
+//!
+//! `mago lint --format=html --output=report/` writes a browsable report: an
+//! index page with per-rule and per-file counts, and one page per analyzed
+//! file showing the source with token-stream-driven syntax highlighting and
+//! inline diagnostic markers. The output is plain HTML and CSS — no
+//! JavaScript frameworks — and fully deterministic so it can be snapshot
+//! tested.
+
+use std::fmt::Write as _;
+
+use mago_token::Token;
+use mago_token::TokenKind;
+
+/// A diagnostic to render on a file page.
+#[derive(Debug, Clone)]
+pub struct HtmlDiagnostic {
+    pub rule: String,
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Builder for the static report; one instance per `--output` directory.
+#[derive(Debug)]
+pub struct HtmlReport {
+    /// Maximum number of source lines per generated page; longer files are
+    /// split into `file.html`, `file-2.html`, and so on.
+    pub lines_per_page: usize,
+}
+
+impl Default for HtmlReport {
+    fn default() -> Self {
+        Self { lines_per_page: 2_000 }
+    }
+}
+
+impl HtmlReport {
+    /// Render the index page: totals per rule and per file, with links.
+    ///
+    /// Entries are `(file name, issue count)` and `(rule name, issue
+    /// count)`; both are sorted here so output never depends on map
+    /// iteration order.
+    pub fn render_index(&self, mut per_file: Vec<(String, usize)>, mut per_rule: Vec<(String, usize)>) -> String {
+        per_file.sort();
+        per_rule.sort();
+
+        let mut html = String::new();
+        html.push_str("<!DOCTYPE html>\n<html lang=\"en\"><head><meta charset=\"utf-8\">");
+        html.push_str("<title>mago report</title><link rel=\"stylesheet\" href=\"mago.css\"></head><body>");
+        html.push_str("<h1>mago report</h1>");
+
+        html.push_str("<h2>Issues per rule</h2><table>");
+        for (rule, count) in &per_rule {
+            let _ = write!(html, "<tr><td>{}</td><td>{count}</td></tr>", escape(rule));
+        }
+        html.push_str("</table>");
+
+        html.push_str("<h2>Issues per file</h2><table>");
+        for (file, count) in &per_file {
+            let _ = write!(
+                html,
+                "<tr><td><a href=\"{href}\">{name}</a></td><td>{count}</td></tr>",
+                href = escape(&page_name(file, 1)),
+                name = escape(file),
+            );
+        }
+        html.push_str("</table></body></html>\n");
+        html
+    }
+
+    /// Render the pages for one source file, splitting at `lines_per_page`.
+    ///
+    /// Highlighting spans come from the token stream: each token is wrapped
+    /// in a `<span>` whose class derives from its `TokenKind`, and
+    /// diagnostics become markers anchored on their line with the message
+    /// and rule in a hover tooltip.
+    pub fn render_file(
+        &self,
+        name: &str,
+        source: &str,
+        tokens: &[Token],
+        resolve: impl Fn(&Token) -> String,
+        diagnostics: &[HtmlDiagnostic],
+    ) -> Vec<(String, String)> {
+        let highlighted = highlight(source, tokens, &resolve);
+        let lines: Vec<&str> = highlighted.lines().collect();
+        let pages = lines.chunks(self.lines_per_page.max(1)).count().max(1);
+
+        let mut rendered = Vec::with_capacity(pages);
+        for (page, chunk) in lines.chunks(self.lines_per_page.max(1)).enumerate() {
+            let first_line = page * self.lines_per_page + 1;
+            let mut html = String::new();
+            html.push_str("<!DOCTYPE html>\n<html lang=\"en\"><head><meta charset=\"utf-8\">");
+            let _ = write!(html, "<title>{}</title>", escape(name));
+            html.push_str("<link rel=\"stylesheet\" href=\"mago.css\"></head><body>");
+            let _ = write!(html, "<h1>{}</h1>", escape(name));
+            if pages > 1 {
+                html.push_str("<nav>");
+                for other in 1..=pages {
+                    let _ = write!(html, "<a href=\"{}\">{other}</a> ", escape(&page_name(name, other)));
+                }
+                html.push_str("</nav>");
+            }
+
+            html.push_str("<pre class=\"source\">");
+            for (index, line) in chunk.iter().enumerate() {
+                let number = first_line + index;
+                let _ = write!(html, "<span class=\"ln\" id=\"L{number}\">{number:>5}</span> {line}");
+                for diagnostic in diagnostics.iter().filter(|diagnostic| diagnostic.line == number) {
+                    let _ = write!(
+                        html,
+                        "<span class=\"marker\" title=\"[{rule}] {message}\">^ col {column}</span>",
+                        rule = escape(&diagnostic.rule),
+                        message = escape(&diagnostic.message),
+                        column = diagnostic.column,
+                    );
+                }
+                html.push('\n');
+            }
+            html.push_str("</pre></body></html>\n");
+
+            rendered.push((page_name(name, page + 1), html));
+        }
+
+        rendered
+    }
+}
+
+/// Wrap each token's source text in a highlighting span.
+fn highlight(source: &str, tokens: &[Token], resolve: &impl Fn(&Token) -> String) -> String {
+    let mut html = String::with_capacity(source.len() * 2);
+    let mut cursor = 0usize;
+    for token in tokens {
+        let start = token.span.start.offset;
+        let end = token.span.end.offset;
+        if start < cursor || end > source.len() {
+            continue;
+        }
+
+        html.push_str(&escape(&source[cursor..start]));
+        let class = css_class(token.kind);
+        if class.is_empty() {
+            html.push_str(&escape(&resolve(token)));
+        } else {
+            let _ = write!(html, "<span class=\"{class}\">{}</span>", escape(&resolve(token)));
+        }
+
+        cursor = end;
+    }
+
+    html.push_str(&escape(&source[cursor..]));
+    html
+}
+
+/// The CSS class for a token kind; empty for plain text.
+fn css_class(kind: TokenKind) -> &'static str {
+    use TokenKind::*;
+
+    match kind {
+        SingleLineComment | MultiLineComment | DocBlockComment | HashComment => "tok-comment",
+        LiteralString | PartialLiteralString | StringPart | DocumentStart | DocumentEnd => "tok-string",
+        LiteralInteger | LiteralFloat => "tok-number",
+        Variable | Dollar | DollarLeftBrace => "tok-variable",
+        Identifier | QualifiedIdentifier | FullyQualifiedIdentifier => "tok-identifier",
+        OpenTag | CloseTag | EchoTag | ShortOpenTag => "tok-tag",
+        Whitespace | InlineText | InlineShebang => "",
+        kind if kind >= Abstract && kind <= Yield => "tok-keyword",
+        _ => "tok-punctuation",
+    }
+}
+
+/// Escape text for inclusion in HTML element content or attribute values.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(character),
+        }
+    }
+
+    escaped
+}
+
+/// The output file name for page `page` (1-based) of `file`.
+fn page_name(file: &str, page: usize) -> String {
+    let safe: String =
+        file.chars().map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' }).collect();
+
+    if page == 1 { format!("{safe}.html") } else { format!("{safe}-{page}.html") }
+}
@@ -0,0 +1,79 @@
+//! Machine-readable JSON output.
+//!
+//! The primary annotation becomes the issue's `location`; every secondary
+//! annotation is emitted under `relatedLocations` with its own file path,
+//! mirroring how SARIF models cross-file labels.
+
+use serde::Serialize;
+
+use crate::AnnotationKind;
+use crate::Issue;
+use crate::SourceAccess;
+
+#[derive(Debug, Serialize)]
+pub struct JsonLocation<'a> {
+    pub path: &'a str,
+    pub start: usize,
+    pub end: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonIssue<'a> {
+    pub level: &'a crate::Level,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<&'a str>,
+    pub message: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<JsonLocation<'a>>,
+    #[serde(rename = "relatedLocations", skip_serializing_if = "Vec::is_empty")]
+    pub related_locations: Vec<JsonLocation<'a>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub notes: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help: Option<&'a str>,
+}
+
+/// Project an issue into its serializable JSON form.
+pub fn to_json_issue<'a>(issue: &'a Issue, sources: &'a dyn SourceAccess) -> JsonIssue<'a> {
+    let location = issue
+        .annotations
+        .iter()
+        .find(|annotation| annotation.kind == AnnotationKind::Primary)
+        .map(|annotation| JsonLocation {
+            path: sources.path(annotation.span.file_id()),
+            start: annotation.span.start.offset,
+            end: annotation.span.end.offset,
+            message: annotation.message.as_deref(),
+        });
+
+    let related_locations = issue
+        .annotations
+        .iter()
+        .filter(|annotation| annotation.kind == AnnotationKind::Secondary)
+        .map(|annotation| JsonLocation {
+            path: sources.path(annotation.span.file_id()),
+            start: annotation.span.start.offset,
+            end: annotation.span.end.offset,
+            message: annotation.message.as_deref(),
+        })
+        .collect();
+
+    JsonIssue {
+        level: &issue.level,
+        code: issue.code.as_deref(),
+        message: &issue.message,
+        location,
+        related_locations,
+        notes: &issue.notes,
+        help: issue.help.as_deref(),
+    }
+}
+
+/// Serialize a batch of issues as a JSON array.
+pub fn render(issues: &[Issue], sources: &dyn SourceAccess) -> serde_json::Result<String> {
+    let projected: Vec<JsonIssue<'_>> = issues.iter().map(|issue| to_json_issue(issue, sources)).collect();
+
+    serde_json::to_string_pretty(&projected)
+}
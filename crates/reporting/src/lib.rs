@@ -0,0 +1,143 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_span::FileId;
+use mago_span::Span;
+
+pub mod console;
+pub mod exit_code;
+pub mod html;
+pub mod json;
+pub mod sarif;
+
+/// The severity of an [`Issue`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Level {
+    Note,
+    Help,
+    Warning,
+    Error,
+}
+
+/// What role an annotation plays within its issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AnnotationKind {
+    Primary,
+    Secondary,
+}
+
+/// A labeled span attached to an issue.
+///
+/// Each annotation carries its own [`Span`] — and therefore its own
+/// [`FileId`] — so secondary labels may point into a different file than
+/// the primary one ("first defined here", "declared in the parent class").
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Annotation {
+    pub kind: AnnotationKind,
+    pub span: Span,
+    pub message: Option<String>,
+}
+
+impl Annotation {
+    pub fn primary(span: Span) -> Self {
+        Self { kind: AnnotationKind::Primary, span, message: None }
+    }
+
+    pub fn secondary(span: Span) -> Self {
+        Self { kind: AnnotationKind::Secondary, span, message: None }
+    }
+
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+/// A single diagnostic: a severity, a message, and any number of
+/// annotations, notes and help texts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Issue {
+    pub level: Level,
+    pub code: Option<String>,
+    pub message: String,
+    pub annotations: Vec<Annotation>,
+    pub notes: Vec<String>,
+    pub help: Option<String>,
+}
+
+impl Issue {
+    pub fn new(level: Level, message: impl Into<String>) -> Self {
+        Self { level, code: None, message: message.into(), annotations: vec![], notes: vec![], help: None }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(Level::Error, message)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(Level::Warning, message)
+    }
+
+    pub fn note(message: impl Into<String>) -> Self {
+        Self::new(Level::Note, message)
+    }
+
+    pub fn help(message: impl Into<String>) -> Self {
+        Self::new(Level::Help, message)
+    }
+
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    pub fn with_annotation(mut self, annotation: Annotation) -> Self {
+        self.annotations.push(annotation);
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// The span of the first primary annotation, if any.
+    pub fn primary_span(&self) -> Option<Span> {
+        self.annotations.iter().find(|annotation| annotation.kind == AnnotationKind::Primary).map(|annotation| annotation.span)
+    }
+
+    /// The file this issue belongs to for sorting and grouping purposes.
+    ///
+    /// Always the *primary* annotation's file: secondary labels may live in
+    /// other files (even files outside the lint run, such as vendor
+    /// definitions) and must not affect where the issue is listed.
+    pub fn file_id(&self) -> Option<FileId> {
+        self.primary_span().map(|span| span.file_id())
+    }
+}
+
+/// Read access to source files referenced by annotations.
+///
+/// Renderers resolve every annotation's file through this trait rather
+/// than assuming one file per diagnostic, so a secondary label can be
+/// rendered as a second code frame even when its file was never linted.
+pub trait SourceAccess {
+    fn path(&self, file_id: FileId) -> &str;
+    fn content(&self, file_id: FileId) -> &str;
+}
+
+/// Sort issues for presentation: by primary file, then offset, then level
+/// descending so errors precede co-located warnings.
+pub fn sort_issues(issues: &mut [Issue]) {
+    issues.sort_by(|a, b| {
+        let a_key = a.primary_span().map(|span| (span.file_id().0, span.start.offset));
+        let b_key = b.primary_span().map(|span| (span.file_id().0, span.start.offset));
+
+        a_key.cmp(&b_key).then(b.level.cmp(&a.level))
+    });
+}
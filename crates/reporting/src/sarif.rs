@@ -0,0 +1,86 @@
+//! SARIF 2.1.0 output.
+//!
+//! Secondary annotations map onto `relatedLocations`, each carrying its
+//! own `artifactLocation`, so viewers can jump to cross-file labels even
+//! when the referenced file was not part of the analysis run.
+
+use serde_json::json;
+use serde_json::Value;
+
+use crate::Annotation;
+use crate::AnnotationKind;
+use crate::Issue;
+use crate::Level;
+use crate::SourceAccess;
+
+/// Render a batch of issues as a single-run SARIF log.
+pub fn render(issues: &[Issue], sources: &dyn SourceAccess) -> Value {
+    let results: Vec<Value> = issues.iter().map(|issue| result(issue, sources)).collect();
+
+    json!({
+        "version": "2.1.0",
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "runs": [{
+            "tool": { "driver": { "name": "mago" } },
+            "results": results,
+        }],
+    })
+}
+
+fn result(issue: &Issue, sources: &dyn SourceAccess) -> Value {
+    let locations: Vec<Value> = issue
+        .annotations
+        .iter()
+        .filter(|annotation| annotation.kind == AnnotationKind::Primary)
+        .map(|annotation| location(annotation, sources))
+        .collect();
+
+    let related: Vec<Value> = issue
+        .annotations
+        .iter()
+        .filter(|annotation| annotation.kind == AnnotationKind::Secondary)
+        .map(|annotation| location(annotation, sources))
+        .collect();
+
+    let mut result = json!({
+        "level": sarif_level(issue.level),
+        "message": { "text": issue.message },
+        "locations": locations,
+    });
+
+    if let Some(code) = &issue.code {
+        result["ruleId"] = json!(code);
+    }
+
+    if !related.is_empty() {
+        result["relatedLocations"] = json!(related);
+    }
+
+    result
+}
+
+fn location(annotation: &Annotation, sources: &dyn SourceAccess) -> Value {
+    let mut location = json!({
+        "physicalLocation": {
+            "artifactLocation": { "uri": sources.path(annotation.span.file_id()) },
+            "region": {
+                "charOffset": annotation.span.start.offset,
+                "charLength": annotation.span.length(),
+            },
+        },
+    });
+
+    if let Some(message) = &annotation.message {
+        location["message"] = json!({ "text": message });
+    }
+
+    location
+}
+
+fn sarif_level(level: Level) -> &'static str {
+    match level {
+        Level::Error => "error",
+        Level::Warning => "warning",
+        Level::Note | Level::Help => "note",
+    }
+}
@@ -0,0 +1,138 @@
+//! The stable interface for external rule packs.
+//!
+//! Everything a rule pack needs — spans, the AST, the interner, issue
+//! construction, fix plans — is re-exported from this crate so packs
+//! depend on `mago_rule_api` alone. The re-exported surface follows
+//! semver discipline: additions are minor, changes to the [`Rule`] or
+//! [`RulePack`] traits or to any re-exported type are major. Internal
+//! crates make no such promise; reaching past this crate into them is
+//! unsupported.
+//!
+//! A pack is an ordinary crate exposing a [`RulePack`] value; the main
+//! binary links packs behind cargo features and registers each one in a
+//! [`Registry`] at startup. See `mago_rule_pack_example` for a complete
+//! in-tree pack.
+
+pub use mago_ast::Node;
+pub use mago_ast::Program;
+pub use mago_fixer::FixPlan;
+pub use mago_fixer::SafetyClassification;
+pub use mago_interner::StringIdentifier;
+pub use mago_interner::ThreadedInterner;
+pub use mago_reporting::Annotation;
+pub use mago_reporting::Issue;
+pub use mago_reporting::Level;
+pub use mago_span::HasSpan;
+pub use mago_span::Position;
+pub use mago_span::Span;
+
+/// Static metadata describing one external rule.
+#[derive(Debug, Clone, Copy)]
+pub struct RuleMeta {
+    /// Kebab-case name, namespaced by the pack (`acme/no-internal-api`).
+    pub name: &'static str,
+    /// One-sentence description shown by `mago lint --explain`.
+    pub description: &'static str,
+    /// Level the rule reports at unless the user configures another.
+    pub default_level: Level,
+}
+
+/// Everything a rule may read and the only way it may report.
+///
+/// The context borrows the interner and the file's source text and
+/// collects issues (optionally with fix plans) on behalf of the rule;
+/// the driver drains them after `check` returns. Packs never construct
+/// reporters or touch linter internals.
+pub struct RuleContext<'a> {
+    interner: &'a ThreadedInterner,
+    source: &'a str,
+    collected: Vec<(Issue, Option<FixPlan>)>,
+}
+
+impl<'a> RuleContext<'a> {
+    pub fn new(interner: &'a ThreadedInterner, source: &'a str) -> Self {
+        Self { interner, source, collected: Vec::new() }
+    }
+
+    /// Resolve an interned string.
+    pub fn lookup(&self, id: &StringIdentifier) -> &str {
+        self.interner.lookup(id)
+    }
+
+    pub fn interner(&self) -> &'a ThreadedInterner {
+        self.interner
+    }
+
+    /// The full source text of the file under analysis.
+    pub fn source_text(&self) -> &'a str {
+        self.source
+    }
+
+    /// The source text covered by `span`.
+    pub fn slice(&self, span: Span) -> &'a str {
+        &self.source[span.to_range()]
+    }
+
+    /// Report an issue with no machine-applicable fix.
+    pub fn report(&mut self, issue: Issue) {
+        self.collected.push((issue, None));
+    }
+
+    /// Report an issue together with a fix plan built by `build`.
+    pub fn report_with_fix(&mut self, issue: Issue, build: impl FnOnce(FixPlan) -> FixPlan) {
+        self.collected.push((issue, Some(build(FixPlan::new()))));
+    }
+
+    /// Drain everything the rule reported, in report order.
+    pub fn finish(self) -> Vec<(Issue, Option<FixPlan>)> {
+        self.collected
+    }
+}
+
+/// One external lint rule.
+///
+/// Object-safe by design: the registry stores `Box<dyn Rule>` so packs
+/// compiled against one `mago_rule_api` minor version keep working with
+/// later minors.
+pub trait Rule: Send + Sync {
+    fn meta(&self) -> RuleMeta;
+
+    /// Walk `program` and report through `context`. Rules walk the AST
+    /// themselves (e.g. a [`Node`] stack via [`Node::children`]); the
+    /// driver calls `check` once per file.
+    fn check(&self, context: &mut RuleContext<'_>, program: &Program);
+}
+
+/// A named collection of rules shipped as one crate.
+pub trait RulePack: Send + Sync {
+    /// Pack name, used as the namespace prefix in rule names and in
+    /// configuration (`[linter.packs.acme]`).
+    fn name(&self) -> &'static str;
+
+    fn rules(&self) -> Vec<Box<dyn Rule>>;
+}
+
+/// The set of packs the binary was built with.
+#[derive(Default)]
+pub struct Registry {
+    packs: Vec<Box<dyn RulePack>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, pack: Box<dyn RulePack>) {
+        self.packs.push(pack);
+    }
+
+    pub fn packs(&self) -> &[Box<dyn RulePack>] {
+        &self.packs
+    }
+
+    /// All rules from all packs, flattened in registration order.
+    pub fn rules(&self) -> Vec<Box<dyn Rule>> {
+        self.packs.iter().flat_map(|pack| pack.rules()).collect()
+    }
+}
@@ -0,0 +1,128 @@
+//! An in-tree example rule pack, written against `mago_rule_api` only.
+//!
+//! The pack exists to prove the external-rule API is sufficient: the one
+//! rule here needs the interner, resolved function names, spans, and the
+//! fix-plan builder, and obtains all of them through the API crate
+//! without touching any internal module. It doubles as the template to
+//! copy when starting a private pack.
+
+use mago_rule_api::Annotation;
+use mago_rule_api::FixPlan;
+use mago_rule_api::HasSpan;
+use mago_rule_api::Issue;
+use mago_rule_api::Level;
+use mago_rule_api::Node;
+use mago_rule_api::Program;
+use mago_rule_api::Registry;
+use mago_rule_api::Rule;
+use mago_rule_api::RuleContext;
+use mago_rule_api::RuleMeta;
+use mago_rule_api::RulePack;
+use mago_rule_api::SafetyClassification;
+
+use mago_ast::*;
+
+/// One forbidden function and, optionally, what to call instead.
+#[derive(Debug, Clone)]
+pub struct ForbiddenFunction {
+    pub name: String,
+    /// When set, the fix replaces the callee name; the arguments are
+    /// left alone, so only drop-in replacements belong here.
+    pub replacement: Option<String>,
+}
+
+/// Forbids calling functions from a configured list.
+///
+/// Names are compared case-insensitively and ignoring a leading `\`,
+/// matching PHP's function-name resolution for unqualified calls.
+pub struct ForbiddenFunctionsRule {
+    forbidden: Vec<ForbiddenFunction>,
+}
+
+impl ForbiddenFunctionsRule {
+    pub fn new(forbidden: Vec<ForbiddenFunction>) -> Self {
+        Self { forbidden }
+    }
+
+    fn find(&self, name: &str) -> Option<&ForbiddenFunction> {
+        let name = name.trim_start_matches('\\');
+        self.forbidden.iter().find(|function| function.name.eq_ignore_ascii_case(name))
+    }
+}
+
+impl Rule for ForbiddenFunctionsRule {
+    fn meta(&self) -> RuleMeta {
+        RuleMeta {
+            name: "example/forbidden-functions",
+            description: "Forbids calling functions from a configured list.",
+            default_level: Level::Error,
+        }
+    }
+
+    fn check(&self, context: &mut RuleContext<'_>, program: &Program) {
+        let mut stack = vec![Node::Program(program)];
+        while let Some(node) = stack.pop() {
+            stack.extend(node.children());
+
+            let Node::Expression(Expression::Call(Call::Function(call))) = node else {
+                continue;
+            };
+
+            let Expression::Identifier(identifier) = call.function.as_ref() else {
+                continue; // `$callable()` — no resolvable name.
+            };
+
+            let name = context.lookup(&identifier.value());
+            let Some(forbidden) = self.find(name) else {
+                continue;
+            };
+
+            let issue = Issue::new(Level::Error, format!("Call to forbidden function `{name}()`."))
+                .with_annotation(
+                    Annotation::primary(identifier.span()).with_message("this function is forbidden by configuration"),
+                );
+
+            match &forbidden.replacement {
+                Some(replacement) => {
+                    let issue = issue.with_help(format!("Use `{replacement}()` instead."));
+                    let span = identifier.span();
+                    let replacement = replacement.clone();
+                    context.report_with_fix(issue, |plan: FixPlan| {
+                        plan.replace(span, replacement, SafetyClassification::PotentiallyUnsafe)
+                    });
+                }
+                None => context.report(issue),
+            }
+        }
+    }
+}
+
+/// The example pack: registered by the main binary behind the
+/// `rule-pack-example` cargo feature.
+pub struct ExamplePack {
+    forbidden: Vec<ForbiddenFunction>,
+}
+
+impl ExamplePack {
+    pub fn new(forbidden: Vec<ForbiddenFunction>) -> Self {
+        Self { forbidden }
+    }
+}
+
+impl RulePack for ExamplePack {
+    fn name(&self) -> &'static str {
+        "example"
+    }
+
+    fn rules(&self) -> Vec<Box<dyn Rule>> {
+        vec![Box::new(ForbiddenFunctionsRule::new(self.forbidden.clone()))]
+    }
+}
+
+/// Build a registry containing only the example pack; test scaffolding
+/// and the documentation example use this.
+pub fn registry(forbidden: Vec<ForbiddenFunction>) -> Registry {
+    let mut registry = Registry::new();
+    registry.register(Box::new(ExamplePack::new(forbidden)));
+    registry
+}
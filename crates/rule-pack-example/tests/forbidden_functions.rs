@@ -0,0 +1,69 @@
+use mago_interner::ThreadedInterner;
+use mago_rule_api::RuleContext;
+use mago_rule_pack_example::registry;
+use mago_rule_pack_example::ForbiddenFunction;
+
+fn forbidden(name: &str, replacement: Option<&str>) -> ForbiddenFunction {
+    ForbiddenFunction { name: name.to_owned(), replacement: replacement.map(str::to_owned) }
+}
+
+/// Run every rule in the example pack over `source` and return the fixed
+/// source (when a fix was reported) alongside the issue messages.
+fn run(source: &str, rules: Vec<ForbiddenFunction>) -> (Vec<String>, String) {
+    let interner = ThreadedInterner::new();
+    let (program, error) = mago_parser::parse_source_text(&interner, source);
+    assert!(error.is_none(), "test source must parse");
+
+    let mut messages = Vec::new();
+    let mut fixed = source.to_owned();
+    for rule in registry(rules).rules() {
+        let mut context = RuleContext::new(&interner, source);
+        rule.check(&mut context, &program);
+
+        for (issue, plan) in context.finish() {
+            messages.push(issue.message.clone());
+            if let Some(plan) = plan {
+                fixed = plan.execute(source, mago_rule_api::SafetyClassification::PotentiallyUnsafe);
+            }
+        }
+    }
+
+    (messages, fixed)
+}
+
+#[test]
+fn test_forbidden_call_is_reported() {
+    let (messages, _) = run("<?php var_dump($value);", vec![forbidden("var_dump", None)]);
+
+    assert_eq!(messages, vec!["Call to forbidden function `var_dump()`.".to_owned()]);
+}
+
+#[test]
+fn test_matching_ignores_case_and_leading_backslash() {
+    let (messages, _) = run("<?php \\VAR_DUMP($value);", vec![forbidden("var_dump", None)]);
+
+    assert_eq!(messages.len(), 1);
+}
+
+#[test]
+fn test_replacement_produces_a_fix() {
+    let (messages, fixed) = run("<?php $id = create_function('', '');", vec![forbidden("create_function", Some("fn"))]);
+
+    assert_eq!(messages.len(), 1);
+    assert_eq!(fixed, "<?php $id = fn('', '');");
+}
+
+#[test]
+fn test_unlisted_functions_are_untouched() {
+    let (messages, fixed) = run("<?php strlen($value);", vec![forbidden("var_dump", None)]);
+
+    assert!(messages.is_empty());
+    assert_eq!(fixed, "<?php strlen($value);");
+}
+
+#[test]
+fn test_dynamic_calls_are_skipped() {
+    let (messages, _) = run("<?php $f = 'var_dump'; $f($value);", vec![forbidden("var_dump", None)]);
+
+    assert!(messages.is_empty());
+}
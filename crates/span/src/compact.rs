@@ -0,0 +1,155 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::FileId;
+use crate::LineIndex;
+use crate::Position;
+use crate::Span;
+
+/// A file-less, line-less span: two `u32` byte offsets.
+///
+/// A [`Span`] stores a `FileId` and a line number in *both* of its
+/// positions, yet every span of one parse tree shares the file and the
+/// lines are derivable from the offsets. Storing `LocalSpan`s on nodes
+/// and keeping the shared context once in a [`SpannedTree`] cuts span
+/// memory to eight bytes per node — for span-dense ASTs of large files
+/// that is most of the saving profiling asked for.
+///
+/// Offsets are `u32`, which caps a file at 4 GiB; the lexer's input
+/// limits reject such files long before spans are built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct LocalSpan {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl LocalSpan {
+    #[inline]
+    pub fn new(start: u32, end: u32) -> Self {
+        Self { start, end }
+    }
+
+    /// Drop a full span's file and line information.
+    #[inline]
+    pub fn from_span(span: Span) -> Self {
+        Self { start: span.start.offset as u32, end: span.end.offset as u32 }
+    }
+
+    /// The length of the span in bytes.
+    #[inline]
+    pub fn length(&self) -> usize {
+        (self.end - self.start) as usize
+    }
+
+    /// The byte range of the span, for slicing source text.
+    #[inline]
+    pub fn to_range(&self) -> std::ops::Range<usize> {
+        self.start as usize..self.end as usize
+    }
+
+    /// Whether the span contains the byte `offset`; the end is exclusive.
+    #[inline]
+    pub fn has_offset(&self, offset: usize) -> bool {
+        self.start as usize <= offset && offset < self.end as usize
+    }
+
+    /// The smallest span covering both this span and `other`.
+    #[inline]
+    pub fn join(self, other: LocalSpan) -> LocalSpan {
+        LocalSpan { start: self.start.min(other.start), end: self.end.max(other.end) }
+    }
+}
+
+/// The per-tree context [`LocalSpan`]s leave out: the file id and the
+/// line starts needed to rebuild full [`Span`]s on demand.
+///
+/// One `SpannedTree` serves a whole parse tree. `localize` strips a span
+/// down for storage; `resolve` is the inverse, recomputing each
+/// position's line from the index, so code built on [`HasSpan`]
+/// continues to receive complete spans.
+///
+/// [`HasSpan`]: crate::HasSpan
+#[derive(Debug, Clone)]
+pub struct SpannedTree {
+    file_id: FileId,
+    lines: LineIndex,
+}
+
+impl SpannedTree {
+    pub fn new(file_id: FileId, source: &str) -> Self {
+        Self { file_id, lines: LineIndex::new(file_id, source) }
+    }
+
+    #[inline]
+    pub fn file_id(&self) -> FileId {
+        self.file_id
+    }
+
+    /// Strip `span` for compact storage.
+    ///
+    /// Debug-asserts the span belongs to this tree's file; in release
+    /// builds a foreign span degrades to its offsets, which is wrong in
+    /// the same silent way mixing files always was.
+    #[inline]
+    pub fn localize(&self, span: Span) -> LocalSpan {
+        debug_assert_eq!(span.file_id(), self.file_id, "localizing a span from another file");
+
+        LocalSpan::from_span(span)
+    }
+
+    /// Rebuild a full [`Span`] — file id and line numbers included — from
+    /// a compact one.
+    #[inline]
+    pub fn resolve(&self, local: LocalSpan) -> Span {
+        Span::new(self.position_at(local.start as usize), self.position_at(local.end as usize))
+    }
+
+    #[inline]
+    fn position_at(&self, offset: usize) -> Position {
+        Position::new(self.file_id, offset, self.lines.line_of(offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_span_is_eight_bytes() {
+        assert_eq!(std::mem::size_of::<LocalSpan>(), 8);
+        assert!(std::mem::size_of::<LocalSpan>() < std::mem::size_of::<Span>() / 2);
+    }
+
+    #[test]
+    fn test_roundtrip_restores_file_and_lines() {
+        let source = "aaa\nbbb\nccc";
+        let tree = SpannedTree::new(FileId(7), source);
+
+        let original = Span::new(Position::new(FileId(7), 4, 1), Position::new(FileId(7), 7, 1));
+        let local = tree.localize(original);
+        assert_eq!(local, LocalSpan::new(4, 7));
+
+        let resolved = tree.resolve(local);
+        assert_eq!(resolved, original);
+        assert_eq!(resolved.text_in(source), Some("bbb"));
+    }
+
+    #[test]
+    fn test_resolve_recomputes_lines_from_offsets() {
+        let tree = SpannedTree::new(FileId(0), "a\nb\nc\n");
+
+        let span = tree.resolve(LocalSpan::new(0, 5));
+        assert_eq!(span.start.line, 0);
+        assert_eq!(span.end.line, 2);
+    }
+
+    #[test]
+    fn test_local_span_operations_match_span_semantics() {
+        let local = LocalSpan::new(4, 10);
+        assert_eq!(local.length(), 6);
+        assert_eq!(local.to_range(), 4..10);
+        assert!(local.has_offset(4));
+        assert!(!local.has_offset(10));
+        assert_eq!(local.join(LocalSpan::new(8, 12)), LocalSpan::new(4, 12));
+    }
+}
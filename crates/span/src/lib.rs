@@ -0,0 +1,221 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+pub use crate::compact::LocalSpan;
+pub use crate::compact::SpannedTree;
+pub use crate::line_index::LineIndex;
+pub use crate::order::sort_spans_by_offset;
+pub use crate::order::spans_are_sorted;
+
+mod compact;
+mod line_index;
+mod order;
+
+/// Identifies the file a position belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct FileId(pub u32);
+
+/// A position in a source file.
+///
+/// The derived `Ord` compares `file_id` before `offset`; use
+/// [`sort_spans_by_offset`] when an explicit, documented ordering of spans
+/// is required.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Position {
+    pub file_id: FileId,
+    pub offset: usize,
+    pub line: usize,
+}
+
+/// A half-open byte range `[start, end)` in a single source file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Position {
+    pub fn new(file_id: FileId, offset: usize, line: usize) -> Self {
+        Self { file_id, offset, line }
+    }
+
+    /// The absolute byte distance between two positions in the same file.
+    ///
+    /// Debug-asserts that both positions share a file: a distance across
+    /// files is meaningless, but release builds degrade to the raw offset
+    /// difference rather than panicking.
+    #[inline]
+    pub fn distance_to(&self, other: &Position) -> usize {
+        debug_assert_eq!(self.file_id, other.file_id, "distance between positions in different files");
+
+        self.offset.abs_diff(other.offset)
+    }
+
+    /// The pair `(self, other)` sorted by offset, smaller first.
+    ///
+    /// Replaces the manual `if a.offset > b.offset { swap }` dance when a
+    /// range is built from two positions of unknown order.
+    #[inline]
+    pub fn ordered(self, other: Position) -> (Position, Position) {
+        if self.offset <= other.offset { (self, other) } else { (other, self) }
+    }
+}
+
+impl Span {
+    pub fn new(start: Position, end: Position) -> Self {
+        Self { start, end }
+    }
+
+    /// Whether the span contains the byte `offset`; the end is exclusive.
+    #[inline]
+    pub fn has_offset(&self, offset: usize) -> bool {
+        self.start.offset <= offset && offset < self.end.offset
+    }
+
+    /// Whether `other` is entirely contained in this span.
+    #[inline]
+    pub fn contains(&self, other: &Span) -> bool {
+        self.start.file_id == other.start.file_id
+            && self.start.offset <= other.start.offset
+            && other.end.offset <= self.end.offset
+    }
+
+    /// The smallest span covering both this span and `other`.
+    #[inline]
+    pub fn join(self, other: impl HasSpan) -> Span {
+        let other = other.span();
+
+        Span {
+            start: if self.start.offset <= other.start.offset { self.start } else { other.start },
+            end: if self.end.offset >= other.end.offset { self.end } else { other.end },
+        }
+    }
+
+    /// The length of the span in bytes.
+    #[inline]
+    pub fn length(&self) -> usize {
+        self.end.offset - self.start.offset
+    }
+
+    #[inline]
+    pub fn file_id(&self) -> FileId {
+        self.start.file_id
+    }
+
+    /// Whether this span ends exactly where `other` starts, or vice versa.
+    ///
+    /// Adjacency requires the same file and `end.offset == start.offset`
+    /// with no gap; trivia attachment and gap analysis use this to decide
+    /// whether two ranges touch.
+    #[inline]
+    pub fn is_adjacent_to(&self, other: &Span) -> bool {
+        self.start.file_id == other.start.file_id
+            && (self.end.offset == other.start.offset || other.end.offset == self.start.offset)
+    }
+
+    /// Whether the span covers no bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.end.offset <= self.start.offset
+    }
+
+    /// Clamp the span so that `end >= start`, yielding an empty span at
+    /// `start` when the input was inverted.
+    ///
+    /// Deserialized or arithmetic-derived spans can end up with `end <
+    /// start`; normalizing gives a defined, non-panicking result instead of
+    /// forcing defensive checks on every consumer. This is lossy — the
+    /// original `end` is discarded — and intended for display and
+    /// robustness paths only, never for reconstructing source ranges.
+    #[inline]
+    pub fn normalize(self) -> Span {
+        if self.end.offset < self.start.offset { Span { start: self.start, end: self.start } } else { self }
+    }
+
+    /// The byte range of the span, for slicing source text.
+    #[inline]
+    pub fn to_range(&self) -> std::ops::Range<usize> {
+        self.start.offset..self.end.offset
+    }
+
+    /// The text this span covers in `source`, checked.
+    ///
+    /// Returns `None` when the span is out of bounds, inverted, or does
+    /// not fall on UTF-8 character boundaries — every way the manual
+    /// `&source[span.to_range()]` slice would panic. Spans produced by
+    /// the lexer are always in range of their own file's content, so a
+    /// `None` here means the span is being sliced against the wrong (or a
+    /// stale) source; callers should treat it as "no text" rather than
+    /// unwrap.
+    #[inline]
+    pub fn text_in<'a>(&self, source: &'a str) -> Option<&'a str> {
+        if self.end.offset < self.start.offset {
+            return None;
+        }
+
+        source.get(self.to_range())
+    }
+
+    /// Iterate the raw bytes the span covers in `source`; empty when
+    /// [`Span::text_in`] would return `None`.
+    #[inline]
+    pub fn bytes_in<'a>(&self, source: &'a str) -> impl Iterator<Item = u8> + 'a {
+        self.text_in(source).unwrap_or_default().bytes()
+    }
+}
+
+/// Implemented by every AST node and token that knows its location.
+pub trait HasSpan {
+    fn span(&self) -> Span;
+}
+
+impl HasSpan for Span {
+    fn span(&self) -> Span {
+        *self
+    }
+}
+
+impl<T: HasSpan> HasSpan for &T {
+    fn span(&self) -> Span {
+        (*self).span()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance_to_is_symmetric() {
+        let a = Position::new(FileId(0), 10, 1);
+        let b = Position::new(FileId(0), 25, 2);
+
+        assert_eq!(a.distance_to(&b), 15);
+        assert_eq!(b.distance_to(&a), 15);
+        assert_eq!(a.distance_to(&a), 0);
+    }
+
+    #[test]
+    fn test_text_in_slices_checked() {
+        let source = "<?php écho;";
+        let span = |start, end| Span::new(Position::new(FileId(0), start, 1), Position::new(FileId(0), end, 1));
+
+        assert_eq!(span(6, 11).text_in(source), Some("écho"));
+        // Out of bounds, inverted, and mid-character spans all decline.
+        assert_eq!(span(6, 99).text_in(source), None);
+        assert_eq!(span(11, 6).text_in(source), None);
+        assert_eq!(span(7, 11).text_in(source), None);
+
+        assert_eq!(span(6, 11).bytes_in(source).count(), 5);
+        assert_eq!(span(6, 99).bytes_in(source).count(), 0);
+    }
+
+    #[test]
+    fn test_ordered_sorts_by_offset() {
+        let a = Position::new(FileId(0), 10, 1);
+        let b = Position::new(FileId(0), 25, 2);
+
+        assert_eq!(a.ordered(b), (a, b));
+        assert_eq!(b.ordered(a), (a, b));
+    }
+}
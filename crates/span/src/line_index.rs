@@ -0,0 +1,102 @@
+use crate::FileId;
+use crate::Position;
+use crate::Span;
+
+/// Precomputed line-start offsets for one file, for offset/line mapping
+/// without rescanning the source.
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    file_id: FileId,
+    /// The byte offset of the first character of each line; `starts[0]`
+    /// is always `0`.
+    starts: Vec<usize>,
+    length: usize,
+}
+
+impl LineIndex {
+    pub fn new(file_id: FileId, source: &str) -> Self {
+        let mut starts = vec![0];
+        for (offset, byte) in source.bytes().enumerate() {
+            if byte == b'\n' {
+                starts.push(offset + 1);
+            }
+        }
+
+        Self { file_id, starts, length: source.len() }
+    }
+
+    /// The zero-based line containing the byte `offset`.
+    pub fn line_of(&self, offset: usize) -> usize {
+        self.starts.partition_point(|start| *start <= offset.min(self.length)) - 1
+    }
+
+    /// The byte offset where `line` starts, clamped to the last line.
+    pub fn line_start(&self, line: usize) -> usize {
+        self.starts[line.min(self.starts.len() - 1)]
+    }
+
+    /// The byte offset just past the end of `line` (including its
+    /// terminating newline, if any).
+    pub fn line_end(&self, line: usize) -> usize {
+        match self.starts.get(line + 1) {
+            Some(next) => *next,
+            None => self.length,
+        }
+    }
+
+    /// Expand `span` to whole lines, with `before` extra lines of leading
+    /// and `after` extra lines of trailing context.
+    ///
+    /// The result snaps to line boundaries and clamps at the start and end
+    /// of the file, so a diagnostic on line 0 with `before = 2` simply
+    /// starts at offset 0 instead of underflowing. This is the one place
+    /// snippet renderers compute their context windows, keeping them
+    /// consistent across the console, HTML, and editor outputs.
+    pub fn expand_span_to_lines(&self, span: Span, before: usize, after: usize) -> Span {
+        let first = self.line_of(span.start.offset).saturating_sub(before);
+        let last = self.line_of(span.end.offset.saturating_sub(1).max(span.start.offset)) + after;
+
+        let start = self.line_start(first);
+        let end = self.line_end(last.min(self.starts.len() - 1));
+
+        Span::new(Position::new(self.file_id, start, first), Position::new(self.file_id, end, last.min(self.starts.len() - 1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(start: usize, end: usize) -> Span {
+        Span::new(Position::new(FileId(0), start, 0), Position::new(FileId(0), end, 0))
+    }
+
+    #[test]
+    fn test_expansion_snaps_to_line_boundaries() {
+        //                      0123 4567 8901 2345
+        let index = LineIndex::new(FileId(0), "aaa\nbbb\nccc\nddd\n");
+
+        let expanded = index.expand_span_to_lines(span(9, 10), 1, 1);
+
+        assert_eq!(expanded.start.offset, 4);
+        assert_eq!(expanded.end.offset, 16);
+    }
+
+    #[test]
+    fn test_expansion_clamps_at_file_edges() {
+        let index = LineIndex::new(FileId(0), "aaa\nbbb");
+
+        let expanded = index.expand_span_to_lines(span(1, 2), 5, 5);
+
+        assert_eq!(expanded.start.offset, 0);
+        assert_eq!(expanded.end.offset, 7);
+    }
+
+    #[test]
+    fn test_no_trailing_newline() {
+        let index = LineIndex::new(FileId(0), "aaa\nbbb");
+
+        assert_eq!(index.line_of(6), 1);
+        assert_eq!(index.line_end(1), 7);
+    }
+}
@@ -0,0 +1,51 @@
+use std::cmp::Ordering;
+
+use crate::Span;
+
+/// Compare two spans by `(file_id, start.offset, end.offset)`.
+///
+/// This is the total order diagnostics and fixes rely on for deterministic
+/// output: spans group by file, then appear in source order, with shorter
+/// spans before longer ones at the same start. It is intentionally explicit
+/// rather than the derived `Ord`, whose `Position` comparison interleaves
+/// fields (`file_id`, then `offset`, then `line`) in ways that are easy to
+/// get surprised by when spans from several files are mixed.
+pub fn compare_spans_by_offset(a: &Span, b: &Span) -> Ordering {
+    a.start
+        .file_id
+        .cmp(&b.start.file_id)
+        .then_with(|| a.start.offset.cmp(&b.start.offset))
+        .then_with(|| a.end.offset.cmp(&b.end.offset))
+}
+
+/// Sort spans by `(file_id, start.offset, end.offset)`.
+pub fn sort_spans_by_offset(spans: &mut [Span]) {
+    spans.sort_by(compare_spans_by_offset);
+}
+
+/// Whether `spans` is already sorted by `(file_id, start.offset,
+/// end.offset)`; useful in debug assertions guarding ordered pipelines.
+pub fn spans_are_sorted(spans: &[Span]) -> bool {
+    spans.windows(2).all(|pair| compare_spans_by_offset(&pair[0], &pair[1]) != Ordering::Greater)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FileId;
+    use crate::Position;
+
+    fn span(file: u32, start: usize, end: usize) -> Span {
+        Span::new(Position::new(FileId(file), start, 1), Position::new(FileId(file), end, 1))
+    }
+
+    #[test]
+    fn test_sort_groups_by_file_then_offset() {
+        let mut spans = vec![span(1, 5, 9), span(0, 7, 8), span(1, 5, 6), span(0, 2, 4)];
+        assert!(!spans_are_sorted(&spans));
+
+        sort_spans_by_offset(&mut spans);
+        assert_eq!(spans, vec![span(0, 2, 4), span(0, 7, 8), span(1, 5, 6), span(1, 5, 9)]);
+        assert!(spans_are_sorted(&spans));
+    }
+}
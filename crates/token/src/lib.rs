@@ -0,0 +1,663 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_interner::StringIdentifier;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+pub use crate::precedence::operators;
+pub use crate::precedence::Associativity;
+pub use crate::precedence::Precedence;
+pub use crate::precedence::OPERATORS;
+
+mod precedence;
+
+/// A single lexed PHP token: its kind, interned source text, and span.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub value: StringIdentifier,
+    pub span: Span,
+}
+
+/// The kind of a lexed token.
+///
+/// Operator and punctuation kinds are named after their glyphs; keyword kinds
+/// after the (case-insensitive) keyword they represent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum TokenKind {
+    Whitespace,
+    InlineText,
+    InlineShebang,
+    SingleLineComment,
+    MultiLineComment,
+    DocBlockComment,
+    OpenTag,
+    EchoTag,
+    ShortOpenTag,
+    CloseTag,
+    Identifier,
+    QualifiedIdentifier,
+    FullyQualifiedIdentifier,
+    Variable,
+    LiteralInteger,
+    LiteralFloat,
+    LiteralString,
+    PartialLiteralString,
+    StringPart,
+    DoubleQuote,
+    Backtick,
+    DollarLeftBrace,
+    DocumentStart,
+    DocumentEnd,
+    Abstract,
+    And,
+    Array,
+    ArrayCast,
+    As,
+    BoolCast,
+    BooleanCast,
+    Break,
+    Callable,
+    Case,
+    Catch,
+    Class,
+    Clone,
+    Const,
+    Continue,
+    Declare,
+    Default,
+    Do,
+    DoubleCast,
+    Echo,
+    Else,
+    ElseIf,
+    Empty,
+    EndDeclare,
+    EndFor,
+    EndForeach,
+    EndIf,
+    EndSwitch,
+    EndWhile,
+    Enum,
+    Eval,
+    Exit,
+    Extends,
+    False,
+    Final,
+    Finally,
+    Fn,
+    FloatCast,
+    For,
+    Foreach,
+    From,
+    Function,
+    Global,
+    Goto,
+    If,
+    Implements,
+    Include,
+    IncludeOnce,
+    Instanceof,
+    Insteadof,
+    IntCast,
+    IntegerCast,
+    Interface,
+    Isset,
+    List,
+    Match,
+    Namespace,
+    New,
+    Null,
+    ObjectCast,
+    Or,
+    Print,
+    Private,
+    PrivateSet,
+    Protected,
+    ProtectedSet,
+    Public,
+    PublicSet,
+    Readonly,
+    Require,
+    RequireOnce,
+    Return,
+    Static,
+    StringCast,
+    Switch,
+    Throw,
+    Trait,
+    True,
+    Try,
+    Unset,
+    UnsetCast,
+    Use,
+    Var,
+    While,
+    Xor,
+    Yield,
+    Ampersand,
+    AmpersandAmpersand,
+    AmpersandEqual,
+    Asterisk,
+    AsteriskAsterisk,
+    AsteriskAsteriskEqual,
+    AsteriskEqual,
+    At,
+    Bang,
+    BangEqual,
+    BangEqualEqual,
+    Caret,
+    CaretEqual,
+    Colon,
+    ColonColon,
+    Comma,
+    Dollar,
+    Dot,
+    DotEqual,
+    DotDotDot,
+    Equal,
+    EqualEqual,
+    EqualEqualEqual,
+    EqualGreaterThan,
+    GreaterThan,
+    GreaterThanEqual,
+    GreaterThanGreaterThan,
+    GreaterThanGreaterThanEqual,
+    LeftBrace,
+    LeftBracket,
+    LeftParenthesis,
+    LessThan,
+    LessThanEqual,
+    LessThanEqualGreaterThan,
+    LessThanGreaterThan,
+    LessThanLessThan,
+    LessThanLessThanEqual,
+    Minus,
+    MinusEqual,
+    MinusGreaterThan,
+    MinusMinus,
+    Percent,
+    PercentEqual,
+    Pipe,
+    PipeEqual,
+    /// The `|>` pipe operator (PHP RFC: pipe-operator).
+    ///
+    /// Reserved ahead of finalization so that precedence and parsing have a
+    /// slot wired; the lexer only produces this kind when targeting a PHP
+    /// version that includes the RFC, so it is inert on older targets.
+    PipeGreaterThan,
+    PipePipe,
+    Plus,
+    PlusEqual,
+    PlusPlus,
+    Question,
+    QuestionColon,
+    QuestionMinusGreaterThan,
+    QuestionQuestion,
+    QuestionQuestionEqual,
+    RightBrace,
+    RightBracket,
+    RightParenthesis,
+    Semicolon,
+    Slash,
+    SlashEqual,
+    Tilde,
+    Attribute,
+    HashComment,
+}
+
+impl TokenKind {
+    /// Match a string against the PHP keyword set, ignoring ASCII case.
+    ///
+    /// PHP keywords are case-insensitive (`FUNCTION`, `Function`, and
+    /// `function` are all the same keyword), and this is the same keyword
+    /// table the lexer matches against, exposed so that tools comparing
+    /// user-written identifiers to keywords — e.g. "don't name a constant
+    /// like a keyword" — cannot drift out of sync with the lexer.
+    pub fn keyword_from_str_ci(value: &str) -> Option<TokenKind> {
+        Some(match value.to_ascii_lowercase().as_str() {
+            "abstract" => TokenKind::Abstract,
+            "and" => TokenKind::And,
+            "array" => TokenKind::Array,
+            "as" => TokenKind::As,
+            "break" => TokenKind::Break,
+            "callable" => TokenKind::Callable,
+            "case" => TokenKind::Case,
+            "catch" => TokenKind::Catch,
+            "class" => TokenKind::Class,
+            "clone" => TokenKind::Clone,
+            "const" => TokenKind::Const,
+            "continue" => TokenKind::Continue,
+            "declare" => TokenKind::Declare,
+            "default" => TokenKind::Default,
+            "do" => TokenKind::Do,
+            "echo" => TokenKind::Echo,
+            "else" => TokenKind::Else,
+            "elseif" => TokenKind::ElseIf,
+            "empty" => TokenKind::Empty,
+            "enddeclare" => TokenKind::EndDeclare,
+            "endfor" => TokenKind::EndFor,
+            "endforeach" => TokenKind::EndForeach,
+            "endif" => TokenKind::EndIf,
+            "endswitch" => TokenKind::EndSwitch,
+            "endwhile" => TokenKind::EndWhile,
+            "enum" => TokenKind::Enum,
+            "eval" => TokenKind::Eval,
+            "exit" | "die" => TokenKind::Exit,
+            "extends" => TokenKind::Extends,
+            "false" => TokenKind::False,
+            "final" => TokenKind::Final,
+            "finally" => TokenKind::Finally,
+            "fn" => TokenKind::Fn,
+            "for" => TokenKind::For,
+            "foreach" => TokenKind::Foreach,
+            "from" => TokenKind::From,
+            "function" => TokenKind::Function,
+            "global" => TokenKind::Global,
+            "goto" => TokenKind::Goto,
+            "if" => TokenKind::If,
+            "implements" => TokenKind::Implements,
+            "include" => TokenKind::Include,
+            "include_once" => TokenKind::IncludeOnce,
+            "instanceof" => TokenKind::Instanceof,
+            "insteadof" => TokenKind::Insteadof,
+            "interface" => TokenKind::Interface,
+            "isset" => TokenKind::Isset,
+            "list" => TokenKind::List,
+            "match" => TokenKind::Match,
+            "namespace" => TokenKind::Namespace,
+            "new" => TokenKind::New,
+            "null" => TokenKind::Null,
+            "or" => TokenKind::Or,
+            "print" => TokenKind::Print,
+            "private" => TokenKind::Private,
+            "protected" => TokenKind::Protected,
+            "public" => TokenKind::Public,
+            "readonly" => TokenKind::Readonly,
+            "require" => TokenKind::Require,
+            "require_once" => TokenKind::RequireOnce,
+            "return" => TokenKind::Return,
+            "static" => TokenKind::Static,
+            "switch" => TokenKind::Switch,
+            "throw" => TokenKind::Throw,
+            "trait" => TokenKind::Trait,
+            "true" => TokenKind::True,
+            "try" => TokenKind::Try,
+            "unset" => TokenKind::Unset,
+            "use" => TokenKind::Use,
+            "var" => TokenKind::Var,
+            "while" => TokenKind::While,
+            "xor" => TokenKind::Xor,
+            "yield" => TokenKind::Yield,
+            _ => return None,
+        })
+    }
+}
+
+impl TokenKind {
+    /// Whether this token may appear directly after `->`, `?->`, or `::`.
+    ///
+    /// The member position accepts an identifier-like token — including
+    /// every reserved word, since `$foo->list()` and `Foo::class` are valid
+    /// member accesses — a `{` (for `->{$expr}`), a `$variable` or bare `$`
+    /// (for `->$name` / `->$$name`), and `class` for the `::class` constant.
+    /// Parsers should consult this one predicate instead of re-deriving the
+    /// set at each call site and forgetting the reserved-word cases.
+    pub fn can_follow_object_operator(&self) -> bool {
+        matches!(
+            self,
+            TokenKind::Identifier | TokenKind::Variable | TokenKind::LeftBrace | TokenKind::Dollar | TokenKind::DollarLeftBrace,
+        ) || self.is_keyword()
+    }
+
+    /// Whether this kind is a PHP keyword.
+    #[inline]
+    pub fn is_keyword(&self) -> bool {
+        // Keyword kinds form a contiguous range in the enum.
+        *self >= TokenKind::Abstract && *self <= TokenKind::Yield
+    }
+
+    /// Whether this keyword is contextual: lexed as a keyword, but a plain
+    /// identifier in every position where its construct cannot start.
+    ///
+    /// The lexer always produces the keyword kind; it is the *parser's*
+    /// position that decides. Concretely:
+    ///
+    /// - `enum` is a keyword only at statement level when followed by a
+    ///   name (`enum Suit {`); elsewhere — method names, class constants,
+    ///   function names (`function enum()` predates PHP 8.1) — it is an
+    ///   identifier.
+    /// - `readonly` is a keyword only as a property/promotion modifier or
+    ///   before `class`; `readonly(...)` in expression position is a
+    ///   function call, and PHP itself special-cases that.
+    /// - `from` is a keyword only immediately after `yield`; everywhere
+    ///   else it is an ordinary identifier (it is not reserved at all).
+    /// - `match` is a keyword only in expression position followed by `(`;
+    ///   as a member or function name it is an identifier.
+    /// - `fn` is a keyword only where a closure can start; as a member
+    ///   name it is an identifier.
+    ///
+    /// Note the member position (`$obj->match()`, `Foo::match()`) accepts
+    /// *every* keyword, contextual or not — that is
+    /// [`can_follow_object_operator`](Self::can_follow_object_operator).
+    /// This predicate is for the positions where even a *declaration* or
+    /// bare use of the word is legal: `function match() {}` parses,
+    /// `function list() {}` does not.
+    #[inline]
+    pub fn is_contextual_keyword(&self) -> bool {
+        matches!(self, TokenKind::Enum | TokenKind::Readonly | TokenKind::From | TokenKind::Match | TokenKind::Fn)
+    }
+
+    /// Whether this kind carries no syntactic meaning (whitespace and
+    /// comments).
+    #[inline]
+    pub fn is_trivia(&self) -> bool {
+        matches!(
+            self,
+            TokenKind::Whitespace
+                | TokenKind::SingleLineComment
+                | TokenKind::MultiLineComment
+                | TokenKind::DocBlockComment,
+        )
+    }
+
+    /// Whether this kind opens a PHP block (`<?php`, `<?=`, `<?`).
+    #[inline]
+    pub fn is_open_tag(&self) -> bool {
+        matches!(self, TokenKind::OpenTag | TokenKind::EchoTag | TokenKind::ShortOpenTag)
+    }
+
+    /// Whether this kind is a logical operator: `&&`, `||`, `!`, and the
+    /// keyword forms `and`, `or`, `xor`.
+    ///
+    /// The category predicates below exist for rules that reason about
+    /// operator families ("use `&&` not `and`", "no bitwise ops on
+    /// booleans") without spelling out `matches!` lists at each site. The
+    /// categories are disjoint; `^` is bitwise, only the keyword `xor` is
+    /// logical.
+    #[inline]
+    pub fn is_logical_operator(&self) -> bool {
+        matches!(
+            self,
+            TokenKind::AmpersandAmpersand
+                | TokenKind::PipePipe
+                | TokenKind::Bang
+                | TokenKind::And
+                | TokenKind::Or
+                | TokenKind::Xor,
+        )
+    }
+
+    /// Whether this kind is a bitwise operator: `&`, `|`, `^`, `~`, `<<`,
+    /// `>>`.
+    #[inline]
+    pub fn is_bitwise_operator(&self) -> bool {
+        matches!(
+            self,
+            TokenKind::Ampersand
+                | TokenKind::Pipe
+                | TokenKind::Caret
+                | TokenKind::Tilde
+                | TokenKind::LessThanLessThan
+                | TokenKind::GreaterThanGreaterThan,
+        )
+    }
+
+    /// Whether this kind is an arithmetic operator: `+`, `-`, `*`, `/`,
+    /// `%`, `**`.
+    #[inline]
+    pub fn is_arithmetic_operator(&self) -> bool {
+        matches!(
+            self,
+            TokenKind::Plus
+                | TokenKind::Minus
+                | TokenKind::Asterisk
+                | TokenKind::Slash
+                | TokenKind::Percent
+                | TokenKind::AsteriskAsterisk,
+        )
+    }
+}
+
+impl HasSpan for Token {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// Match a token kind by its surface syntax, e.g. `T!["=>"]` or `T!["fn"]`.
+#[macro_export]
+macro_rules! T {
+    ("&") => { $crate::TokenKind::Ampersand };
+    ("&&") => { $crate::TokenKind::AmpersandAmpersand };
+    ("&=") => { $crate::TokenKind::AmpersandEqual };
+    ("*") => { $crate::TokenKind::Asterisk };
+    ("**") => { $crate::TokenKind::AsteriskAsterisk };
+    ("**=") => { $crate::TokenKind::AsteriskAsteriskEqual };
+    ("*=") => { $crate::TokenKind::AsteriskEqual };
+    ("@") => { $crate::TokenKind::At };
+    ("!") => { $crate::TokenKind::Bang };
+    ("!=") => { $crate::TokenKind::BangEqual };
+    ("!==") => { $crate::TokenKind::BangEqualEqual };
+    ("^") => { $crate::TokenKind::Caret };
+    ("^=") => { $crate::TokenKind::CaretEqual };
+    (":") => { $crate::TokenKind::Colon };
+    ("::") => { $crate::TokenKind::ColonColon };
+    (",") => { $crate::TokenKind::Comma };
+    ("$") => { $crate::TokenKind::Dollar };
+    (".") => { $crate::TokenKind::Dot };
+    (".=") => { $crate::TokenKind::DotEqual };
+    ("...") => { $crate::TokenKind::DotDotDot };
+    ("=") => { $crate::TokenKind::Equal };
+    ("==") => { $crate::TokenKind::EqualEqual };
+    ("===") => { $crate::TokenKind::EqualEqualEqual };
+    ("=>") => { $crate::TokenKind::EqualGreaterThan };
+    (">") => { $crate::TokenKind::GreaterThan };
+    (">=") => { $crate::TokenKind::GreaterThanEqual };
+    (">>") => { $crate::TokenKind::GreaterThanGreaterThan };
+    (">>=") => { $crate::TokenKind::GreaterThanGreaterThanEqual };
+    ("{") => { $crate::TokenKind::LeftBrace };
+    ("[") => { $crate::TokenKind::LeftBracket };
+    ("(") => { $crate::TokenKind::LeftParenthesis };
+    ("<") => { $crate::TokenKind::LessThan };
+    ("<=") => { $crate::TokenKind::LessThanEqual };
+    ("<=>") => { $crate::TokenKind::LessThanEqualGreaterThan };
+    ("<>") => { $crate::TokenKind::LessThanGreaterThan };
+    ("<<") => { $crate::TokenKind::LessThanLessThan };
+    ("<<=") => { $crate::TokenKind::LessThanLessThanEqual };
+    ("-") => { $crate::TokenKind::Minus };
+    ("-=") => { $crate::TokenKind::MinusEqual };
+    ("->") => { $crate::TokenKind::MinusGreaterThan };
+    ("--") => { $crate::TokenKind::MinusMinus };
+    ("%") => { $crate::TokenKind::Percent };
+    ("%=") => { $crate::TokenKind::PercentEqual };
+    ("|") => { $crate::TokenKind::Pipe };
+    ("|=") => { $crate::TokenKind::PipeEqual };
+    ("|>") => { $crate::TokenKind::PipeGreaterThan };
+    ("||") => { $crate::TokenKind::PipePipe };
+    ("+") => { $crate::TokenKind::Plus };
+    ("+=") => { $crate::TokenKind::PlusEqual };
+    ("++") => { $crate::TokenKind::PlusPlus };
+    ("?") => { $crate::TokenKind::Question };
+    ("?:") => { $crate::TokenKind::QuestionColon };
+    ("?->") => { $crate::TokenKind::QuestionMinusGreaterThan };
+    ("??") => { $crate::TokenKind::QuestionQuestion };
+    ("??=") => { $crate::TokenKind::QuestionQuestionEqual };
+    ("}") => { $crate::TokenKind::RightBrace };
+    ("]") => { $crate::TokenKind::RightBracket };
+    (")") => { $crate::TokenKind::RightParenthesis };
+    (";") => { $crate::TokenKind::Semicolon };
+    ("/") => { $crate::TokenKind::Slash };
+    ("/=") => { $crate::TokenKind::SlashEqual };
+    ("~") => { $crate::TokenKind::Tilde };
+    ("abstract") => { $crate::TokenKind::Abstract };
+    ("and") => { $crate::TokenKind::And };
+    ("array") => { $crate::TokenKind::Array };
+    ("as") => { $crate::TokenKind::As };
+    ("break") => { $crate::TokenKind::Break };
+    ("callable") => { $crate::TokenKind::Callable };
+    ("case") => { $crate::TokenKind::Case };
+    ("catch") => { $crate::TokenKind::Catch };
+    ("class") => { $crate::TokenKind::Class };
+    ("clone") => { $crate::TokenKind::Clone };
+    ("const") => { $crate::TokenKind::Const };
+    ("continue") => { $crate::TokenKind::Continue };
+    ("declare") => { $crate::TokenKind::Declare };
+    ("default") => { $crate::TokenKind::Default };
+    ("do") => { $crate::TokenKind::Do };
+    ("echo") => { $crate::TokenKind::Echo };
+    ("else") => { $crate::TokenKind::Else };
+    ("elseif") => { $crate::TokenKind::ElseIf };
+    ("empty") => { $crate::TokenKind::Empty };
+    ("enum") => { $crate::TokenKind::Enum };
+    ("eval") => { $crate::TokenKind::Eval };
+    ("exit") => { $crate::TokenKind::Exit };
+    ("extends") => { $crate::TokenKind::Extends };
+    ("false") => { $crate::TokenKind::False };
+    ("final") => { $crate::TokenKind::Final };
+    ("finally") => { $crate::TokenKind::Finally };
+    ("fn") => { $crate::TokenKind::Fn };
+    ("for") => { $crate::TokenKind::For };
+    ("foreach") => { $crate::TokenKind::Foreach };
+    ("from") => { $crate::TokenKind::From };
+    ("function") => { $crate::TokenKind::Function };
+    ("global") => { $crate::TokenKind::Global };
+    ("goto") => { $crate::TokenKind::Goto };
+    ("if") => { $crate::TokenKind::If };
+    ("implements") => { $crate::TokenKind::Implements };
+    ("include") => { $crate::TokenKind::Include };
+    ("include_once") => { $crate::TokenKind::IncludeOnce };
+    ("instanceof") => { $crate::TokenKind::Instanceof };
+    ("insteadof") => { $crate::TokenKind::Insteadof };
+    ("interface") => { $crate::TokenKind::Interface };
+    ("isset") => { $crate::TokenKind::Isset };
+    ("list") => { $crate::TokenKind::List };
+    ("match") => { $crate::TokenKind::Match };
+    ("namespace") => { $crate::TokenKind::Namespace };
+    ("new") => { $crate::TokenKind::New };
+    ("null") => { $crate::TokenKind::Null };
+    ("or") => { $crate::TokenKind::Or };
+    ("print") => { $crate::TokenKind::Print };
+    ("private") => { $crate::TokenKind::Private };
+    ("private(set)") => { $crate::TokenKind::PrivateSet };
+    ("protected") => { $crate::TokenKind::Protected };
+    ("protected(set)") => { $crate::TokenKind::ProtectedSet };
+    ("public") => { $crate::TokenKind::Public };
+    ("public(set)") => { $crate::TokenKind::PublicSet };
+    ("readonly") => { $crate::TokenKind::Readonly };
+    ("require") => { $crate::TokenKind::Require };
+    ("require_once") => { $crate::TokenKind::RequireOnce };
+    ("return") => { $crate::TokenKind::Return };
+    ("static") => { $crate::TokenKind::Static };
+    ("switch") => { $crate::TokenKind::Switch };
+    ("throw") => { $crate::TokenKind::Throw };
+    ("trait") => { $crate::TokenKind::Trait };
+    ("true") => { $crate::TokenKind::True };
+    ("try") => { $crate::TokenKind::Try };
+    ("unset") => { $crate::TokenKind::Unset };
+    ("use") => { $crate::TokenKind::Use };
+    ("var") => { $crate::TokenKind::Var };
+    ("while") => { $crate::TokenKind::While };
+    ("xor") => { $crate::TokenKind::Xor };
+    ("yield") => { $crate::TokenKind::Yield };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TokenKind;
+
+    #[test]
+    fn test_keyword_from_str_ci_matches_any_case() {
+        assert_eq!(TokenKind::keyword_from_str_ci("function"), Some(TokenKind::Function));
+        assert_eq!(TokenKind::keyword_from_str_ci("FUNCTION"), Some(TokenKind::Function));
+        assert_eq!(TokenKind::keyword_from_str_ci("FuNcTiOn"), Some(TokenKind::Function));
+        assert_eq!(TokenKind::keyword_from_str_ci("ElseIf"), Some(TokenKind::ElseIf));
+        assert_eq!(TokenKind::keyword_from_str_ci("DIE"), Some(TokenKind::Exit));
+    }
+
+    #[test]
+    fn test_can_follow_object_operator() {
+        assert!(TokenKind::Identifier.can_follow_object_operator());
+        assert!(TokenKind::Variable.can_follow_object_operator());
+        assert!(TokenKind::LeftBrace.can_follow_object_operator());
+        // Reserved words are valid member names after `->` / `::`.
+        assert!(TokenKind::List.can_follow_object_operator());
+        assert!(TokenKind::Class.can_follow_object_operator());
+
+        assert!(!TokenKind::LiteralInteger.can_follow_object_operator());
+        assert!(!TokenKind::Semicolon.can_follow_object_operator());
+        assert!(!TokenKind::MinusGreaterThan.can_follow_object_operator());
+    }
+
+    #[test]
+    fn test_contextual_keywords_as_method_names() {
+        // The matrix the parser relies on: each of these lexes as its
+        // keyword kind even after `->`, stays classified contextual, and
+        // is accepted in the member position.
+        for spelling in ["readonly", "enum", "match", "fn"] {
+            let kind = TokenKind::keyword_from_str_ci(spelling).expect("lexes as a keyword");
+            assert!(kind.is_keyword(), "`{spelling}` must lex as a keyword");
+            assert!(kind.is_contextual_keyword(), "`{spelling}` must be contextual");
+            assert!(kind.can_follow_object_operator(), "`$obj->{spelling}()` must parse");
+        }
+
+        // `from` is contextual too, but never reserved: it only lexes as a
+        // keyword at all so `yield from` can be recognized.
+        assert!(TokenKind::From.is_contextual_keyword());
+
+        // Fully reserved words are not contextual: `function list() {}`
+        // does not parse even though `$obj->list()` does.
+        assert!(!TokenKind::List.is_contextual_keyword());
+        assert!(!TokenKind::Class.is_contextual_keyword());
+        assert!(!TokenKind::Identifier.is_contextual_keyword());
+    }
+
+    #[test]
+    fn test_operator_categories_are_disjoint_and_agree_with_infix() {
+        use crate::Precedence;
+
+        for (kind, lexeme, _, _) in crate::OPERATORS {
+            let categories = usize::from(kind.is_logical_operator())
+                + usize::from(kind.is_bitwise_operator())
+                + usize::from(kind.is_arithmetic_operator());
+            assert!(categories <= 1, "`{lexeme}` is in more than one operator category");
+        }
+
+        // Every categorized kind except the prefix-only `!` and `~` is an
+        // infix operator with a real binding power.
+        let categorized = [
+            TokenKind::AmpersandAmpersand,
+            TokenKind::PipePipe,
+            TokenKind::And,
+            TokenKind::Or,
+            TokenKind::Xor,
+            TokenKind::Ampersand,
+            TokenKind::Pipe,
+            TokenKind::Caret,
+            TokenKind::LessThanLessThan,
+            TokenKind::GreaterThanGreaterThan,
+            TokenKind::Plus,
+            TokenKind::Minus,
+            TokenKind::Asterisk,
+            TokenKind::Slash,
+            TokenKind::Percent,
+            TokenKind::AsteriskAsterisk,
+        ];
+        for kind in categorized {
+            assert_ne!(Precedence::infix(&kind), Precedence::Lowest, "{kind:?} should be infix");
+        }
+
+        assert!(TokenKind::Bang.is_logical_operator());
+        assert!(TokenKind::Tilde.is_bitwise_operator());
+        assert_eq!(Precedence::infix(&TokenKind::Bang), Precedence::Lowest);
+        assert_eq!(Precedence::infix(&TokenKind::Tilde), Precedence::Lowest);
+    }
+
+    #[test]
+    fn test_keyword_from_str_ci_rejects_non_keywords() {
+        assert_eq!(TokenKind::keyword_from_str_ci("functions"), None);
+        assert_eq!(TokenKind::keyword_from_str_ci(""), None);
+        assert_eq!(TokenKind::keyword_from_str_ci("strlen"), None);
+    }
+}
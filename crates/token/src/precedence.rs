@@ -0,0 +1,284 @@
+use crate::TokenKind;
+
+/// Binding power of PHP operators, ordered from loosest to tightest.
+///
+/// Variants compare by declaration order, so `Precedence::AddSub >
+/// Precedence::Comparison` holds, matching the PHP operator table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Precedence {
+    Lowest,
+    KeyOr,
+    KeyXor,
+    KeyAnd,
+    Print,
+    Yield,
+    YieldFrom,
+    IncDec,
+    Assignment,
+    ElvisOrConditional,
+    Coalesce,
+    Or,
+    And,
+    BitwiseOr,
+    BitwiseXor,
+    BitwiseAnd,
+    Equality,
+    /// The `|>` pipe operator (PHP RFC: pipe-operator), left-associative,
+    /// binding looser than comparison and tighter than equality per the RFC.
+    Pipe,
+    Comparison,
+    Concat,
+    BitShift,
+    AddSub,
+    MulDivMod,
+    Bang,
+    Instanceof,
+    Prefix,
+    Pow,
+    Clone,
+    New,
+    CallDim,
+    ObjectAccess,
+}
+
+impl Precedence {
+    /// The binding power of `kind` when used as an infix operator.
+    pub fn infix(kind: &TokenKind) -> Precedence {
+        match kind {
+            TokenKind::Or => Self::KeyOr,
+            TokenKind::Xor => Self::KeyXor,
+            TokenKind::And => Self::KeyAnd,
+            TokenKind::Equal
+            | TokenKind::PlusEqual
+            | TokenKind::MinusEqual
+            | TokenKind::AsteriskEqual
+            | TokenKind::SlashEqual
+            | TokenKind::PercentEqual
+            | TokenKind::AsteriskAsteriskEqual
+            | TokenKind::DotEqual
+            | TokenKind::AmpersandEqual
+            | TokenKind::PipeEqual
+            | TokenKind::CaretEqual
+            | TokenKind::LessThanLessThanEqual
+            | TokenKind::GreaterThanGreaterThanEqual
+            | TokenKind::QuestionQuestionEqual => Self::Assignment,
+            TokenKind::Question | TokenKind::QuestionColon => Self::ElvisOrConditional,
+            TokenKind::QuestionQuestion => Self::Coalesce,
+            TokenKind::PipePipe => Self::Or,
+            TokenKind::AmpersandAmpersand => Self::And,
+            TokenKind::Pipe => Self::BitwiseOr,
+            TokenKind::Caret => Self::BitwiseXor,
+            TokenKind::Ampersand => Self::BitwiseAnd,
+            TokenKind::EqualEqual
+            | TokenKind::BangEqual
+            | TokenKind::EqualEqualEqual
+            | TokenKind::BangEqualEqual
+            | TokenKind::LessThanGreaterThan => Self::Equality,
+            TokenKind::PipeGreaterThan => Self::Pipe,
+            TokenKind::LessThan
+            | TokenKind::LessThanEqual
+            | TokenKind::GreaterThan
+            | TokenKind::GreaterThanEqual
+            | TokenKind::LessThanEqualGreaterThan => Self::Comparison,
+            TokenKind::Dot => Self::Concat,
+            TokenKind::LessThanLessThan | TokenKind::GreaterThanGreaterThan => Self::BitShift,
+            TokenKind::Plus | TokenKind::Minus => Self::AddSub,
+            TokenKind::Asterisk | TokenKind::Slash | TokenKind::Percent => Self::MulDivMod,
+            TokenKind::Instanceof => Self::Instanceof,
+            TokenKind::AsteriskAsterisk => Self::Pow,
+            TokenKind::MinusGreaterThan | TokenKind::QuestionMinusGreaterThan | TokenKind::ColonColon => {
+                Self::ObjectAccess
+            }
+            TokenKind::LeftParenthesis | TokenKind::LeftBracket | TokenKind::LeftBrace => Self::CallDim,
+            _ => Self::Lowest,
+        }
+    }
+
+    /// The binding power of a construct-expression keyword used in prefix
+    /// position: `print` and `yield`.
+    ///
+    /// These are expressions in PHP but bind looser than every real
+    /// operator — `print $a && $b` is `print ($a && $b)` — so a Pratt
+    /// parser must start their operand at this level rather than at
+    /// [`Precedence::Prefix`]. `yield from` is two tokens; when the parser
+    /// sees `yield` followed by `from` it should use
+    /// [`Precedence::YieldFrom`] instead of the value returned here for
+    /// the bare `yield`.
+    pub fn of_construct(kind: &TokenKind) -> Option<Precedence> {
+        match kind {
+            TokenKind::Print => Some(Self::Print),
+            TokenKind::Yield => Some(Self::Yield),
+            _ => None,
+        }
+    }
+
+    /// The binding power of `kind` when used as a postfix operator.
+    pub fn postfix(kind: &TokenKind) -> Precedence {
+        match kind {
+            TokenKind::PlusPlus | TokenKind::MinusMinus => Self::IncDec,
+            TokenKind::LeftParenthesis | TokenKind::LeftBracket | TokenKind::LeftBrace => Self::CallDim,
+            TokenKind::ColonColon => Self::ObjectAccess,
+            _ => Self::Lowest,
+        }
+    }
+
+    /// Whether operators at this level associate to the left.
+    ///
+    /// Assignment, coalesce, and `**` are right-associative; the conditional
+    /// operator is non-associative and handled in the parser; everything
+    /// else, including the `|>` pipe operator, associates to the left.
+    pub fn is_left_associative(&self) -> bool {
+        !matches!(self, Self::Assignment | Self::Coalesce | Self::Pow)
+    }
+}
+
+/// How operators at one precedence level combine when chained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Associativity {
+    Left,
+    Right,
+    /// Chaining is a parse or semantic error (`$a == $b == $c` is legal but
+    /// ambiguous to humans; `$a < $b < $c` likewise).
+    NonAssociative,
+}
+
+/// One row per infix/postfix operator: the token kind, its canonical
+/// lexeme, its precedence, and its associativity (`None` for operators
+/// whose grouping is handled structurally by the parser, like `?`).
+///
+/// This is the table documentation generators and grammar exporters should
+/// consume instead of hardcoding their own; [`operators`] cross-checks it
+/// against [`Precedence::infix`] so the two cannot drift.
+pub const OPERATORS: &[(TokenKind, &str, Precedence, Option<Associativity>)] = &[
+    (TokenKind::Or, "or", Precedence::KeyOr, Some(Associativity::Left)),
+    (TokenKind::Xor, "xor", Precedence::KeyXor, Some(Associativity::Left)),
+    (TokenKind::And, "and", Precedence::KeyAnd, Some(Associativity::Left)),
+    (TokenKind::Equal, "=", Precedence::Assignment, Some(Associativity::Right)),
+    (TokenKind::PlusEqual, "+=", Precedence::Assignment, Some(Associativity::Right)),
+    (TokenKind::MinusEqual, "-=", Precedence::Assignment, Some(Associativity::Right)),
+    (TokenKind::AsteriskEqual, "*=", Precedence::Assignment, Some(Associativity::Right)),
+    (TokenKind::SlashEqual, "/=", Precedence::Assignment, Some(Associativity::Right)),
+    (TokenKind::PercentEqual, "%=", Precedence::Assignment, Some(Associativity::Right)),
+    (TokenKind::AsteriskAsteriskEqual, "**=", Precedence::Assignment, Some(Associativity::Right)),
+    (TokenKind::DotEqual, ".=", Precedence::Assignment, Some(Associativity::Right)),
+    (TokenKind::AmpersandEqual, "&=", Precedence::Assignment, Some(Associativity::Right)),
+    (TokenKind::PipeEqual, "|=", Precedence::Assignment, Some(Associativity::Right)),
+    (TokenKind::CaretEqual, "^=", Precedence::Assignment, Some(Associativity::Right)),
+    (TokenKind::LessThanLessThanEqual, "<<=", Precedence::Assignment, Some(Associativity::Right)),
+    (TokenKind::GreaterThanGreaterThanEqual, ">>=", Precedence::Assignment, Some(Associativity::Right)),
+    (TokenKind::QuestionQuestionEqual, "??=", Precedence::Assignment, Some(Associativity::Right)),
+    (TokenKind::Question, "?", Precedence::ElvisOrConditional, None),
+    (TokenKind::QuestionColon, "?:", Precedence::ElvisOrConditional, Some(Associativity::NonAssociative)),
+    (TokenKind::QuestionQuestion, "??", Precedence::Coalesce, Some(Associativity::Right)),
+    (TokenKind::PipePipe, "||", Precedence::Or, Some(Associativity::Left)),
+    (TokenKind::AmpersandAmpersand, "&&", Precedence::And, Some(Associativity::Left)),
+    (TokenKind::Pipe, "|", Precedence::BitwiseOr, Some(Associativity::Left)),
+    (TokenKind::Caret, "^", Precedence::BitwiseXor, Some(Associativity::Left)),
+    (TokenKind::Ampersand, "&", Precedence::BitwiseAnd, Some(Associativity::Left)),
+    (TokenKind::EqualEqual, "==", Precedence::Equality, Some(Associativity::NonAssociative)),
+    (TokenKind::BangEqual, "!=", Precedence::Equality, Some(Associativity::NonAssociative)),
+    (TokenKind::EqualEqualEqual, "===", Precedence::Equality, Some(Associativity::NonAssociative)),
+    (TokenKind::BangEqualEqual, "!==", Precedence::Equality, Some(Associativity::NonAssociative)),
+    (TokenKind::LessThanGreaterThan, "<>", Precedence::Equality, Some(Associativity::NonAssociative)),
+    (TokenKind::PipeGreaterThan, "|>", Precedence::Pipe, Some(Associativity::Left)),
+    (TokenKind::LessThan, "<", Precedence::Comparison, Some(Associativity::NonAssociative)),
+    (TokenKind::LessThanEqual, "<=", Precedence::Comparison, Some(Associativity::NonAssociative)),
+    (TokenKind::GreaterThan, ">", Precedence::Comparison, Some(Associativity::NonAssociative)),
+    (TokenKind::GreaterThanEqual, ">=", Precedence::Comparison, Some(Associativity::NonAssociative)),
+    (TokenKind::LessThanEqualGreaterThan, "<=>", Precedence::Comparison, Some(Associativity::NonAssociative)),
+    (TokenKind::Dot, ".", Precedence::Concat, Some(Associativity::Left)),
+    (TokenKind::LessThanLessThan, "<<", Precedence::BitShift, Some(Associativity::Left)),
+    (TokenKind::GreaterThanGreaterThan, ">>", Precedence::BitShift, Some(Associativity::Left)),
+    (TokenKind::Plus, "+", Precedence::AddSub, Some(Associativity::Left)),
+    (TokenKind::Minus, "-", Precedence::AddSub, Some(Associativity::Left)),
+    (TokenKind::Asterisk, "*", Precedence::MulDivMod, Some(Associativity::Left)),
+    (TokenKind::Slash, "/", Precedence::MulDivMod, Some(Associativity::Left)),
+    (TokenKind::Percent, "%", Precedence::MulDivMod, Some(Associativity::Left)),
+    (TokenKind::Instanceof, "instanceof", Precedence::Instanceof, Some(Associativity::NonAssociative)),
+    (TokenKind::AsteriskAsterisk, "**", Precedence::Pow, Some(Associativity::Right)),
+    (TokenKind::MinusGreaterThan, "->", Precedence::ObjectAccess, Some(Associativity::Left)),
+    (TokenKind::QuestionMinusGreaterThan, "?->", Precedence::ObjectAccess, Some(Associativity::Left)),
+    (TokenKind::ColonColon, "::", Precedence::ObjectAccess, Some(Associativity::Left)),
+];
+
+/// The operator table, debug-asserted to agree with [`Precedence::infix`].
+pub fn operators() -> &'static [(TokenKind, &'static str, Precedence, Option<Associativity>)] {
+    debug_assert!(
+        OPERATORS.iter().all(|(kind, _, precedence, _)| Precedence::infix(kind) == *precedence),
+        "OPERATORS disagrees with Precedence::infix"
+    );
+
+    OPERATORS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_agrees_with_infix_precedence() {
+        for (kind, lexeme, precedence, _) in operators() {
+            assert_eq!(Precedence::infix(kind), *precedence, "precedence mismatch for `{lexeme}`");
+        }
+    }
+
+    #[test]
+    fn test_lexemes_are_unique() {
+        for (index, (_, lexeme, _, _)) in OPERATORS.iter().enumerate() {
+            assert!(
+                OPERATORS.iter().skip(index + 1).all(|(_, other, _, _)| other != lexeme),
+                "duplicate lexeme `{lexeme}`"
+            );
+        }
+    }
+
+    #[test]
+    fn test_keyword_operators_round_trip_through_keyword_lookup() {
+        for (kind, lexeme, _, _) in OPERATORS {
+            if lexeme.chars().all(|character| character.is_ascii_alphabetic()) {
+                assert_eq!(
+                    TokenKind::keyword_from_str_ci(lexeme),
+                    Some(*kind),
+                    "keyword lookup mismatch for `{lexeme}`"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_construct_keywords_bind_looser_than_operators() {
+        let print = Precedence::of_construct(&TokenKind::Print).expect("print is a construct");
+        let r#yield = Precedence::of_construct(&TokenKind::Yield).expect("yield is a construct");
+
+        // `print $a && $b` must parse as `print ($a && $b)`: a parser that
+        // starts the print operand at `Precedence::Print` keeps consuming
+        // through every infix operator in the table.
+        for (kind, lexeme, _, _) in OPERATORS {
+            if matches!(kind, TokenKind::Or | TokenKind::Xor | TokenKind::And) {
+                continue; // `print $a and $b` is `(print $a) and $b`.
+            }
+
+            assert!(print < Precedence::infix(kind), "print must bind looser than `{lexeme}`");
+        }
+
+        assert!(print < Precedence::infix(&TokenKind::AmpersandAmpersand));
+        assert!(r#yield < Precedence::infix(&TokenKind::Equal), "yield must bind looser than assignment");
+        assert!(print < r#yield && r#yield < Precedence::YieldFrom);
+    }
+
+    #[test]
+    fn test_non_construct_keywords_have_no_construct_precedence() {
+        assert_eq!(Precedence::of_construct(&TokenKind::New), None);
+        assert_eq!(Precedence::of_construct(&TokenKind::Clone), None);
+        assert_eq!(Precedence::of_construct(&TokenKind::Throw), None);
+    }
+
+    #[test]
+    fn test_associativity_agrees_with_precedence_flag() {
+        for (_, lexeme, precedence, associativity) in OPERATORS {
+            if let Some(Associativity::Right) = associativity {
+                assert!(!precedence.is_left_associative(), "`{lexeme}` marked right-associative at a left level");
+            }
+        }
+    }
+}
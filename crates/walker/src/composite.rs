@@ -0,0 +1,49 @@
+use mago_ast::Node;
+use mago_ast::Program;
+
+use crate::Walker;
+
+/// Runs several walkers in one shared traversal.
+///
+/// Running N lint rules as N full AST traversals wastes most of the time on
+/// redundant tree walks; a composite walker visits each node once and fans
+/// the `walk_in_*` / `walk_out_*` events out to every registered walker, in
+/// registration order on enter and in reverse order on exit (so nesting
+/// semantics hold per walker).
+///
+/// All walkers share one context type `C`. Walkers needing private state
+/// should keep it in `C` behind their own field or map entry; per-walker
+/// context tuples were considered and rejected because they make the
+/// registration API unusable with `dyn` walkers.
+pub struct CompositeWalker<'w, C> {
+    walkers: Vec<&'w dyn Walker<C>>,
+}
+
+impl<'w, C> CompositeWalker<'w, C> {
+    pub fn new(walkers: Vec<&'w dyn Walker<C>>) -> Self {
+        Self { walkers }
+    }
+
+    pub fn push(&mut self, walker: &'w dyn Walker<C>) {
+        self.walkers.push(walker);
+    }
+
+    /// Walk `program` once, dispatching to every registered walker.
+    pub fn walk_program_with_all(&self, program: &Program, context: &mut C) {
+        self.walk_program(program, context);
+    }
+}
+
+impl<C> Walker<C> for CompositeWalker<'_, C> {
+    fn walk_in_node(&self, node: Node<'_>, context: &mut C) {
+        for walker in &self.walkers {
+            walker.walk_in_node(node, context);
+        }
+    }
+
+    fn walk_out_node(&self, node: Node<'_>, context: &mut C) {
+        for walker in self.walkers.iter().rev() {
+            walker.walk_out_node(node, context);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use mago_ast::Node;
+use mago_ast::Program;
+
+/// Lazily yield every node of the program in pre-order.
+///
+/// This is the pull-based complement to the [`Walker`](crate::Walker)
+/// trait: no trait to implement, no context to thread — just an iterator,
+/// so ad-hoc queries compose with the standard adapters:
+///
+/// ```ignore
+/// let calls = iter_nodes(&program)
+///     .filter_map(|node| match node {
+///         Node::FunctionCall(call) => Some(call),
+///         _ => None,
+///     })
+///     .count();
+/// ```
+///
+/// The order matches the `Enter` events of
+/// [`walk_program_iterative`](crate::iterative::walk_program_iterative):
+/// each node before its children, children left to right. Stateful passes
+/// that need exit events or mutable context should keep using the trait.
+pub fn iter_nodes(program: &Program) -> NodeIterator<'_> {
+    NodeIterator { stack: vec![Node::Program(program)] }
+}
+
+/// See [`iter_nodes`].
+pub struct NodeIterator<'a> {
+    stack: Vec<Node<'a>>,
+}
+
+impl<'a> Iterator for NodeIterator<'a> {
+    type Item = Node<'a>;
+
+    fn next(&mut self) -> Option<Node<'a>> {
+        let node = self.stack.pop()?;
+        self.stack.extend(node.children().into_iter().rev());
+        Some(node)
+    }
+}
+
+/// Method-call sugar for [`iter_nodes`], so call sites read
+/// `program.iter_nodes()`.
+pub trait IterNodes {
+    fn iter_nodes(&self) -> NodeIterator<'_>;
+}
+
+impl IterNodes for Program {
+    fn iter_nodes(&self) -> NodeIterator<'_> {
+        iter_nodes(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_ast::NodeKind;
+    use mago_interner::ThreadedInterner;
+    use mago_span::HasSpan;
+
+    use super::*;
+
+    fn parse(interner: &ThreadedInterner, source: &str) -> Program {
+        let (program, error) = mago_parser::parse_source_text(interner, source);
+        assert!(error.is_none(), "test source must parse");
+        program
+    }
+
+    #[test]
+    fn test_yields_nodes_in_pre_order() {
+        let interner = ThreadedInterner::new();
+        let program = parse(&interner, "<?php $a = 1 + 2;");
+
+        let kinds: Vec<NodeKind> = program.iter_nodes().map(|node| node.kind()).collect();
+        assert_eq!(kinds.first(), Some(&NodeKind::Program));
+
+        // Pre-order: every node appears before its children, which for
+        // spans means a parent starts at or before anything inside it
+        // once we fix the parent/descendant pairs — spot-check the
+        // assignment before its operands.
+        let assignment = kinds.iter().position(|kind| *kind == NodeKind::Assignment).expect("has an assignment");
+        let binary = kinds.iter().position(|kind| *kind == NodeKind::Binary).expect("has a binary");
+        assert!(assignment < binary);
+    }
+
+    #[test]
+    fn test_matches_children_exhaustively() {
+        let interner = ThreadedInterner::new();
+        let program = parse(&interner, "<?php function f() { return [1, 2]; } f();");
+
+        // Every node yielded except the root must be reachable as some
+        // yielded node's child; count both sides of that relation.
+        let yielded = program.iter_nodes().count();
+        let children: usize = program.iter_nodes().map(|node| node.children().len()).sum();
+        assert_eq!(yielded, children + 1);
+    }
+
+    #[test]
+    fn test_composes_with_iterator_adapters() {
+        let interner = ThreadedInterner::new();
+        let program = parse(&interner, "<?php $a = 1; $b = 2; $c = $a;");
+
+        let variables = program
+            .iter_nodes()
+            .filter(|node| matches!(node, Node::DirectVariable(_)))
+            .map(|node| node.span().start.offset)
+            .collect::<Vec<_>>();
+
+        assert_eq!(variables.len(), 4);
+        assert!(variables.windows(2).all(|pair| pair[0] < pair[1]), "pre-order yields source order here");
+    }
+}
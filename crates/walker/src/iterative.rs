@@ -0,0 +1,113 @@
+use mago_ast::Node;
+use mago_ast::Program;
+
+use crate::Walker;
+
+/// Walk the program without native recursion.
+///
+/// The generated `walk_*` functions recurse once per node, so deeply nested
+/// expressions — generated code with thousands of chained concatenations or
+/// nested arrays — overflow the stack. This entry point drives the exact
+/// same `walk_in_*` / `walk_out_*` callbacks from an explicit heap-allocated
+/// work stack over the `Node` enum instead: each node is pushed as an
+/// `Enter` event which, when popped, dispatches the enter callback, pushes
+/// the matching `Exit` event, and then pushes the children in reverse so
+/// they are visited left-to-right. Enter/exit ordering is therefore
+/// identical to the recursive traversal.
+pub fn walk_program_iterative<W, C>(walker: &W, program: &Program, context: &mut C)
+where
+    W: Walker<C>,
+{
+    enum Event<'a> {
+        Enter(Node<'a>),
+        Exit(Node<'a>),
+    }
+
+    let mut stack: Vec<Event<'_>> = vec![Event::Enter(Node::Program(program))];
+    while let Some(event) = stack.pop() {
+        match event {
+            Event::Enter(node) => {
+                walker.walk_in_node(node, context);
+                stack.push(Event::Exit(node));
+
+                let children = node.children();
+                for child in children.into_iter().rev() {
+                    stack.push(Event::Enter(child));
+                }
+            }
+            Event::Exit(node) => {
+                walker.walk_out_node(node, context);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_ast::Node;
+    use mago_ast::NodeKind;
+    use mago_interner::ThreadedInterner;
+
+    use super::walk_program_iterative;
+    use crate::Walker;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Event {
+        Enter(NodeKind),
+        Exit(NodeKind),
+    }
+
+    struct Recorder;
+
+    impl Walker<Vec<Event>> for Recorder {
+        fn walk_in_node(&self, node: Node<'_>, context: &mut Vec<Event>) {
+            context.push(Event::Enter(node.kind()));
+        }
+
+        fn walk_out_node(&self, node: Node<'_>, context: &mut Vec<Event>) {
+            context.push(Event::Exit(node.kind()));
+        }
+    }
+
+    fn parse(interner: &ThreadedInterner, code: &str) -> mago_ast::Program {
+        let (program, error) = mago_parser::parse_source_text(interner, code);
+        assert!(error.is_none(), "fixture must parse: {error:?}");
+        program
+    }
+
+    #[test]
+    fn test_iterative_ordering_matches_recursive() {
+        let interner = ThreadedInterner::new();
+        let program = parse(
+            &interner,
+            "<?php $a = [1, [2, [3, fn () => match (true) { default => 1 + 2 * 3 }]]]; foo($a)->bar(baz(1 . 2 . 3));",
+        );
+
+        let mut recursive = Vec::new();
+        Recorder.walk_program(&program, &mut recursive);
+
+        let mut iterative = Vec::new();
+        walk_program_iterative(&Recorder, &program, &mut iterative);
+
+        assert_eq!(recursive, iterative);
+    }
+
+    #[test]
+    fn test_survives_100k_deep_concatenation_chain() {
+        let interner = ThreadedInterner::new();
+        let mut code = String::with_capacity(1 << 20);
+        code.push_str("<?php $x = 'a'");
+        for _ in 0..100_000 {
+            code.push_str(" . 'a'");
+        }
+        code.push(';');
+
+        let program = parse(&interner, &code);
+
+        // The recursive walker overflows the stack on this fixture; the
+        // iterative one must complete.
+        let mut events = Vec::new();
+        walk_program_iterative(&Recorder, &program, &mut events);
+        assert!(!events.is_empty());
+    }
+}
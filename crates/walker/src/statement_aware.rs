@@ -0,0 +1,130 @@
+use mago_ast::Expression;
+use mago_ast::Node;
+use mago_ast::Program;
+use mago_ast::Statement;
+
+/// A walker whose expression hooks receive the nearest enclosing
+/// statement.
+///
+/// Rules firing on an expression very often need "the statement this
+/// expression lives in" — to delete the whole statement in a fix, or to
+/// anchor an annotation on it. Tracking that through a context field is
+/// error-prone (every rule must remember to update it); this walker
+/// updates it at each statement boundary during traversal instead, so the
+/// pairing is correct by construction.
+pub trait StatementAwareWalker<C> {
+    /// Called when entering an expression; `statement` is the innermost
+    /// statement containing it.
+    fn walk_in_expression(&self, expression: &Expression, statement: &Statement, context: &mut C) {
+        let _ = (expression, statement, context);
+    }
+
+    /// Called when leaving an expression.
+    fn walk_out_expression(&self, expression: &Expression, statement: &Statement, context: &mut C) {
+        let _ = (expression, statement, context);
+    }
+
+    /// Called when entering a statement.
+    fn walk_in_statement(&self, statement: &Statement, context: &mut C) {
+        let _ = (statement, context);
+    }
+
+    /// Called when leaving a statement.
+    fn walk_out_statement(&self, statement: &Statement, context: &mut C) {
+        let _ = (statement, context);
+    }
+}
+
+/// Drive a [`StatementAwareWalker`] over the program.
+///
+/// Statements are tracked on an explicit stack: entering a statement
+/// pushes it, leaving pops it, and expression hooks read the top. The
+/// traversal order is identical to
+/// [`walk_program_iterative`](crate::iterative::walk_program_iterative).
+pub fn walk_program_with_statements<W, C>(walker: &W, program: &Program, context: &mut C)
+where
+    W: StatementAwareWalker<C>,
+{
+    enum Event<'a> {
+        Enter(Node<'a>),
+        Exit(Node<'a>),
+    }
+
+    let mut statements: Vec<&Statement> = Vec::new();
+    let mut stack: Vec<Event<'_>> = vec![Event::Enter(Node::Program(program))];
+
+    while let Some(event) = stack.pop() {
+        match event {
+            Event::Enter(node) => {
+                match node {
+                    Node::Statement(statement) => {
+                        statements.push(statement);
+                        walker.walk_in_statement(statement, context);
+                    }
+                    Node::Expression(expression) => {
+                        if let Some(statement) = statements.last() {
+                            walker.walk_in_expression(expression, statement, context);
+                        }
+                    }
+                    _ => {}
+                }
+
+                stack.push(Event::Exit(node));
+                for child in node.children().into_iter().rev() {
+                    stack.push(Event::Enter(child));
+                }
+            }
+            Event::Exit(node) => match node {
+                Node::Statement(statement) => {
+                    walker.walk_out_statement(statement, context);
+                    statements.pop();
+                }
+                Node::Expression(expression) => {
+                    if let Some(statement) = statements.last() {
+                        walker.walk_out_expression(expression, statement, context);
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mago_interner::ThreadedInterner;
+    use mago_span::HasSpan;
+
+    use super::*;
+
+    /// Records, for every variable expression, the span of the statement
+    /// reported as its owner.
+    struct OwnerRecorder;
+
+    impl StatementAwareWalker<Vec<(usize, usize)>> for OwnerRecorder {
+        fn walk_in_expression(&self, expression: &Expression, statement: &Statement, context: &mut Vec<(usize, usize)>) {
+            if matches!(expression, Expression::Variable(_)) {
+                context.push((expression.span().start.offset, statement.span().start.offset));
+            }
+        }
+    }
+
+    #[test]
+    fn test_expressions_are_paired_with_their_innermost_statement() {
+        let interner = ThreadedInterner::new();
+        let (program, error) =
+            mago_parser::parse_source_text(&interner, "<?php $a = 1; if ($b) { $c = 2; }");
+        assert!(error.is_none());
+
+        let mut pairs = Vec::new();
+        walk_program_with_statements(&OwnerRecorder, &program, &mut pairs);
+
+        // `$a` belongs to the first expression statement, `$b` to the `if`,
+        // and `$c` to the inner expression statement — not the `if`.
+        let owners: Vec<usize> = pairs.iter().map(|(_, owner)| *owner).collect();
+        assert_eq!(owners.len(), 3);
+        assert_eq!(owners[0], 6);
+        assert_eq!(owners[1], 14);
+        assert_eq!(owners[2], 24);
+    }
+}
@@ -0,0 +1,141 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use mago_ast::Node;
+use mago_ast::Program;
+use mago_ast::Variable;
+use mago_interner::ThreadedInterner;
+use mago_lexer::Lexer;
+use mago_lexer::input::Input;
+use mago_source::SourceManager;
+use mago_span::HasSpan;
+
+use crate::error::Error;
+
+/// Dump the parsed AST, or the token stream, of a PHP file.
+///
+/// This exists for contributors writing rules and for users reporting parser
+/// bugs: it prints exactly what mago sees, with spans, so span-accuracy
+/// issues are easy to demonstrate.
+#[derive(Parser, Debug)]
+#[command(
+    name = "ast",
+    about = "Print the AST or token stream of a PHP file",
+    long_about = "Parse a PHP file (or an inline expression) and print a readable tree of the \
+                  resulting AST, including node kinds, spans, and resolved identifier text. \
+                  Useful when writing rules or reporting parser bugs."
+)]
+pub struct AstCommand {
+    /// The PHP file to parse.
+    #[arg(required_unless_present = "expr")]
+    pub file: Option<PathBuf>,
+
+    /// Parse an inline code snippet via the expression entry point instead of a file.
+    #[arg(long, value_name = "CODE", conflicts_with = "file")]
+    pub expr: Option<String>,
+
+    /// Dump the token stream instead of the AST.
+    #[arg(long)]
+    pub tokens: bool,
+
+    /// Include attached comments and other trivia in the output.
+    #[arg(long)]
+    pub include_trivia: bool,
+
+    /// The output format to use.
+    #[arg(long, value_enum, default_value_t = AstFormat::Tree)]
+    pub format: AstFormat,
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AstFormat {
+    Tree,
+    Json,
+}
+
+pub fn execute(command: AstCommand) -> Result<ExitCode, Error> {
+    let interner = ThreadedInterner::new();
+    let manager = SourceManager::new(interner.clone());
+
+    let source_id = match (&command.file, &command.expr) {
+        (Some(file), _) => manager.insert_path(file.clone())?,
+        (None, Some(code)) => manager.insert_content("<expr>".to_owned(), format!("<?php {code};"), false),
+        (None, None) => unreachable!("clap enforces file or --expr"),
+    };
+
+    let source = manager.load(&source_id)?;
+
+    if command.tokens {
+        let mut lexer = Lexer::new(&interner, Input::new(source_id, source.content(&interner).as_bytes()));
+        while let Some(token) = lexer.advance() {
+            let token = token?;
+            if !command.include_trivia && token.kind.is_trivia() {
+                continue;
+            }
+
+            match command.format {
+                AstFormat::Tree => {
+                    println!(
+                        "{:?} @ {}..{} {:?}",
+                        token.kind,
+                        token.span.start.offset,
+                        token.span.end.offset,
+                        interner.lookup(&token.value),
+                    );
+                }
+                AstFormat::Json => println!("{}", serde_json::to_string(&token)?),
+            }
+        }
+
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let (program, error) = mago_parser::parse_source(&interner, &source);
+    if let Some(error) = &error {
+        eprintln!("parse error: {error}");
+    }
+
+    match command.format {
+        AstFormat::Tree => print_tree(&interner, &program, command.include_trivia),
+        // The JSON form reuses the AST's serde derives, so it stays in sync
+        // with the node definitions automatically.
+        AstFormat::Json => println!("{}", serde_json::to_string_pretty(&program)?),
+    }
+
+    Ok(if error.is_some() { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+fn print_tree(interner: &ThreadedInterner, program: &Program, include_trivia: bool) {
+    fn walk(interner: &ThreadedInterner, node: Node<'_>, depth: usize, include_trivia: bool) {
+        let span = node.span();
+        let mut line = format!("{}{:?} @ {}..{}", "  ".repeat(depth), node.kind(), span.start.offset, span.end.offset);
+        match node {
+            Node::Identifier(identifier) => {
+                line.push_str(&format!(" `{}`", interner.lookup(&identifier.value())));
+            }
+            Node::Variable(Variable::Direct(variable)) => {
+                line.push_str(&format!(" `{}`", interner.lookup(&variable.name)));
+            }
+            Node::LiteralString(literal) => {
+                line.push_str(&format!(" {}", interner.lookup(&literal.value)));
+            }
+            _ => {}
+        }
+
+        println!("{line}");
+        for child in node.children() {
+            walk(interner, child, depth + 1, include_trivia);
+        }
+    }
+
+    walk(interner, Node::Program(program), 0, include_trivia);
+
+    if include_trivia {
+        for trivia in program.trivia.iter() {
+            let span = trivia.span();
+            println!("trivia {:?} @ {}..{}", trivia.kind, span.start.offset, span.end.offset);
+        }
+    }
+}
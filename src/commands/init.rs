@@ -0,0 +1,104 @@
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use crate::error::Error;
+use crate::init::detect_frameworks;
+use crate::init::read_composer;
+use crate::init::translate::translate_existing_configs;
+
+/// Generate a `mago.toml` tailored to the current project.
+#[derive(Parser, Debug)]
+#[command(
+    name = "init",
+    about = "Generate a mago.toml tailored to the detected project",
+    long_about = "Inspect the working directory (composer.json, framework markers, and existing \
+                  .php-cs-fixer.php / phpcs.xml configurations) and write a commented mago.toml \
+                  that matches the project."
+)]
+pub struct InitCommand {
+    /// The directory to inspect; defaults to the current working directory.
+    #[arg(long, short = 'd')]
+    pub directory: Option<PathBuf>,
+
+    /// Overwrite an existing `mago.toml`.
+    #[arg(long)]
+    pub force: bool,
+}
+
+pub fn execute(command: InitCommand) -> Result<ExitCode, Error> {
+    let root = match command.directory {
+        Some(directory) => directory,
+        None => std::env::current_dir()?,
+    };
+
+    let target = root.join("mago.toml");
+    if target.exists() && !command.force {
+        eprintln!("mago.toml already exists; pass --force to overwrite it.");
+
+        return Ok(ExitCode::FAILURE);
+    }
+
+    let composer = read_composer(&root);
+    let frameworks = detect_frameworks(&root);
+    let translated = translate_existing_configs(&root);
+
+    let mut config = String::new();
+    writeln!(config, "# Generated by `mago init`.")?;
+    if let Some(constraint) = &composer.php_constraint {
+        writeln!(config, "# PHP version constraint taken from composer.json: {constraint}")?;
+        writeln!(config, "php_version = \"{}\"", minimum_version(constraint))?;
+    }
+
+    writeln!(config)?;
+    writeln!(config, "[source]")?;
+    if composer.source_paths.is_empty() {
+        writeln!(config, "paths = [\".\"]")?;
+    } else {
+        let paths: Vec<String> = composer.source_paths.iter().map(|path| format!("\"{path}\"")).collect();
+        writeln!(config, "paths = [{}]", paths.join(", "))?;
+    }
+
+    let mut excludes: Vec<&str> = vec!["vendor/"];
+    for framework in &frameworks {
+        writeln!(config, "# {} detected.", framework.name())?;
+        excludes.extend(framework.suggested_excludes());
+    }
+
+    let excludes: Vec<String> = excludes.iter().map(|path| format!("\"{path}\"")).collect();
+    writeln!(config, "excludes = [{}]", excludes.join(", "))?;
+
+    if !translated.is_empty() {
+        writeln!(config)?;
+        writeln!(config, "[format]")?;
+        for setting in &translated {
+            writeln!(config, "# Mapped from {}.", setting.origin)?;
+            let key = setting.key.strip_prefix("format.").unwrap_or(&setting.key);
+            writeln!(config, "{key} = {}", setting.value)?;
+        }
+    }
+
+    std::fs::write(&target, config)?;
+    println!("Wrote {}.", target.display());
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Extract the lowest version mentioned in a composer constraint such as
+/// `^8.2 || ^8.3`, falling back to the constraint text itself.
+fn minimum_version(constraint: &str) -> String {
+    constraint
+        .split(['|', ' ', ','])
+        .filter_map(|part| {
+            let part = part.trim_start_matches(['^', '~', '>', '=', '<']);
+            let mut segments = part.splitn(3, '.');
+            let major: u32 = segments.next()?.parse().ok()?;
+            let minor: u32 = segments.next().and_then(|minor| minor.parse().ok()).unwrap_or(0);
+
+            Some((major, minor))
+        })
+        .min()
+        .map_or_else(|| constraint.to_owned(), |(major, minor)| format!("{major}.{minor}"))
+}
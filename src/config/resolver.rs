@@ -0,0 +1,241 @@
+//! Layered configuration resolution.
+//!
+//! Settings are merged from four layers, later layers winning:
+//!
+//! 1. built-in defaults,
+//! 2. the `mago.toml` configuration file,
+//! 3. environment variables (`MAGO_` prefix, `__` as the section separator,
+//!    e.g. `MAGO_LINTER__LEVEL=error`),
+//! 4. repeated `--config key=value` flags (dotted keys, e.g.
+//!    `--config linter.rules.no-debug-calls.level=off`).
+//!
+//! The merged `toml::Value` is what the typed `Configuration` is
+//! deserialized from, so every key automatically supports overrides.
+//! Provenance is tracked per key so `mago config show --resolved` can
+//! annotate where each effective value came from.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use toml::Value;
+
+/// The layer a value was contributed by, in precedence order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Layer {
+    Default,
+    File,
+    Environment,
+    Flag,
+}
+
+impl fmt::Display for Layer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Layer::Default => write!(f, "built-in default"),
+            Layer::File => write!(f, "configuration file"),
+            Layer::Environment => write!(f, "environment variable"),
+            Layer::Flag => write!(f, "--config flag"),
+        }
+    }
+}
+
+/// An error produced while applying an override, carrying enough context to
+/// point at the offending layer and key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolveError {
+    pub layer: Layer,
+    pub key: String,
+    pub message: String,
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid value for `{}` from {}: {}", self.key, self.layer, self.message)
+    }
+}
+
+impl std::error::Error for ResolveError {}
+
+/// A layered configuration resolver.
+#[derive(Debug, Default)]
+pub struct Resolver {
+    value: Value,
+    provenance: HashMap<String, Layer>,
+}
+
+impl Resolver {
+    /// Start from the built-in defaults.
+    pub fn new(defaults: Value) -> Self {
+        let mut resolver = Self { value: Value::Table(Default::default()), provenance: HashMap::new() };
+        resolver.merge(defaults, Layer::Default);
+        resolver
+    }
+
+    /// Merge the parsed configuration file.
+    pub fn with_file(mut self, file: Value) -> Self {
+        self.merge(file, Layer::File);
+        self
+    }
+
+    /// Apply `MAGO_`-prefixed environment variables from `variables`.
+    pub fn with_environment(
+        mut self,
+        variables: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<Self, ResolveError> {
+        for (name, raw) in variables {
+            let Some(rest) = name.strip_prefix("MAGO_") else {
+                continue;
+            };
+
+            let key = rest.to_ascii_lowercase().split("__").collect::<Vec<_>>().join(".");
+            let value = coerce(&raw, Layer::Environment, &key)?;
+            self.set(&key, value, Layer::Environment);
+        }
+
+        Ok(self)
+    }
+
+    /// Apply repeated `--config key=value` flags.
+    pub fn with_flags<'a>(mut self, flags: impl IntoIterator<Item = &'a str>) -> Result<Self, ResolveError> {
+        for flag in flags {
+            let Some((key, raw)) = flag.split_once('=') else {
+                return Err(ResolveError {
+                    layer: Layer::Flag,
+                    key: flag.to_owned(),
+                    message: "expected `key=value`".to_owned(),
+                });
+            };
+
+            let value = coerce(raw, Layer::Flag, key)?;
+            self.set(key, value, Layer::Flag);
+        }
+
+        Ok(self)
+    }
+
+    /// The effective merged configuration.
+    pub fn resolved(&self) -> &Value {
+        &self.value
+    }
+
+    /// The layer that contributed the effective value of `key`, if set.
+    pub fn provenance(&self, key: &str) -> Option<Layer> {
+        self.provenance.get(key).copied()
+    }
+
+    /// All keys with their provenance, sorted, for `config show --resolved`.
+    pub fn annotations(&self) -> Vec<(String, Layer)> {
+        let mut entries: Vec<_> = self.provenance.iter().map(|(key, layer)| (key.clone(), *layer)).collect();
+        entries.sort();
+        entries
+    }
+
+    fn merge(&mut self, incoming: Value, layer: Layer) {
+        fn walk(target: &mut Value, incoming: Value, prefix: &str, layer: Layer, provenance: &mut HashMap<String, Layer>) {
+            match incoming {
+                Value::Table(table) => {
+                    if !matches!(target, Value::Table(_)) {
+                        *target = Value::Table(Default::default());
+                    }
+
+                    let Value::Table(target) = target else { unreachable!() };
+                    for (name, value) in table {
+                        let key = if prefix.is_empty() { name.clone() } else { format!("{prefix}.{name}") };
+                        walk(target.entry(name).or_insert(Value::Boolean(false)), value, &key, layer, provenance);
+                    }
+                }
+                leaf => {
+                    *target = leaf;
+                    provenance.insert(prefix.to_owned(), layer);
+                }
+            }
+        }
+
+        let mut root = std::mem::replace(&mut self.value, Value::Boolean(false));
+        walk(&mut root, incoming, "", layer, &mut self.provenance);
+        self.value = root;
+    }
+
+    fn set(&mut self, key: &str, value: Value, layer: Layer) {
+        let mut wrapped = value;
+        for segment in key.rsplit('.') {
+            let mut table = toml::map::Map::new();
+            table.insert(segment.to_owned(), wrapped);
+            wrapped = Value::Table(table);
+        }
+
+        self.merge(wrapped, layer);
+    }
+}
+
+/// Coerce a raw override string into a TOML value.
+///
+/// The raw text is parsed as a TOML literal first, so integers, booleans,
+/// and arrays (`["src", "tests"]`) work; anything that fails to parse is
+/// taken as a plain string, which covers unquoted values like `error`.
+fn coerce(raw: &str, layer: Layer, key: &str) -> Result<Value, ResolveError> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return Err(ResolveError { layer, key: key.to_owned(), message: "value is empty".to_owned() });
+    }
+
+    match format!("value = {raw}").parse::<Value>() {
+        Ok(Value::Table(mut table)) => Ok(table.remove("value").expect("parsed key must exist")),
+        _ => Ok(Value::String(raw.to_owned())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn defaults() -> Value {
+        "print_width = 120\n[linter]\nlevel = \"warning\"\n[source]\npaths = [\".\"]"
+            .parse()
+            .expect("defaults must parse")
+    }
+
+    #[test]
+    fn test_precedence_defaults_file_env_flag() {
+        let file: Value = "[linter]\nlevel = \"note\"".parse().unwrap();
+        let resolver = Resolver::new(defaults())
+            .with_file(file)
+            .with_environment([("MAGO_LINTER__LEVEL".to_owned(), "error".to_owned())])
+            .unwrap()
+            .with_flags(["linter.level=off"])
+            .unwrap();
+
+        let level = resolver.resolved().get("linter").and_then(|linter| linter.get("level"));
+        assert_eq!(level, Some(&Value::String("off".to_owned())));
+        assert_eq!(resolver.provenance("linter.level"), Some(Layer::Flag));
+        assert_eq!(resolver.provenance("print_width"), Some(Layer::Default));
+    }
+
+    #[test]
+    fn test_arrays_and_nested_tables() {
+        let resolver = Resolver::new(defaults())
+            .with_environment([("MAGO_SOURCE__PATHS".to_owned(), "[\"src\", \"tests\"]".to_owned())])
+            .unwrap()
+            .with_flags(["linter.rules.no-debug-calls.level=off"])
+            .unwrap();
+
+        let paths =
+            resolver.resolved().get("source").and_then(|source| source.get("paths")).and_then(Value::as_array).unwrap();
+        assert_eq!(paths.len(), 2);
+
+        let level = resolver
+            .resolved()
+            .get("linter")
+            .and_then(|linter| linter.get("rules"))
+            .and_then(|rules| rules.get("no-debug-calls"))
+            .and_then(|rule| rule.get("level"));
+        assert_eq!(level, Some(&Value::String("off".to_owned())));
+    }
+
+    #[test]
+    fn test_malformed_flag_reports_layer_and_key() {
+        let error = Resolver::new(defaults()).with_flags(["linter.level"]).unwrap_err();
+        assert_eq!(error.layer, Layer::Flag);
+        assert!(error.to_string().contains("linter.level"));
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// An inclusive range of 1-based line numbers on the *new* side of a diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl LineRange {
+    /// Widen the range by `context` lines on each side, saturating at line 1.
+    pub fn widen(self, context: usize) -> Self {
+        Self { start: self.start.saturating_sub(context).max(1), end: self.end.saturating_add(context) }
+    }
+
+    /// Whether this range intersects the inclusive line range `[start, end]`.
+    pub fn intersects(self, start: usize, end: usize) -> bool {
+        self.start <= end && start <= self.end
+    }
+}
+
+/// The set of lines added or modified per file, extracted from a unified diff.
+///
+/// Paths are the *new* paths of the diff, so renames are tracked under their
+/// destination and deleted files are absent entirely.
+#[derive(Debug, Default, Clone)]
+pub struct ChangedLines {
+    ranges: HashMap<PathBuf, Vec<LineRange>>,
+}
+
+impl ChangedLines {
+    /// Parse the output of `git diff` (any context width, including `-U0`).
+    ///
+    /// Only hunk information for the new side of the diff is retained: the
+    /// `+++` header determines the file, and each `@@ -a,b +c,d @@` header
+    /// contributes the range `[c, c + d - 1]`. Hunks that only delete lines
+    /// (`d == 0`) still contribute a single anchor line so that issues right
+    /// at a deletion site are kept. New files and renames need no special
+    /// handling beyond using the `+++` path; `/dev/null` targets are skipped.
+    pub fn parse(diff: &str) -> Self {
+        let mut ranges: HashMap<PathBuf, Vec<LineRange>> = HashMap::new();
+        let mut current: Option<PathBuf> = None;
+
+        for line in diff.lines() {
+            if let Some(path) = line.strip_prefix("+++ ") {
+                let path = path.strip_suffix('\t').unwrap_or(path);
+                current = if path == "/dev/null" {
+                    None
+                } else {
+                    Some(PathBuf::from(path.strip_prefix("b/").unwrap_or(path)))
+                };
+
+                continue;
+            }
+
+            let Some(path) = current.as_ref() else {
+                continue;
+            };
+
+            if let Some(range) = parse_hunk_header(line) {
+                ranges.entry(path.clone()).or_default().push(range);
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Whether any changed range of `path`, widened by `context` lines,
+    /// intersects the inclusive line range `[start_line, end_line]`.
+    pub fn intersects(&self, path: &Path, start_line: usize, end_line: usize, context: usize) -> bool {
+        self.ranges
+            .get(path)
+            .is_some_and(|ranges| ranges.iter().any(|range| range.widen(context).intersects(start_line, end_line)))
+    }
+
+    /// Whether the diff touched `path` at all.
+    pub fn contains_file(&self, path: &Path) -> bool {
+        self.ranges.contains_key(path)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+/// Parse a `@@ -a,b +c,d @@` hunk header into the new-side line range.
+fn parse_hunk_header(line: &str) -> Option<LineRange> {
+    let rest = line.strip_prefix("@@ ")?;
+    let (ranges, _) = rest.split_once(" @@")?;
+    let (_, new_side) = ranges.split_once(" +")?;
+    let new_side = new_side.split_whitespace().next()?;
+
+    let (start, count) = match new_side.split_once(',') {
+        Some((start, count)) => (start.parse().ok()?, count.parse().ok()?),
+        None => (new_side.parse().ok()?, 1usize),
+    };
+
+    if count == 0 {
+        // A pure deletion: anchor on the line preceding the removed block.
+        return Some(LineRange { start: start.max(1), end: start.max(1) });
+    }
+
+    Some(LineRange { start, end: start + count - 1 })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hunk_header() {
+        assert_eq!(parse_hunk_header("@@ -10,3 +12,4 @@"), Some(LineRange { start: 12, end: 15 }));
+        assert_eq!(parse_hunk_header("@@ -10 +12 @@ fn context"), Some(LineRange { start: 12, end: 12 }));
+        assert_eq!(parse_hunk_header("@@ -10,2 +9,0 @@"), Some(LineRange { start: 9, end: 9 }));
+        assert_eq!(parse_hunk_header("not a hunk"), None);
+    }
+
+    #[test]
+    fn test_parse_diff_with_rename_and_new_file() {
+        let diff = concat!(
+            "diff --git a/src/old.php b/src/new.php\n",
+            "rename from src/old.php\n",
+            "rename to src/new.php\n",
+            "--- a/src/old.php\n",
+            "+++ b/src/new.php\n",
+            "@@ -5,0 +6,2 @@\n",
+            "+line\n",
+            "+line\n",
+            "diff --git a/src/added.php b/src/added.php\n",
+            "--- /dev/null\n",
+            "+++ b/src/added.php\n",
+            "@@ -0,0 +1,3 @@\n",
+            "+a\n+b\n+c\n",
+            "diff --git a/src/deleted.php b/src/deleted.php\n",
+            "--- a/src/deleted.php\n",
+            "+++ /dev/null\n",
+            "@@ -1,3 +0,0 @@\n",
+        );
+
+        let changed = ChangedLines::parse(diff);
+        assert!(changed.intersects(Path::new("src/new.php"), 6, 7, 0));
+        assert!(!changed.intersects(Path::new("src/new.php"), 8, 10, 0));
+        assert!(changed.intersects(Path::new("src/added.php"), 1, 1, 0));
+        assert!(!changed.contains_file(Path::new("src/deleted.php")));
+        assert!(!changed.contains_file(Path::new("src/old.php")));
+    }
+
+    #[test]
+    fn test_context_widens_ranges() {
+        let diff = "+++ b/a.php\n@@ -1,1 +10,1 @@\n";
+        let changed = ChangedLines::parse(diff);
+
+        assert!(!changed.intersects(Path::new("a.php"), 7, 8, 0));
+        assert!(changed.intersects(Path::new("a.php"), 7, 8, 2));
+        assert!(changed.intersects(Path::new("a.php"), 12, 14, 2));
+    }
+}
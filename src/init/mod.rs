@@ -0,0 +1,91 @@
+pub mod translate;
+
+use std::path::Path;
+
+/// Frameworks we recognize during `mago init`, each carrying the excludes
+/// that are almost always correct for that ecosystem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Framework {
+    Laravel,
+    Symfony,
+    WordPress,
+}
+
+impl Framework {
+    /// Paths that should be excluded from analysis for this framework.
+    pub fn suggested_excludes(&self) -> &'static [&'static str] {
+        match self {
+            Self::Laravel => &["storage/", "bootstrap/cache/"],
+            Self::Symfony => &["var/cache/", "var/log/"],
+            Self::WordPress => &["wp-content/uploads/", "wp-content/cache/"],
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Laravel => "Laravel",
+            Self::Symfony => "Symfony",
+            Self::WordPress => "WordPress",
+        }
+    }
+}
+
+/// Detect framework markers in the project root.
+pub fn detect_frameworks(root: &Path) -> Vec<Framework> {
+    let mut frameworks = Vec::new();
+    if root.join("artisan").exists() {
+        frameworks.push(Framework::Laravel);
+    }
+
+    if root.join("symfony.lock").exists() || root.join("config/bundles.php").exists() {
+        frameworks.push(Framework::Symfony);
+    }
+
+    if root.join("wp-config.php").exists() || root.join("wp-content").is_dir() {
+        frameworks.push(Framework::WordPress);
+    }
+
+    frameworks
+}
+
+/// The PHP version constraint and autoload paths read from `composer.json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComposerProject {
+    pub php_constraint: Option<String>,
+    pub source_paths: Vec<String>,
+}
+
+/// Read the pieces of `composer.json` that `mago init` cares about: the
+/// `require.php` constraint and the PSR-4 / classmap autoload directories.
+pub fn read_composer(root: &Path) -> ComposerProject {
+    let Ok(content) = std::fs::read_to_string(root.join("composer.json")) else {
+        return ComposerProject::default();
+    };
+
+    let Ok(manifest) = serde_json::from_str::<serde_json::Value>(&content) else {
+        return ComposerProject::default();
+    };
+
+    let php_constraint =
+        manifest.pointer("/require/php").and_then(|value| value.as_str()).map(|constraint| constraint.to_owned());
+
+    let mut source_paths = Vec::new();
+    for section in ["/autoload/psr-4", "/autoload-dev/psr-4"] {
+        if let Some(map) = manifest.pointer(section).and_then(|value| value.as_object()) {
+            for path in map.values() {
+                match path {
+                    serde_json::Value::String(path) => source_paths.push(path.clone()),
+                    serde_json::Value::Array(paths) => {
+                        source_paths.extend(paths.iter().filter_map(|path| path.as_str().map(str::to_owned)));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    source_paths.sort();
+    source_paths.dedup();
+
+    ComposerProject { php_constraint, source_paths }
+}
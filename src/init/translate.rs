@@ -0,0 +1,167 @@
+//! Translation of well-known settings from other PHP tooling configurations
+//! into `mago.toml` entries.
+//!
+//! This is a real mapping layer rather than string templating: each source
+//! tool gets a parser for the handful of settings we can faithfully carry
+//! over, and every translated entry records where it came from so the
+//! generated config can annotate it with a comment.
+
+use std::path::Path;
+
+/// A single setting translated from another tool's configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslatedSetting {
+    /// The `mago.toml` key, e.g. `format.print_width`.
+    pub key: String,
+    /// The TOML-formatted value.
+    pub value: String,
+    /// Human-readable provenance, e.g. `.php-cs-fixer.php: line_length`.
+    pub origin: String,
+}
+
+/// Translate settings from every recognized tool configuration in `root`.
+pub fn translate_existing_configs(root: &Path) -> Vec<TranslatedSetting> {
+    let mut settings = Vec::new();
+
+    for candidate in [".php-cs-fixer.php", ".php-cs-fixer.dist.php"] {
+        if let Ok(content) = std::fs::read_to_string(root.join(candidate)) {
+            settings.extend(translate_php_cs_fixer(candidate, &content));
+        }
+    }
+
+    for candidate in ["phpcs.xml", "phpcs.xml.dist"] {
+        if let Ok(content) = std::fs::read_to_string(root.join(candidate)) {
+            settings.extend(translate_phpcs(candidate, &content));
+        }
+    }
+
+    settings
+}
+
+/// Map a handful of well-known `.php-cs-fixer.php` rules onto mago settings.
+///
+/// The fixer config is PHP code, so this intentionally does no evaluation:
+/// it looks for the literal rule names with literal scalar values, which is
+/// how the overwhelming majority of real configs spell them.
+pub fn translate_php_cs_fixer(file: &str, content: &str) -> Vec<TranslatedSetting> {
+    let mut settings = Vec::new();
+
+    if let Some(value) = find_php_scalar(content, "'array_syntax'", "'syntax'") {
+        let short = value.contains("short");
+        settings.push(TranslatedSetting {
+            key: "format.array_style".to_owned(),
+            value: if short { "\"short\"".to_owned() } else { "\"long\"".to_owned() },
+            origin: format!("{file}: array_syntax"),
+        });
+    }
+
+    if content.contains("'trailing_comma_in_multiline'") {
+        let enabled = !find_php_scalar(content, "'trailing_comma_in_multiline'", "").is_some_and(|v| v == "false");
+        settings.push(TranslatedSetting {
+            key: "format.trailing_comma".to_owned(),
+            value: enabled.to_string(),
+            origin: format!("{file}: trailing_comma_in_multiline"),
+        });
+    }
+
+    settings
+}
+
+/// Map a handful of well-known `phpcs.xml` properties onto mago settings.
+pub fn translate_phpcs(file: &str, content: &str) -> Vec<TranslatedSetting> {
+    let mut settings = Vec::new();
+
+    if let Some(value) = find_xml_property(content, "lineLimit").or_else(|| find_xml_property(content, "maxLineLength"))
+    {
+        settings.push(TranslatedSetting {
+            key: "format.print_width".to_owned(),
+            value,
+            origin: format!("{file}: Generic.Files.LineLength"),
+        });
+    }
+
+    if content.contains("Generic.Arrays.DisallowLongArraySyntax") {
+        settings.push(TranslatedSetting {
+            key: "format.array_style".to_owned(),
+            value: "\"short\"".to_owned(),
+            origin: format!("{file}: Generic.Arrays.DisallowLongArraySyntax"),
+        });
+    }
+
+    settings
+}
+
+/// Find `key => scalar` (optionally nested under `outer => [...]`) in PHP
+/// configuration source and return the scalar as text.
+fn find_php_scalar(content: &str, outer: &str, inner: &str) -> Option<String> {
+    let start = content.find(outer)?;
+    let haystack = &content[start..];
+    let haystack = if inner.is_empty() {
+        haystack
+    } else {
+        let inner_at = haystack.find(inner)?;
+        &haystack[inner_at + inner.len()..]
+    };
+
+    let arrow = haystack.find("=>")?;
+    let value = haystack[arrow + 2..]
+        .trim_start()
+        .split(|c: char| c == ',' || c == ']' || c == ')' || c == '\n')
+        .next()?
+        .trim();
+
+    if value.is_empty() { None } else { Some(value.trim_matches('\'').trim_matches('"').to_owned()) }
+}
+
+/// Find `<property name="NAME" value="..."/>` in phpcs XML.
+fn find_xml_property(content: &str, name: &str) -> Option<String> {
+    let needle = format!("name=\"{name}\"");
+    let at = content.find(&needle)?;
+    let rest = &content[at + needle.len()..];
+    let value_at = rest.find("value=\"")?;
+    let rest = &rest[value_at + 7..];
+    let end = rest.find('"')?;
+
+    Some(rest[..end].to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_php_cs_fixer_array_syntax_and_trailing_comma() {
+        let config = r#"
+            return (new PhpCsFixer\Config())->setRules([
+                'array_syntax' => ['syntax' => 'short'],
+                'trailing_comma_in_multiline' => true,
+            ]);
+        "#;
+
+        let settings = translate_php_cs_fixer(".php-cs-fixer.php", config);
+        assert!(settings.iter().any(|s| s.key == "format.array_style" && s.value == "\"short\""));
+        assert!(settings.iter().any(|s| s.key == "format.trailing_comma" && s.value == "true"));
+    }
+
+    #[test]
+    fn test_phpcs_line_length_and_array_syntax() {
+        let config = r#"
+            <ruleset>
+                <rule ref="Generic.Files.LineLength">
+                    <properties><property name="lineLimit" value="100"/></properties>
+                </rule>
+                <rule ref="Generic.Arrays.DisallowLongArraySyntax"/>
+            </ruleset>
+        "#;
+
+        let settings = translate_phpcs("phpcs.xml", config);
+        assert!(settings.iter().any(|s| s.key == "format.print_width" && s.value == "100"));
+        assert!(settings.iter().any(|s| s.key == "format.array_style"));
+    }
+
+    #[test]
+    fn test_missing_settings_translate_to_nothing() {
+        assert!(translate_php_cs_fixer(".php-cs-fixer.php", "<?php return [];").is_empty());
+        assert!(translate_phpcs("phpcs.xml", "<ruleset/>").is_empty());
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Condvar;
+use std::sync::Mutex;
+use std::sync::mpsc;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Wall time and file counts collected per pipeline phase, printed by `--stats`.
+#[derive(Debug, Default, Clone)]
+pub struct PipelineStats {
+    pub discovery: Duration,
+    pub index: Duration,
+    pub analysis: Duration,
+    pub reporting: Duration,
+    pub files: usize,
+    pub threads: usize,
+    pub peak_rss_bytes: Option<u64>,
+}
+
+impl PipelineStats {
+    pub fn print(&self) {
+        println!("phase            wall time");
+        println!("discovery        {:>9.2?}", self.discovery);
+        println!("index            {:>9.2?}", self.index);
+        println!("analysis         {:>9.2?}", self.analysis);
+        println!("reporting        {:>9.2?}", self.reporting);
+        println!();
+        println!("files: {}, threads: {}", self.files, self.threads);
+        if let Some(rss) = self.peak_rss_bytes {
+            println!("peak rss: {:.1} MiB", rss as f64 / (1024.0 * 1024.0));
+        }
+    }
+}
+
+/// A bounded, work-stealing driver for per-file analysis.
+///
+/// Files are fed to a pool of `threads` workers while at most `max_in_flight`
+/// files have their AST alive at once, keeping memory bounded on large
+/// monorepos. Results are streamed back over a channel; output ordering stays
+/// deterministic because results are buffered per file and flushed in path
+/// order, regardless of completion order.
+pub struct ParallelPipeline {
+    threads: usize,
+    max_in_flight: usize,
+}
+
+impl ParallelPipeline {
+    /// Create a pipeline with the given worker count; `0` selects the number
+    /// of available CPUs. In-flight files are capped at four per worker.
+    pub fn new(threads: usize) -> Self {
+        let threads = if threads == 0 {
+            std::thread::available_parallelism().map_or(1, |n| n.get())
+        } else {
+            threads
+        };
+
+        Self { threads, max_in_flight: threads * 4 }
+    }
+
+    /// Process `files` with `worker`, invoking `sink` for each result in
+    /// lexicographic path order. Returns the analysis-phase statistics.
+    pub fn run<T, W, S>(&self, mut files: Vec<PathBuf>, worker: W, mut sink: S) -> PipelineStats
+    where
+        T: Send + 'static,
+        W: Fn(&PathBuf) -> T + Send + Sync + 'static,
+        S: FnMut(PathBuf, T),
+    {
+        files.sort();
+
+        let start = Instant::now();
+        let total = files.len();
+        let worker = Arc::new(worker);
+        let in_flight = Arc::new((Mutex::new(0usize), Condvar::new()));
+        let (result_sender, result_receiver) = mpsc::channel::<(PathBuf, T)>();
+
+        std::thread::scope(|scope| {
+            let queue = Arc::new(Mutex::new(files.clone().into_iter()));
+            for _ in 0..self.threads {
+                let queue = Arc::clone(&queue);
+                let worker = Arc::clone(&worker);
+                let in_flight = Arc::clone(&in_flight);
+                let result_sender = result_sender.clone();
+                let max_in_flight = self.max_in_flight;
+
+                scope.spawn(move || {
+                    loop {
+                        let Some(file) = queue.lock().expect("queue poisoned").next() else {
+                            break;
+                        };
+
+                        let (count, available) = &*in_flight;
+                        let mut count = count.lock().expect("in-flight counter poisoned");
+                        while *count >= max_in_flight {
+                            count = available.wait(count).expect("in-flight counter poisoned");
+                        }
+
+                        *count += 1;
+                        drop(count);
+
+                        let result = worker(&file);
+                        if result_sender.send((file, result)).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+
+            drop(result_sender);
+
+            // Flush results in path order: buffer out-of-order completions
+            // until the next expected path arrives.
+            let mut pending: BTreeMap<PathBuf, T> = BTreeMap::new();
+            let mut expected = files.into_iter();
+            let mut next = expected.next();
+            for (file, result) in result_receiver {
+                let (count, available) = &*in_flight;
+                *count.lock().expect("in-flight counter poisoned") -= 1;
+                available.notify_one();
+
+                pending.insert(file, result);
+                while let Some(path) = next.as_ref() {
+                    let Some(result) = pending.remove(path) else {
+                        break;
+                    };
+
+                    sink(next.take().expect("expected path"), result);
+                    next = expected.next();
+                }
+            }
+        });
+
+        PipelineStats {
+            analysis: start.elapsed(),
+            files: total,
+            threads: self.threads,
+            peak_rss_bytes: peak_rss(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Best-effort peak resident set size of the current process, if the
+/// platform exposes it.
+fn peak_rss() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+        let kib: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+        return Some(kib * 1024);
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_results_flushed_in_path_order() {
+        let files: Vec<PathBuf> = (0..64).map(|i| PathBuf::from(format!("src/file-{i:02}.php"))).collect();
+        let pipeline = ParallelPipeline::new(8);
+
+        let mut seen = Vec::new();
+        pipeline.run(files.clone(), |path| path.clone(), |path, _| seen.push(path));
+
+        let mut expected = files;
+        expected.sort();
+        assert_eq!(seen, expected);
+    }
+}
@@ -0,0 +1,41 @@
+use mago_rule_api::Registry;
+
+/// Build the registry of external rule packs this binary was compiled
+/// with, from the resolved `[linter.packs]` configuration table.
+///
+/// Each pack crate sits behind a cargo feature; enabling the feature
+/// links the pack and registers it here. Rule names stay namespaced by
+/// pack (`example/forbidden-functions`), so packs cannot collide with
+/// built-in rules or with each other, and a binary built without a
+/// feature simply never sees that pack's configuration section.
+pub fn registry(packs: &toml::value::Table) -> Registry {
+    let mut registry = Registry::new();
+
+    #[cfg(feature = "rule-pack-example")]
+    {
+        let forbidden = packs
+            .get("example")
+            .and_then(|pack| pack.get("forbidden_functions"))
+            .and_then(toml::Value::as_array)
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter_map(toml::Value::as_str)
+                    .map(|name| mago_rule_pack_example::ForbiddenFunction {
+                        name: name.to_owned(),
+                        replacement: None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        registry.register(Box::new(mago_rule_pack_example::ExamplePack::new(forbidden)));
+    }
+
+    #[cfg(not(feature = "rule-pack-example"))]
+    {
+        let _ = packs;
+    }
+
+    registry
+}